@@ -0,0 +1,25 @@
+//! Regenerates `src/champion_id/generated.rs` from the champions of the latest DDragon version.
+//!
+//! ```text
+//! cargo run --example generate_champion_ids > src/champion_id/generated.rs
+//! ```
+
+use samira::utils_api::UtilsApi;
+
+fn main() {
+    let api = UtilsApi::try_latest("en_US").expect("could not retrieve the latest version");
+    let mut champions = api
+        .try_get_all_champions()
+        .expect("could not retrieve the champions");
+    champions.sort_by(|a, b| a.id.cmp(&b.id));
+
+    println!(
+        "// Generated by examples/generate_champion_ids.rs from DDragon, do not edit by hand."
+    );
+    println!();
+    println!("champion_ids! {{");
+    for champion in champions {
+        println!("    {} = {},", champion.id, champion.key);
+    }
+    println!("}}");
+}
@@ -0,0 +1,82 @@
+//! Exports the participants of matches as Arrow record batches, to hand crawled matches to DuckDB, Spark or pandas
+//! without converting them row by row.
+//! The columns are the fields of the participants and of the match info, named like in match-v5
+//! ("kills", "challenges.kda", "gameDuration", ...), the lists being written as JSON strings.
+
+use std::{io::Write, sync::Arc};
+
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::{
+    models::match_model::Match,
+    tabular::{self, ColumnKind},
+};
+
+/// Returns the participants of the matches as a record batch, one row per participant,
+/// with the match id and the match info fields on every row.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{arrow::*, models::match_model::*};
+///
+/// let game: Match = serde_json::from_str(r#"{
+///     "metadata": {"dataVersion": "2", "matchId": "EUW1_6000000000", "participants": []},
+///     "info": {
+///         "gameCreation": 1700000000000, "gameDuration": 1800, "gameId": 6000000000, "gameMode": "CLASSIC",
+///         "gameName": "", "gameStartTimestamp": 1700000000000, "gameType": "MATCHED_GAME",
+///         "gameVersion": "13.22.541.5047", "mapId": 11, "participants": [], "platformId": "EUW1", "queueId": 420,
+///         "teams": []
+///     }
+/// }"#).unwrap();
+/// let batch = participants_record_batch(&[game.clone()]).unwrap();
+/// assert_eq!(batch.num_rows(), game.info.participants.len());
+///
+/// let mut file = Vec::new();
+/// write_participants_ipc(&[game], &mut file).unwrap();
+/// assert!(file.starts_with(b"ARROW1"));
+/// ```
+pub fn participants_record_batch(matches: &[Match]) -> Result<RecordBatch, ArrowError> {
+    let rows = tabular::participant_rows(matches)
+        .map_err(|error| ArrowError::JsonError(error.to_string()))?;
+    let columns = tabular::columns(rows);
+    let fields: Vec<Field> = columns
+        .iter()
+        .map(|column| {
+            let data_type = match column.kind {
+                ColumnKind::Boolean => DataType::Boolean,
+                ColumnKind::Integer => DataType::Int64,
+                ColumnKind::Float => DataType::Float64,
+                ColumnKind::Text => DataType::Utf8,
+            };
+            Field::new(&column.name, data_type, true)
+        })
+        .collect();
+    let arrays: Vec<ArrayRef> = columns
+        .iter()
+        .map(|column| -> ArrayRef {
+            match column.kind {
+                ColumnKind::Boolean => Arc::new(BooleanArray::from(column.booleans())),
+                ColumnKind::Integer => Arc::new(Int64Array::from(column.integers())),
+                ColumnKind::Float => Arc::new(Float64Array::from(column.floats())),
+                ColumnKind::Text => Arc::new(StringArray::from(column.texts())),
+            }
+        })
+        .collect();
+    match arrays.is_empty() {
+        true => Ok(RecordBatch::new_empty(Arc::new(Schema::new(fields)))),
+        false => RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays),
+    }
+}
+
+/// Writes the participants of the matches to an Arrow IPC file, see [`participants_record_batch`].
+pub fn write_participants_ipc(matches: &[Match], writer: impl Write) -> Result<(), ArrowError> {
+    let batch = participants_record_batch(matches)?;
+    let mut writer = FileWriter::try_new(writer, &batch.schema())?;
+    writer.write(&batch)?;
+    writer.finish()
+}
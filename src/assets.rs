@@ -0,0 +1,153 @@
+//! Downloads the DDragon images into a directory, for applications serving them themselves.
+//! See [`UtilsApi::sync_assets`](crate::utils_api::UtilsApi::sync_assets).
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::Read,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SamiraError, transport::HttpTransport};
+
+/// The file, in the synced directory, remembering where each asset was downloaded from.
+pub(crate) const MANIFEST: &str = "samira-assets.json";
+
+/// The images to download.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::assets::*;
+///
+/// let kinds = AssetKinds { items: false, ..AssetKinds::ALL };
+/// assert_eq!(kinds.champions, true);
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct AssetKinds {
+    /// The champion squares, saved in `champion/`.
+    pub champions: bool,
+    /// The item icons, saved in `item/`.
+    pub items: bool,
+    /// The rune and rune tree icons, saved in `rune/` under their DDragon path.
+    pub runes: bool,
+    /// The summoner spell icons, saved in `spell/`.
+    pub summoner_spells: bool,
+}
+
+impl AssetKinds {
+    pub const ALL: AssetKinds = AssetKinds {
+        champions: true,
+        items: true,
+        runes: true,
+        summoner_spells: true,
+    };
+}
+
+/// The outcome of a sync.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SyncReport {
+    /// The assets written to the directory.
+    pub downloaded: usize,
+    /// The assets already up to date.
+    pub skipped: usize,
+    /// The assets which could not be downloaded, with their path relative to the directory.
+    pub failed: Vec<(String, SamiraError)>,
+}
+
+/// Where an asset was downloaded from, to skip it while it does not change.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub(crate) struct ManifestEntry {
+    pub url: String,
+    pub etag: Option<String>,
+}
+
+pub(crate) type Manifest = HashMap<String, ManifestEntry>;
+
+pub(crate) enum Synced {
+    Downloaded(ManifestEntry),
+    Skipped,
+    /// Already up to date, but known under another url.
+    NotModified(ManifestEntry),
+}
+
+pub(crate) fn read_manifest(directory: &Path) -> Manifest {
+    fs::read(directory.join(MANIFEST))
+        .ok()
+        .and_then(|content| serde_json::from_slice(&content).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn write_manifest(directory: &Path, manifest: &Manifest) -> Result<(), SamiraError> {
+    write_file(&directory.join(MANIFEST), &serde_json::to_vec(manifest)?)
+}
+
+/// Downloads an asset to `path` in the directory, unless it was already downloaded from the same url.
+/// DDragon urls change with each version, unversioned ones are revalidated with their ETag.
+/// The path comes from DDragon, so it is rejected unless it stays inside the directory.
+pub(crate) fn sync_asset(
+    transport: &dyn HttpTransport,
+    directory: &Path,
+    path: &str,
+    url: &str,
+    entry: Option<&ManifestEntry>,
+) -> Result<Synced, SamiraError> {
+    let inside = Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !inside {
+        return Err(SamiraError::malformed(&format!(
+            "the asset path {path} leaves the directory"
+        )));
+    }
+    let file = directory.join(path);
+    let entry = entry.filter(|_| file.is_file());
+    if entry.is_some_and(|entry| entry.url == url) {
+        return Ok(Synced::Skipped);
+    }
+    let etag = entry.and_then(|entry| entry.etag.as_deref());
+    let headers: Vec<(&str, &str)> = etag
+        .map(|etag| ("If-None-Match", etag))
+        .into_iter()
+        .collect();
+    let mut response = transport.get(url, &headers)?;
+    let synced = ManifestEntry {
+        url: url.to_string(),
+        etag: response.header("ETag").map(str::to_string),
+    };
+    match response.status {
+        304 => {
+            return Ok(Synced::NotModified(ManifestEntry {
+                etag: synced.etag.or(etag.map(str::to_string)),
+                ..synced
+            }))
+        }
+        200..=299 => {}
+        status => {
+            return Err(SamiraError::from_status(
+                status,
+                response.header("Retry-After"),
+            ))
+        }
+    }
+    let mut content = Vec::new();
+    response.body.read_to_end(&mut content)?;
+    write_file(&file, &content)?;
+    Ok(Synced::Downloaded(synced))
+}
+
+/// Writes a file then renames it, so a concurrent reader never sees a partial file.
+fn write_file(file: &Path, content: &[u8]) -> Result<(), SamiraError> {
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temporary = PathBuf::from(file);
+    temporary.as_mut_os_string().push(".tmp");
+    fs::write(&temporary, content)?;
+    fs::rename(&temporary, file)?;
+    Ok(())
+}
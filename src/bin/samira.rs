@@ -0,0 +1,270 @@
+//! The samira command line, querying the Riot API and DDragon from a terminal.
+//! The token is read from the `RIOT_API_KEY` or `RIOT_API` environment variable.
+
+use std::{env, error::Error, process, sync::Arc};
+
+use samira::{cache::file_cache::FileCache, prelude::*};
+use serde::Serialize;
+
+const USAGE: &str = "usage: samira [--json] <command>
+
+commands:
+    summoner <platform> <name#tag>
+    rank <platform> <name#tag>
+    matches <platform> <name#tag> [--count <count>] [--queue <queue id>]
+    rotation <platform>
+    champion <name> [--language <language>]
+    ddragon download <directory> [--language <language>]";
+
+/// The parsed command line, flags can be given anywhere.
+struct Args {
+    json: bool,
+    count: u32,
+    queue: Option<Queue>,
+    language: String,
+    positional: Vec<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, Box<dyn Error>> {
+        let mut parsed = Args {
+            json: false,
+            count: 5,
+            queue: None,
+            language: "en_US".to_string(),
+            positional: Vec::new(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value of {}", arg));
+            match arg.as_str() {
+                "--json" => parsed.json = true,
+                "--count" => parsed.count = value()?.parse()?,
+                "--queue" => {
+                    let id = value()?.parse()?;
+                    parsed.queue = Some(Queue::from_id(id).ok_or(format!("unknown queue {}", id))?);
+                }
+                "--language" => parsed.language = value()?,
+                "-h" | "--help" => return Err(USAGE.into()),
+                _ => parsed.positional.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn positional(&self, index: usize, name: &str) -> Result<&str, Box<dyn Error>> {
+        self.positional
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| format!("missing {}\n\n{}", name, USAGE).into())
+    }
+
+    fn platform(&self, index: usize) -> Result<Platform, Box<dyn Error>> {
+        Ok(self.positional(index, "platform")?.parse()?)
+    }
+
+    fn riot_id(&self, index: usize) -> Result<(&str, &str), Box<dyn Error>> {
+        let riot_id = self.positional(index, "riot id")?;
+        riot_id
+            .rsplit_once('#')
+            .ok_or_else(|| format!("invalid riot id {}, expected name#tag", riot_id).into())
+    }
+}
+
+fn main() {
+    let result = Args::parse(env::args().skip(1)).and_then(|args| run(&args));
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    match args.positional(0, "command")? {
+        "summoner" => summoner(args),
+        "rank" => rank(args),
+        "matches" => matches(args),
+        "rotation" => rotation(args),
+        "champion" => champion(args),
+        "ddragon" => match args.positional(1, "ddragon command")? {
+            "download" => ddragon_download(args),
+            command => Err(format!("unknown ddragon command {}\n\n{}", command, USAGE).into()),
+        },
+        command => Err(format!("unknown command {}\n\n{}", command, USAGE).into()),
+    }
+}
+
+fn summoner(args: &Args) -> Result<(), Box<dyn Error>> {
+    let (platform, (game_name, tag_line)) = (args.platform(1)?, args.riot_id(2)?);
+    let api = RiotApi::try_from_env()?;
+    let summoner =
+        api.try_get_summoner(&platform, SummonerFilter::by_riot_id(game_name, tag_line))?;
+    print(args, &summoner, || {
+        table(
+            &["name", "level", "puuid"],
+            vec![vec![
+                format!("{}#{}", game_name, tag_line),
+                summoner.summoner_level.to_string(),
+                summoner.puuid.to_string(),
+            ]],
+        )
+    })
+}
+
+fn rank(args: &Args) -> Result<(), Box<dyn Error>> {
+    let (platform, (game_name, tag_line)) = (args.platform(1)?, args.riot_id(2)?);
+    let api = RiotApi::try_from_env()?;
+    let account = api.try_get_account_by_riot_id(&account_route(&platform), game_name, tag_line)?;
+    let entries = api.try_get_league_entries(&platform, &account.puuid)?;
+    print(args, &entries, || {
+        table(
+            &["queue", "rank", "wins", "losses"],
+            entries
+                .iter()
+                .map(|entry| {
+                    vec![
+                        entry.queue_type.clone(),
+                        entry.to_string(),
+                        entry.wins.to_string(),
+                        entry.losses.to_string(),
+                    ]
+                })
+                .collect(),
+        )
+    })
+}
+
+fn matches(args: &Args) -> Result<(), Box<dyn Error>> {
+    let (platform, (game_name, tag_line)) = (args.platform(1)?, args.riot_id(2)?);
+    let api = RiotApi::try_from_env()?;
+    let account = api.try_get_account_by_riot_id(&account_route(&platform), game_name, tag_line)?;
+    let mut filter = MatchIdsFilter::new().count(args.count);
+    if let Some(queue) = args.queue {
+        filter = filter.queue(queue);
+    }
+    let region = platform.regional_route();
+    let match_ids = api.try_get_match_ids(&region, &account.puuid, &filter)?;
+    let matches = api
+        .get_many(&match_ids, 4, |api, match_id| {
+            api.try_get_match(&region, match_id)
+        })
+        .into_iter()
+        .collect::<Result<Vec<Match>, SamiraError>>()?;
+    print(args, &matches, || {
+        table(
+            &["match", "mode", "champion", "k/d/a", "result"],
+            matches
+                .iter()
+                .filter_map(|game| {
+                    let participant = game
+                        .info
+                        .participants
+                        .iter()
+                        .find(|participant| participant.puuid == account.puuid)?;
+                    Some(vec![
+                        game.metadata.match_id.to_string(),
+                        game.info.game_mode.as_str().to_string(),
+                        participant.champion_name.clone(),
+                        format!(
+                            "{}/{}/{}",
+                            participant.kills, participant.deaths, participant.assists
+                        ),
+                        match participant.win {
+                            true => "win".to_string(),
+                            false => "loss".to_string(),
+                        },
+                    ])
+                })
+                .collect(),
+        )
+    })
+}
+
+fn rotation(args: &Args) -> Result<(), Box<dyn Error>> {
+    let platform = args.platform(1)?;
+    let rotation = RiotApi::try_from_env()?.try_get_champion_rotations(&platform)?;
+    print(args, &rotation, || {
+        table(
+            &["champion"],
+            rotation
+                .free_champions()
+                .iter()
+                .map(|champion| vec![champion.to_string()])
+                .collect(),
+        )
+    })
+}
+
+fn champion(args: &Args) -> Result<(), Box<dyn Error>> {
+    let name = args.positional(1, "champion name")?;
+    let api = UtilsApi::try_latest(&args.language)?;
+    let champion = api
+        .try_get_all_champions()?
+        .into_iter()
+        .find(|champion| {
+            champion.id.eq_ignore_ascii_case(name) || champion.name.eq_ignore_ascii_case(name)
+        })
+        .ok_or_else(|| format!("unknown champion {}", name))?;
+    print(args, &champion, || {
+        table(
+            &["champion", "key", "tags"],
+            vec![vec![
+                champion.to_string(),
+                champion.key.to_string(),
+                champion.tags.join(", "),
+            ]],
+        )
+    })
+}
+
+fn ddragon_download(args: &Args) -> Result<(), Box<dyn Error>> {
+    let directory = args.positional(2, "directory")?;
+    let api =
+        UtilsApi::try_latest(&args.language)?.with_cache(Arc::new(FileCache::new(directory)?));
+    api.warm_cache()?;
+    println!(
+        "downloaded {} ({}) into {}",
+        api.version, api.language, directory
+    );
+    Ok(())
+}
+
+/// Accounts are served by every regional route but SEA.
+fn account_route(platform: &Platform) -> Region {
+    match platform.regional_route() {
+        Region::SEA => Region::ASIA,
+        region => region,
+    }
+}
+
+/// Prints the value as JSON with `--json`, as a table otherwise.
+fn print<T: Serialize>(args: &Args, value: &T, table: impl FnOnce()) -> Result<(), Box<dyn Error>> {
+    match args.json {
+        true => println!("{}", serde_json::to_string_pretty(value)?),
+        false => table(),
+    }
+    Ok(())
+}
+
+fn table(headers: &[&str], rows: Vec<Vec<String>>) {
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([headers[column].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    line(headers.to_vec());
+    for row in &rows {
+        line(row.iter().map(String::as_str).collect());
+    }
+}
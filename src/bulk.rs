@@ -0,0 +1,42 @@
+use std::{sync::Mutex, thread};
+
+/// Runs `run` on every input with at most `max_concurrency` threads, returning the outputs in the inputs order.
+/// Without concurrency the inputs are run on the calling thread,
+/// as they always are on the wasm32 target where threads cannot be spawned.
+pub(crate) fn run_bounded<I: Send, T: Send>(
+    inputs: impl IntoIterator<Item = I>,
+    max_concurrency: usize,
+    run: impl Fn(I) -> T + Sync,
+) -> Vec<T> {
+    let inputs: Vec<I> = inputs.into_iter().collect();
+    let workers = max_concurrency.min(inputs.len());
+    if workers <= 1 || cfg!(target_arch = "wasm32") {
+        return inputs.into_iter().map(run).collect();
+    }
+    let count = inputs.len();
+    let queue = Mutex::new(inputs.into_iter().enumerate());
+    let mut outputs: Vec<(usize, T)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut outputs = Vec::new();
+                    loop {
+                        // The queue is released before running the input.
+                        let next = queue.lock().unwrap().next();
+                        match next {
+                            Some((index, input)) => outputs.push((index, run(input))),
+                            None => return outputs,
+                        }
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    debug_assert_eq!(outputs.len(), count);
+    outputs.sort_by_key(|(index, _)| *index);
+    outputs.into_iter().map(|(_, output)| output).collect()
+}
@@ -0,0 +1,135 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{self, Value};
+
+use super::Cache;
+
+/// A cache storing each response in its own file, so it survives process restarts.
+/// Files are named after a hash of the request url, which contains the DDragon version,
+/// and keep the url to detect hash collisions.
+#[derive(Debug, Clone)]
+pub struct FileCache {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileEntry {
+    key: String,
+    expires_at: u64,
+    value: Value,
+}
+
+impl FileCache {
+    /// Creates a FileCache storing its files in the given directory, creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::{env, sync::Arc, time::Duration};
+    /// use samira::{cache::{file_cache::*, *}, utils_api::*};
+    ///
+    /// let cache = FileCache::new(env::temp_dir().join("samira-doc-cache")).unwrap();
+    /// cache.set("key", "value".into(), Duration::from_secs(60));
+    /// assert_eq!(cache.get("key"), Some("value".into()));
+    /// let api = UtilsApi::default().with_cache(Arc::new(cache));
+    /// ```
+    pub fn new(directory: impl AsRef<Path>) -> io::Result<FileCache> {
+        fs::create_dir_all(directory.as_ref())?;
+        Ok(FileCache {
+            directory: directory.as_ref().to_path_buf(),
+        })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.json", hash(key)))
+    }
+
+    fn read(&self, key: &str) -> Option<FileEntry> {
+        let content = fs::read(self.path(key)).ok()?;
+        let entry: FileEntry = serde_json::from_slice(&content).ok()?;
+        match entry.key == key {
+            true => Some(entry),
+            false => None,
+        }
+    }
+}
+
+/// FNV-1a, which unlike the standard hasher is guaranteed to be stable between runs.
+pub(crate) fn hash(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Returns a temporary file next to `path`, unique to this write so concurrent writers of the same file
+/// never write to the same temporary file.
+pub(crate) fn temporary_path(path: &Path) -> PathBuf {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+    let write = WRITES.fetch_add(1, Ordering::Relaxed);
+    path.with_extension(format!("{}-{}.tmp", process::id(), write))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let entry = self.read(key)?;
+        if entry.expires_at <= now() {
+            self.remove(key);
+            return None;
+        }
+        Some(entry.value)
+    }
+
+    fn set(&self, key: &str, value: Value, ttl: Duration) {
+        let entry = FileEntry {
+            key: key.to_string(),
+            expires_at: now().saturating_add(ttl.as_secs()),
+            value,
+        };
+        let path = self.path(key);
+        let temporary = temporary_path(&path);
+        // Written then renamed so a concurrent reader never sees a partial file.
+        let written = serde_json::to_vec(&entry)
+            .map_err(io::Error::from)
+            .and_then(|content| fs::write(&temporary, content))
+            .and_then(|_| fs::rename(&temporary, &path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        if self.read(key).is_some() {
+            let _ = fs::remove_file(self.path(key));
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(entries) = fs::read_dir(&self.directory) {
+            for entry in entries.flatten() {
+                if entry
+                    .path()
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                {
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{collections::HashMap, fmt, sync::Mutex, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+// std's Instant panics in browsers.
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use serde_json::Value;
+
+use super::Cache;
+
+/// An in-memory cache, expired responses are evicted when accessed or purged.
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, (Instant, Value)>>,
+}
+
+impl fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("entries", &self.entries.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl MemoryCache {
+    /// Removes every expired response.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::cache::{memory_cache::*, *};
+    ///
+    /// let cache = MemoryCache::default();
+    /// cache.set("key", "value".into(), Duration::ZERO);
+    /// cache.purge_expired();
+    /// assert_eq!(cache.get("key"), None);
+    /// ```
+    pub fn purge_expired(&self) {
+        let now = Instant::now();
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|_, (expires_at, _)| *expires_at > now);
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((expires_at, value)) if *expires_at > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn set(&self, key: &str, value: Value, ttl: Duration) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (Instant::now() + ttl, value));
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_cache;
+pub mod memory_cache;
+#[cfg(feature = "sled")]
+pub mod sled_cache;
+
+use std::{collections::HashMap, fmt::Debug, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{self, Value};
+
+/// How long a response is kept to be revalidated once its time to live expired.
+const REVALIDATION_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// A store for API responses, keyed by request url.
+/// Implementations must be thread safe as a cache can be shared by several clients.
+pub trait Cache: Debug + Send + Sync {
+    /// Returns the cached response of a request, or None if it is missing or expired.
+    fn get(&self, key: &str) -> Option<Value>;
+    /// Stores the response of a request for the given duration.
+    fn set(&self, key: &str, value: Value, ttl: Duration);
+    /// Removes the cached response of a request.
+    fn remove(&self, key: &str);
+    /// Removes every cached response.
+    fn clear(&self);
+}
+
+/// The kind of data returned by an endpoint, each category having its own time to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheCategory {
+    Summoner,
+    League,
+    Match,
+    ChampionRotation,
+    Status,
+    StaticData,
+}
+
+/// The time to live of the cached responses of each category.
+/// A category without a time to live is never cached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheTtls {
+    ttls: HashMap<CacheCategory, Duration>,
+}
+
+impl Default for CacheTtls {
+    fn default() -> CacheTtls {
+        CacheTtls::none()
+            .with(CacheCategory::Summoner, Duration::from_secs(60))
+            .with(CacheCategory::League, Duration::from_secs(60))
+            .with(CacheCategory::Match, Duration::from_secs(60 * 60))
+            .with(
+                CacheCategory::ChampionRotation,
+                Duration::from_secs(60 * 60),
+            )
+            .with(CacheCategory::StaticData, Duration::from_secs(24 * 60 * 60))
+    }
+}
+
+impl CacheTtls {
+    /// Creates a CacheTtls which caches nothing.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::cache::*;
+    ///
+    /// let ttls = CacheTtls::none();
+    /// assert_eq!(ttls.get(CacheCategory::StaticData), None);
+    /// ```
+    pub fn none() -> CacheTtls {
+        CacheTtls {
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Sets the time to live of a category.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::cache::*;
+    ///
+    /// let ttls = CacheTtls::default().with(CacheCategory::Summoner, Duration::from_secs(10));
+    /// assert_eq!(ttls.get(CacheCategory::Summoner), Some(Duration::from_secs(10)));
+    /// ```
+    pub fn with(mut self, category: CacheCategory, ttl: Duration) -> CacheTtls {
+        self.ttls.insert(category, ttl);
+        self
+    }
+
+    /// Stops caching a category.
+    pub fn without(mut self, category: CacheCategory) -> CacheTtls {
+        self.ttls.remove(&category);
+        self
+    }
+
+    /// Returns the time to live of a category, or None if it is not cached.
+    pub fn get(&self, category: CacheCategory) -> Option<Duration> {
+        self.ttls.get(&category).copied()
+    }
+}
+
+/// The validators of a response, sent back to the server to check whether it changed.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub(crate) struct Validators {
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+}
+
+/// The result of a conditional request.
+pub(crate) enum Revalidation {
+    NotModified,
+    Modified(Option<Value>, Validators),
+}
+
+#[derive(Serialize, Deserialize)]
+struct ValidatedEntry {
+    validators: Validators,
+    value: Value,
+}
+
+/// Returns the cached response of a request while it is fresh.
+/// Once expired, the response is revalidated with a conditional request,
+/// and only downloaded again if it changed.
+pub(crate) fn get_or_revalidate<E>(
+    cache: Option<&dyn Cache>,
+    ttl: Option<Duration>,
+    key: &str,
+    fetch: impl FnOnce(&Validators) -> Result<Revalidation, E>,
+) -> Result<Option<Value>, E> {
+    let (cache, ttl) = match (cache, ttl) {
+        (Some(cache), Some(ttl)) => (cache, ttl),
+        _ => {
+            return match fetch(&Validators::default())? {
+                Revalidation::Modified(value, _) => Ok(value),
+                Revalidation::NotModified => Ok(None),
+            }
+        }
+    };
+    let fresh_key = format!("{}\nfresh", key);
+    let entry = cache
+        .get(key)
+        .and_then(|entry| serde_json::from_value::<ValidatedEntry>(entry).ok());
+    let validators = match &entry {
+        Some(entry) if cache.get(&fresh_key).is_some() => return Ok(Some(entry.value.clone())),
+        Some(entry) => entry.validators.clone(),
+        None => Validators::default(),
+    };
+    match fetch(&validators)? {
+        Revalidation::NotModified => {
+            cache.set(&fresh_key, Value::Null, ttl);
+            Ok(entry.map(|entry| entry.value))
+        }
+        Revalidation::Modified(None, _) => Ok(None),
+        Revalidation::Modified(Some(value), validators) => {
+            let entry = ValidatedEntry {
+                validators,
+                value: value.clone(),
+            };
+            if let Ok(entry) = serde_json::to_value(entry) {
+                cache.set(key, entry, ttl.max(REVALIDATION_TTL));
+                cache.set(&fresh_key, Value::Null, ttl);
+            }
+            Ok(Some(value))
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    fmt, io,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{self, Value};
+
+use super::Cache;
+
+/// A persistent cache backed by a sled database, suitable for long-running processes.
+/// When the stored responses exceed the size limit, the responses closest to expiring are evicted first.
+pub struct SledCache {
+    entries: sled::Tree,
+    expirations: sled::Tree,
+    max_size: Option<u64>,
+    size: Mutex<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SledEntry {
+    expires_at: u64,
+    value: Value,
+}
+
+impl fmt::Debug for SledCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SledCache")
+            .field("entries", &self.entries.len())
+            .field("size", &self.size())
+            .field("max_size", &self.max_size)
+            .finish()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// The expiration index key, ordered by expiration time then by response key.
+fn expiration_key(expires_at: u64, key: &[u8]) -> Vec<u8> {
+    let mut expiration_key = expires_at.to_be_bytes().to_vec();
+    expiration_key.extend_from_slice(key);
+    expiration_key
+}
+
+impl SledCache {
+    /// Opens or creates a SledCache in the given directory.
+    /// `max_size` limits the total size in bytes of the stored responses.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::{env, sync::Arc, time::Duration};
+    /// use samira::{cache::{sled_cache::*, *}, riot_api::*};
+    ///
+    /// let cache = SledCache::open(env::temp_dir().join("samira-doc-sled"), Some(64 * 1024 * 1024)).unwrap();
+    /// cache.set("key", "value".into(), Duration::from_secs(60));
+    /// assert_eq!(cache.get("key"), Some("value".into()));
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_cache(Arc::new(cache));
+    /// ```
+    pub fn open(directory: impl AsRef<Path>, max_size: Option<u64>) -> io::Result<SledCache> {
+        let db = sled::open(directory).map_err(io::Error::from)?;
+        let entries = db.open_tree("entries").map_err(io::Error::from)?;
+        let expirations = db.open_tree("expirations").map_err(io::Error::from)?;
+        let size = entries
+            .iter()
+            .values()
+            .flatten()
+            .map(|value| value.len() as u64)
+            .sum();
+        Ok(SledCache {
+            entries,
+            expirations,
+            max_size,
+            size: Mutex::new(size),
+        })
+    }
+
+    /// Returns the total size in bytes of the stored responses.
+    pub fn size(&self) -> u64 {
+        *self.size.lock().unwrap()
+    }
+
+    /// Removes every expired response.
+    pub fn purge_expired(&self) {
+        let mut size = self.size.lock().unwrap();
+        let limit = expiration_key(now().saturating_add(1), &[]);
+        for expiration in self.expirations.range(..limit).keys().flatten() {
+            self.remove_entry(&expiration[8..], &mut size);
+        }
+    }
+
+    fn read(&self, key: &[u8]) -> Option<SledEntry> {
+        let content = self.entries.get(key).ok()??;
+        serde_json::from_slice(&content).ok()
+    }
+
+    fn remove_entry(&self, key: &[u8], size: &mut u64) {
+        if let Ok(Some(content)) = self.entries.remove(key) {
+            *size = size.saturating_sub(content.len() as u64);
+            if let Ok(entry) = serde_json::from_slice::<SledEntry>(&content) {
+                let _ = self
+                    .expirations
+                    .remove(expiration_key(entry.expires_at, key));
+            }
+        }
+    }
+
+    fn evict(&self, size: &mut u64) {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return,
+        };
+        while *size > max_size {
+            match self.expirations.first() {
+                Ok(Some((expiration, _))) => self.remove_entry(&expiration[8..], size),
+                _ => break,
+            }
+        }
+    }
+}
+
+impl Cache for SledCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let entry = self.read(key.as_bytes())?;
+        if entry.expires_at <= now() {
+            self.remove(key);
+            return None;
+        }
+        Some(entry.value)
+    }
+
+    fn set(&self, key: &str, value: Value, ttl: Duration) {
+        let entry = SledEntry {
+            expires_at: now().saturating_add(ttl.as_secs()),
+            value,
+        };
+        let content = match serde_json::to_vec(&entry) {
+            Ok(content) => content,
+            Err(_) => return,
+        };
+        let mut size = self.size.lock().unwrap();
+        self.remove_entry(key.as_bytes(), &mut size);
+        let content_size = content.len() as u64;
+        if self.entries.insert(key.as_bytes(), content).is_ok() {
+            let _ = self
+                .expirations
+                .insert(expiration_key(entry.expires_at, key.as_bytes()), &[]);
+            *size += content_size;
+        }
+        self.evict(&mut size);
+    }
+
+    fn remove(&self, key: &str) {
+        let mut size = self.size.lock().unwrap();
+        self.remove_entry(key.as_bytes(), &mut size);
+    }
+
+    fn clear(&self) {
+        let mut size = self.size.lock().unwrap();
+        let _ = self.entries.clear();
+        let _ = self.expirations.clear();
+        *size = 0;
+    }
+}
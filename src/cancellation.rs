@@ -0,0 +1,110 @@
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+// std's Instant panics in browsers.
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::error::SamiraError;
+
+/// Aborts the requests of a client once cancelled or once its deadline passed,
+/// including their waits on the rate limits and their retry delays.
+/// Clones share their cancellation, so a token can be cancelled from another thread.
+/// A request already sent is not interrupted, it is its next wait that fails with [`SamiraError::Cancelled`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::time::Duration;
+/// use samira::{cancellation::*, error::*, platform::*, riot_api::*, transport::mock_transport::*};
+///
+/// let token = CancellationToken::new().with_timeout(Duration::from_secs(5));
+/// let api = RiotApi::new_unchecked("TOKEN_HERE")
+///     .with_transport(MockTransport::new())
+///     .with_cancellation(token.clone());
+/// token.cancel();
+/// assert_eq!(api.try_get_champion_rotations(&Platform::EUW1), Err(SamiraError::Cancelled));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<(Mutex<bool>, Condvar)>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// Creates a token which is never cancelled until [`CancellationToken::cancel`] is called.
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Sets the instant after which the token is cancelled.
+    pub fn with_deadline(mut self, deadline: Instant) -> CancellationToken {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets the deadline to the given duration from now.
+    pub fn with_timeout(self, timeout: Duration) -> CancellationToken {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Cancels the token and its clones, waking up the requests waiting with them.
+    pub fn cancel(&self) {
+        let (cancelled, changed) = &*self.cancelled;
+        *cancelled.lock().unwrap() = true;
+        changed.notify_all();
+    }
+
+    /// Returns whether the token was cancelled or its deadline passed.
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.0.lock().unwrap()
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Fails if the token was cancelled or its deadline passed.
+    pub(crate) fn check(&self) -> Result<(), SamiraError> {
+        match self.is_cancelled() {
+            true => Err(SamiraError::Cancelled),
+            false => Ok(()),
+        }
+    }
+
+    /// Sleeps for the given duration unless the token is cancelled meanwhile.
+    /// It fails right away when the sleep would outlast the deadline.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn sleep(&self, duration: Duration) -> Result<(), SamiraError> {
+        let now = Instant::now();
+        let wake_up = now + duration;
+        if self.deadline.is_some_and(|deadline| wake_up > deadline) {
+            return Err(SamiraError::Cancelled);
+        }
+        let (cancelled, changed) = &*self.cancelled;
+        let mut cancelled = cancelled.lock().unwrap();
+        let mut now = now;
+        while !*cancelled && now < wake_up {
+            cancelled = changed.wait_timeout(cancelled, wake_up - now).unwrap().0;
+            now = Instant::now();
+        }
+        match *cancelled {
+            true => Err(SamiraError::Cancelled),
+            false => Ok(()),
+        }
+    }
+
+    /// A browser cannot block its thread to sleep, so the wait fails with the duration to retry after instead.
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn sleep(&self, duration: Duration) -> Result<(), SamiraError> {
+        self.check()?;
+        Err(SamiraError::RateLimited {
+            retry_after: Some(duration.as_secs_f64().ceil() as u64),
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Declares [`ChampionId`] from the champions listed in the generated module.
+macro_rules! champion_ids {
+    ($($champion:ident = $key:literal,)*) => {
+        /// A champion, named after its DDragon id and valued by its numeric key
+        /// (`ChampionId::Samira as i32 == 360`), as found in rotations, masteries and matches.
+        /// The champions are generated from DDragon, see `examples/generate_champion_ids.rs` to refresh them.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// use samira::champion_id::*;
+        ///
+        /// assert_eq!(ChampionId::Samira.key(), 360);
+        /// assert_eq!(ChampionId::from_key(62), Some(ChampionId::MonkeyKing));
+        /// assert_eq!(ChampionId::from_id("Samira"), Some(ChampionId::Samira));
+        /// let champion: ChampionId = serde_json::from_str("360").unwrap();
+        /// assert_eq!(champion.to_string(), "Samira");
+        /// ```
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(i32)]
+        pub enum ChampionId {
+            $($champion = $key,)*
+        }
+
+        impl ChampionId {
+            /// Every champion, sorted by DDragon id.
+            pub const ALL: &'static [ChampionId] = &[$(ChampionId::$champion,)*];
+
+            /// Returns the DDragon id of the champion ("MonkeyKing", "Samira", ...).
+            pub fn id(&self) -> &'static str {
+                match self {
+                    $(ChampionId::$champion => stringify!($champion),)*
+                }
+            }
+        }
+    };
+}
+
+mod generated;
+
+pub use generated::ChampionId;
+
+impl ChampionId {
+    /// Returns the numeric key of the champion, e.g. 360 for Samira.
+    pub fn key(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns the champion matching a numeric key, None if it is unknown.
+    pub fn from_key(key: i32) -> Option<ChampionId> {
+        ChampionId::ALL
+            .iter()
+            .find(|champion| champion.key() == key)
+            .copied()
+    }
+
+    /// Returns the champion matching a DDragon id, None if it is unknown.
+    pub fn from_id(id: &str) -> Option<ChampionId> {
+        ChampionId::ALL
+            .iter()
+            .find(|champion| champion.id() == id)
+            .copied()
+    }
+}
+
+impl Serialize for ChampionId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChampionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ChampionId, D::Error> {
+        let key = i32::deserialize(deserializer)?;
+        ChampionId::from_key(key).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Signed(key as i64), &"a champion key")
+        })
+    }
+}
+
+impl fmt::Display for ChampionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
@@ -0,0 +1,369 @@
+//! Crawls matches from player to player: the matches of a player lead to its teammates and opponents,
+//! whose matches are crawled in turn.
+//! The progress is saved to a checkpoint file so long crawls can be stopped and resumed without fetching
+//! the same matches again.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cancellation::CancellationToken,
+    error::SamiraError,
+    filters::match_ids_filter::MatchIdsFilter,
+    ids::{MatchId, Puuid},
+    models::match_model::Match,
+    region::Region,
+    riot_api::RiotApi,
+};
+
+/// The progress of a [`Crawler`], saved to resume it.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CrawlCheckpoint {
+    /// The players whose matches are still to be listed, the next first.
+    pub players: VecDeque<Puuid>,
+    /// The matches still to be fetched, the next first.
+    pub matches: VecDeque<MatchId>,
+    /// The players whose matches were listed.
+    pub visited_players: HashSet<Puuid>,
+    /// The matches which were fetched or are still to be fetched, they are never queued twice.
+    pub seen_matches: HashSet<MatchId>,
+    /// The failed attempts of the queued matches which could not be fetched yet.
+    pub attempts: HashMap<MatchId, u32>,
+    /// The players of `players`, so a player is never queued twice.
+    #[serde(skip)]
+    queued_players: HashSet<Puuid>,
+}
+
+impl CrawlCheckpoint {
+    /// Reads a checkpoint, None if the file does not exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Option<CrawlCheckpoint>, SamiraError> {
+        match fs::read(path) {
+            Ok(content) => {
+                let mut checkpoint: CrawlCheckpoint = serde_json::from_slice(&content)?;
+                checkpoint.queued_players = checkpoint.players.iter().cloned().collect();
+                Ok(Some(checkpoint))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Writes the checkpoint, replacing the previous one.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SamiraError> {
+        let path = path.as_ref();
+        let mut temporary = PathBuf::from(path);
+        temporary.as_mut_os_string().push(".tmp");
+        // Written then renamed so a crash never leaves a partial checkpoint.
+        let written = serde_json::to_vec(self)
+            .map_err(io::Error::from)
+            .and_then(|content| fs::write(&temporary, content))
+            .and_then(|_| fs::rename(&temporary, path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+        Ok(written?)
+    }
+
+    /// Returns true if there is nothing left to crawl.
+    pub fn is_done(&self) -> bool {
+        self.matches.is_empty()
+            && self
+                .players
+                .iter()
+                .all(|puuid| self.visited_players.contains(puuid))
+    }
+
+    /// Queues a player unless it was already visited or queued.
+    fn queue_player(&mut self, puuid: Puuid) {
+        if !self.visited_players.contains(&puuid) && self.queued_players.insert(puuid.clone()) {
+            self.players.push_back(puuid);
+        }
+    }
+
+    fn next_player(&mut self) -> Option<Puuid> {
+        let puuid = self.players.pop_front()?;
+        self.queued_players.remove(&puuid);
+        Some(puuid)
+    }
+}
+
+/// The outcome of a [`Crawler::step`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CrawlStep {
+    /// The matches fetched.
+    pub matches: Vec<Match>,
+    /// The matches which could not be fetched.
+    /// They are queued again after a rate limit, a server or a transport error, until they fail too many times,
+    /// and after an invalid token or a cancellation, which stops the crawl.
+    pub failed: Vec<(MatchId, SamiraError)>,
+}
+
+/// Crawls the matches of a region, starting from seed players.
+/// With a checkpoint file, the progress is saved every few steps and when the crawl stops,
+/// and a new crawler given the same file resumes where the previous one stopped.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{crawler::*, ids::*, region::*, riot_api::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new()
+///     .with_json("*/lol/match/v5/matches/by-puuid/PUUID/ids*", r#"["EUW1_6000000000", "EUW1_6000000001"]"#)
+///     .with_json("*/lol/match/v5/matches/by-puuid/*/ids*", "[]")
+///     .with_json("*/lol/match/v5/matches/EUW1_600000000*", r#"{
+///         "metadata": {"dataVersion": "2", "matchId": "EUW1_6000000000", "participants": ["PUUID", "OTHER"]},
+///         "info": {
+///             "gameCreation": 1700000000000, "gameDuration": 1800, "gameId": 6000000000, "gameMode": "CLASSIC",
+///             "gameName": "", "gameStartTimestamp": 1700000000000, "gameType": "MATCHED_GAME",
+///             "gameVersion": "13.22.541.5047", "mapId": 11, "participants": [], "platformId": "EUW1", "queueId": 420,
+///             "teams": []
+///         }
+///     }"#);
+/// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+/// let checkpoint = std::env::temp_dir().join("samira-crawler-doctest.json");
+/// let _ = std::fs::remove_file(&checkpoint);
+///
+/// let mut crawler = Crawler::new(api.clone(), Region::EUROPE, ["PUUID".into()]).with_checkpoint(&checkpoint).unwrap();
+/// let step = crawler.step().unwrap().unwrap();
+/// assert_eq!(step.matches.len(), 2);
+/// crawler.save_checkpoint().unwrap();
+///
+/// // The teammates and opponents are crawled next, once each, and the matches are not fetched again.
+/// let resumed = Crawler::new(api, Region::EUROPE, []).with_checkpoint(&checkpoint).unwrap();
+/// assert_eq!(resumed.checkpoint().players, [Puuid::from("OTHER")]);
+/// assert!(resumed.checkpoint().seen_matches.contains(&MatchId::from("EUW1_6000000000")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Crawler {
+    api: RiotApi,
+    region: Region,
+    filter: MatchIdsFilter,
+    max_concurrency: usize,
+    max_attempts: u32,
+    checkpoint_path: Option<PathBuf>,
+    checkpoint_every: usize,
+    state: CrawlCheckpoint,
+}
+
+impl Crawler {
+    /// Creates a crawler of the matches of a region, starting from the seed players.
+    /// It fetches up to 4 matches at once, tries each match 3 times at most
+    /// and lists the matches of the players with the default filter.
+    pub fn new(api: RiotApi, region: Region, seeds: impl IntoIterator<Item = Puuid>) -> Crawler {
+        let mut state = CrawlCheckpoint::default();
+        for seed in seeds {
+            state.queue_player(seed);
+        }
+        Crawler {
+            api,
+            region,
+            filter: MatchIdsFilter::new(),
+            max_concurrency: 4,
+            max_attempts: 3,
+            checkpoint_path: None,
+            checkpoint_every: 10,
+            state,
+        }
+    }
+
+    /// Sets the filter listing the matches of each player, e.g. to only crawl a queue.
+    pub fn with_filter(mut self, filter: MatchIdsFilter) -> Crawler {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets how many matches are fetched at once, the requests still go through the rate limiter.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Crawler {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Sets how many times a match failing with a rate limit, a server or a transport error is fetched
+    /// before it is dropped, 3 by default.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{crawler::*, error::*, region::*, retry_policy::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/lol/match/v5/matches/by-puuid/PUUID/ids*", r#"["EUW1_6000000000"]"#)
+    ///     .with_response("*/lol/match/v5/matches/EUW1_6000000000", 503, &[], "");
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE")
+    ///     .with_transport(transport)
+    ///     .with_retry_policy(RetryPolicy::none());
+    ///
+    /// let mut crawler = Crawler::new(api, Region::EUROPE, ["PUUID".into()]).with_max_attempts(2);
+    /// let step = crawler.step().unwrap().unwrap();
+    /// assert_eq!(step.failed[0].1, SamiraError::Http { status: 503 });
+    /// assert_eq!(crawler.checkpoint().matches.len(), 1);
+    /// // The second failure drops the match, so the crawl ends.
+    /// crawler.step().unwrap();
+    /// assert!(crawler.checkpoint().is_done());
+    /// assert_eq!(crawler.step(), Ok(None));
+    /// ```
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Crawler {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Saves the progress to a checkpoint file, resuming from it if it exists.
+    /// The seed players which were not visited yet are added to the resumed crawl.
+    pub fn with_checkpoint(mut self, path: impl AsRef<Path>) -> Result<Crawler, SamiraError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(mut state) = CrawlCheckpoint::load(&path)? {
+            while let Some(seed) = self.state.next_player() {
+                state.queue_player(seed);
+            }
+            self.state = state;
+        }
+        self.checkpoint_path = Some(path);
+        Ok(self)
+    }
+
+    /// Sets after how many steps [`Crawler::run`] saves the checkpoint, 10 by default.
+    pub fn with_checkpoint_every(mut self, steps: usize) -> Crawler {
+        self.checkpoint_every = steps.max(1);
+        self
+    }
+
+    /// Returns the progress of the crawl.
+    pub fn checkpoint(&self) -> &CrawlCheckpoint {
+        &self.state
+    }
+
+    /// Saves the progress to the checkpoint file, if any.
+    pub fn save_checkpoint(&self) -> Result<(), SamiraError> {
+        match &self.checkpoint_path {
+            Some(path) => self.state.save(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Fetches the queued matches, listing the matches of the next player first if none are queued.
+    /// The players of the fetched matches are queued in turn.
+    /// Returns None once there is nothing left to crawl.
+    ///
+    /// If the matches of the player cannot be listed it returns the error and the player is queued again.
+    /// If no match could be fetched because the token is invalid or the crawl was cancelled, it returns that error.
+    pub fn step(&mut self) -> Result<Option<CrawlStep>, SamiraError> {
+        if self.state.matches.is_empty() && !self.list_next_player()? {
+            return Ok(None);
+        }
+        let match_ids: Vec<MatchId> = self.state.matches.drain(..).collect();
+        let region = &self.region;
+        let fetched = self
+            .api
+            .get_many(&match_ids, self.max_concurrency, |api, match_id| {
+                api.try_get_match(region, match_id)
+            });
+        let mut step = CrawlStep::default();
+        let mut stopped = None;
+        for (match_id, fetched) in match_ids.into_iter().zip(fetched) {
+            match fetched {
+                Ok(game) => {
+                    self.state.attempts.remove(&match_id);
+                    for puuid in &game.metadata.participants {
+                        self.state.queue_player(puuid.clone());
+                    }
+                    step.matches.push(game);
+                }
+                Err(error) => {
+                    match &error {
+                        // The match is fine, it is fetched again once the crawl resumes.
+                        SamiraError::InvalidToken | SamiraError::Cancelled => {
+                            self.state.matches.push_back(match_id.clone());
+                            stopped = Some(error.clone());
+                        }
+                        SamiraError::RateLimited { .. }
+                        | SamiraError::Http { status: 500..=599 }
+                        | SamiraError::Transport(_) => {
+                            let attempts = self.state.attempts.entry(match_id.clone()).or_default();
+                            *attempts += 1;
+                            match *attempts < self.max_attempts {
+                                true => self.state.matches.push_back(match_id.clone()),
+                                false => {
+                                    self.state.attempts.remove(&match_id);
+                                }
+                            }
+                        }
+                        // Fetching the match again would fail the same way.
+                        _ => {
+                            self.state.attempts.remove(&match_id);
+                        }
+                    }
+                    step.failed.push((match_id, error));
+                }
+            }
+        }
+        match stopped {
+            Some(error) if step.matches.is_empty() => Err(error),
+            _ => Ok(Some(step)),
+        }
+    }
+
+    /// Crawls until there is nothing left or the token is cancelled, calling `on_step` after each step.
+    /// The checkpoint is saved every few steps and when the crawl stops, even on an error.
+    pub fn run(
+        &mut self,
+        cancellation: &CancellationToken,
+        mut on_step: impl FnMut(CrawlStep),
+    ) -> Result<(), SamiraError> {
+        let mut steps = 0;
+        while !cancellation.is_cancelled() {
+            match self.step() {
+                Ok(Some(step)) => on_step(step),
+                Ok(None) => break,
+                Err(error) => {
+                    self.save_checkpoint()?;
+                    return Err(error);
+                }
+            }
+            steps += 1;
+            if steps % self.checkpoint_every == 0 {
+                self.save_checkpoint()?;
+            }
+        }
+        self.save_checkpoint()
+    }
+
+    /// Lists the matches of the next player not visited yet, returning false if there is none.
+    fn list_next_player(&mut self) -> Result<bool, SamiraError> {
+        while let Some(puuid) = self.state.next_player() {
+            if self.state.visited_players.contains(&puuid) {
+                continue;
+            }
+            let match_ids = match self
+                .api
+                .try_get_match_ids(&self.region, &puuid, &self.filter)
+            {
+                Ok(match_ids) => match_ids,
+                Err(SamiraError::NotFound) => Vec::new(),
+                Err(error) => {
+                    self.state.queue_player(puuid);
+                    return Err(error);
+                }
+            };
+            for match_id in match_ids {
+                if self.state.seen_matches.insert(match_id.clone()) {
+                    self.state.matches.push_back(match_id);
+                }
+            }
+            self.state.visited_players.insert(puuid);
+            if !self.state.matches.is_empty() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -0,0 +1,205 @@
+//! Estimates the damage of the spells of the champions from their DDragon effects and ratios,
+//! and the stats of the champion casting them.
+//! Only the base damage written in the effect arrays and the ratios written in the vars are taken into account:
+//! the passives, the items and the resistances of the target are left to the caller.
+
+use crate::models::champion_model::{Spell, Stats};
+
+/// The stats of the champion casting a spell, the ratios of the spells scale with.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{damage::*, models::champion_model::*};
+///
+/// let stats = Stats {attackdamage: 60.0, attackdamageperlevel: 3.0, hp: 600.0, hpperlevel: 100.0, ..Default::default()};
+/// let samira = ChampionStats::at_level(&stats, 1).bonus_attack_damage(40.0);
+/// assert_eq!(samira.stat("attackdamage"), Some(100.0));
+/// assert_eq!(samira.stat("bonusattackdamage"), Some(40.0));
+/// assert_eq!(samira.stat("@special.nothing"), None);
+/// // The stats grow faster at each level.
+/// assert_eq!(ChampionStats::at_level(&stats, 18).base_health, 600.0 + 100.0 * 17.0 * (0.7025 + 0.0175 * 17.0));
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct ChampionStats {
+    /// The level of the champion, from 1 to 18.
+    pub level: u32,
+    pub ability_power: f64,
+    pub base_attack_damage: f64,
+    pub bonus_attack_damage: f64,
+    pub base_health: f64,
+    pub bonus_health: f64,
+    pub base_armor: f64,
+    pub bonus_armor: f64,
+    pub base_magic_resist: f64,
+    pub bonus_magic_resist: f64,
+    pub base_mana: f64,
+    pub bonus_mana: f64,
+}
+
+impl ChampionStats {
+    /// Returns the base stats of a champion at a level, without items nor runes.
+    pub fn at_level(stats: &Stats, level: u32) -> ChampionStats {
+        let level = level.clamp(1, 18);
+        // The growth of the stats is not linear, it speeds up with the levels.
+        let levels = f64::from(level - 1);
+        let grown =
+            |base: f64, per_level: f64| base + per_level * levels * (0.7025 + 0.0175 * levels);
+        ChampionStats {
+            level,
+            base_attack_damage: grown(stats.attackdamage, stats.attackdamageperlevel),
+            base_health: grown(stats.hp, stats.hpperlevel),
+            base_armor: grown(stats.armor, stats.armorperlevel),
+            base_magic_resist: grown(stats.spellblock, stats.spellblockperlevel),
+            base_mana: grown(stats.mp, stats.mpperlevel),
+            ..Default::default()
+        }
+    }
+
+    pub fn ability_power(mut self, ability_power: f64) -> ChampionStats {
+        self.ability_power = ability_power;
+        self
+    }
+
+    pub fn bonus_attack_damage(mut self, bonus_attack_damage: f64) -> ChampionStats {
+        self.bonus_attack_damage = bonus_attack_damage;
+        self
+    }
+
+    pub fn bonus_health(mut self, bonus_health: f64) -> ChampionStats {
+        self.bonus_health = bonus_health;
+        self
+    }
+
+    pub fn bonus_armor(mut self, bonus_armor: f64) -> ChampionStats {
+        self.bonus_armor = bonus_armor;
+        self
+    }
+
+    pub fn bonus_magic_resist(mut self, bonus_magic_resist: f64) -> ChampionStats {
+        self.bonus_magic_resist = bonus_magic_resist;
+        self
+    }
+
+    pub fn bonus_mana(mut self, bonus_mana: f64) -> ChampionStats {
+        self.bonus_mana = bonus_mana;
+        self
+    }
+
+    /// Returns the stat a ratio links to ("spelldamage", "bonusattackdamage", ...), None if it is not a stat.
+    pub fn stat(&self, link: &str) -> Option<f64> {
+        match link {
+            "spelldamage" => Some(self.ability_power),
+            "attackdamage" => Some(self.base_attack_damage + self.bonus_attack_damage),
+            "bonusattackdamage" => Some(self.bonus_attack_damage),
+            "health" | "maxhealth" => Some(self.base_health + self.bonus_health),
+            "bonushealth" => Some(self.bonus_health),
+            "armor" => Some(self.base_armor + self.bonus_armor),
+            "bonusarmor" => Some(self.bonus_armor),
+            "spellblock" => Some(self.base_magic_resist + self.bonus_magic_resist),
+            "bonusspellblock" => Some(self.bonus_magic_resist),
+            "mana" => Some(self.base_mana + self.bonus_mana),
+            "bonusmana" => Some(self.bonus_mana),
+            "@player.level" | "level" => Some(f64::from(self.level)),
+            _ => None,
+        }
+    }
+}
+
+/// An estimate of the damage of a spell at a rank.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct SpellDamage {
+    /// The damage of the effect array at the rank.
+    pub base: f64,
+    /// The damage added by the ratios.
+    pub scaling: f64,
+}
+
+impl SpellDamage {
+    pub fn total(&self) -> f64 {
+        self.base + self.scaling
+    }
+}
+
+impl Spell {
+    /// Returns the value of an effect ("e1" being 1) at a rank of the spell, starting at 1.
+    pub fn effect_at(&self, effect: usize, rank: usize) -> Option<f64> {
+        self.effect
+            .get(effect)?
+            .as_ref()?
+            .get(rank.checked_sub(1)?)
+            .copied()
+    }
+
+    /// Estimates the damage of an effect of the spell ("e1" being 1) at a rank, starting at 1.
+    /// The ratios added to the effect are those following it in the tooltip, until the next effect:
+    /// in "deals {{ e1 }} (+{{ a1 }}) damage" the ratio `a1` scales `e1`.
+    /// Returns None if the spell has no such effect or rank.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{damage::*, models::champion_model::*};
+    ///
+    /// let disintegrate: Spell = serde_json::from_str(r#"{
+    ///     "id": "AnnieQ", "name": "Disintegrate", "maxrank": 5,
+    ///     "tooltip": "Deals {{ e1 }} <span class=\"color99FF99\">(+{{ a1 }})</span> magic damage.",
+    ///     "effect": [null, [80, 115, 150, 185, 220]],
+    ///     "vars": [{"link": "spelldamage", "coeff": 0.8, "key": "a1"}]
+    /// }"#).unwrap();
+    /// let annie = ChampionStats::default().ability_power(100.0);
+    /// let damage = disintegrate.damage(1, 3, &annie).unwrap();
+    /// assert_eq!(damage.base, 150.0);
+    /// assert_eq!(damage.total(), 230.0);
+    /// assert_eq!(disintegrate.damage(1, 6, &annie), None);
+    /// ```
+    pub fn damage(&self, effect: usize, rank: usize, stats: &ChampionStats) -> Option<SpellDamage> {
+        let placeholder = format!("e{}", effect);
+        let vars: Vec<&str> = placeholders(&self.tooltip)
+            .skip_while(|key| *key != placeholder)
+            .skip(1)
+            .take_while(|key| !key.starts_with('e'))
+            .collect();
+        self.damage_with(effect, rank, &vars, stats)
+    }
+
+    /// Estimates the damage of an effect of the spell at a rank, scaled by the given ratios ("a1", "f2", ...),
+    /// for the spells whose tooltip does not tell which ratios scale the effect.
+    /// The ratios the spell does not have or linked to an unknown stat are ignored.
+    pub fn damage_with(
+        &self,
+        effect: usize,
+        rank: usize,
+        vars: &[&str],
+        stats: &ChampionStats,
+    ) -> Option<SpellDamage> {
+        let base = self.effect_at(effect, rank)?;
+        let scaling = vars
+            .iter()
+            .filter_map(|key| {
+                let var = self.var(key)?;
+                Some(var.coeff_at(rank)? * stats.stat(&var.link)?)
+            })
+            .sum();
+        Some(SpellDamage { base, scaling })
+    }
+
+    /// Estimates the damage of an effect at every rank of the spell, the first rank first.
+    pub fn damage_per_rank(&self, effect: usize, stats: &ChampionStats) -> Vec<SpellDamage> {
+        (1..=self.maxrank.max(0) as usize)
+            .map_while(|rank| self.damage(effect, rank, stats))
+            .collect()
+    }
+}
+
+/// Returns the keys of the placeholders of a tooltip ("e1", "a1", ...), in order.
+fn placeholders(tooltip: &str) -> impl Iterator<Item = &str> {
+    tooltip.split("{{").skip(1).filter_map(|placeholder| {
+        let (key, _) = placeholder.split_once("}}")?;
+        Some(key.trim())
+    })
+}
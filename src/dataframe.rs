@@ -0,0 +1,92 @@
+//! Converts the models into polars DataFrames, one column per field, ready for analysis.
+//! Nested fields are flattened into dotted columns ("stats.hp", "challenges.kda"),
+//! and the lists are written as JSON strings.
+
+use polars::prelude::*;
+use serde_json::Value;
+
+use crate::{
+    models::{champion_model::Champion, league_model::LeagueEntry, match_model::Match},
+    tabular::{self, ColumnKind},
+};
+
+/// Converts a list of models into a DataFrame, one row per model.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{dataframe::*, models::league_model::*};
+///
+/// let entries = vec![
+///     LeagueEntry {tier: "GOLD".to_string(), rank: "II".to_string(), league_points: 56, wins: 20, losses: 18, ..Default::default()},
+///     LeagueEntry {tier: "SILVER".to_string(), rank: "I".to_string(), league_points: 12, wins: 5, losses: 7, ..Default::default()},
+/// ];
+/// let dataframe = entries.to_dataframe().unwrap();
+/// assert_eq!(dataframe.height(), 2);
+/// let league_points = dataframe.column("leaguePoints").unwrap().i64().unwrap();
+/// assert_eq!(league_points.get(0), Some(56));
+/// assert_eq!(dataframe.column("tier").unwrap().str().unwrap().get(1), Some("SILVER"));
+/// ```
+pub trait ToDataFrame {
+    fn to_dataframe(&self) -> PolarsResult<DataFrame>;
+}
+
+/// One row per participant, with the match id and the match info fields ("gameDuration", "queueId", ...) on every row,
+/// the teams excluded.
+impl ToDataFrame for [Match] {
+    fn to_dataframe(&self) -> PolarsResult<DataFrame> {
+        to_dataframe(tabular::participant_rows(self).map_err(to_polars_error)?)
+    }
+}
+
+impl ToDataFrame for [LeagueEntry] {
+    fn to_dataframe(&self) -> PolarsResult<DataFrame> {
+        let rows: Result<Vec<_>, _> = self.iter().map(tabular::to_row).collect();
+        to_dataframe(rows.map_err(to_polars_error)?)
+    }
+}
+
+/// One row per champion, without its texts, skins and spells.
+impl ToDataFrame for [Champion] {
+    fn to_dataframe(&self) -> PolarsResult<DataFrame> {
+        let mut rows = Vec::new();
+        for champion in self {
+            let mut row = tabular::to_row(champion).map_err(to_polars_error)?;
+            for field in [
+                "skins",
+                "lore",
+                "blurb",
+                "allytips",
+                "enemytips",
+                "spells",
+                "passive",
+            ] {
+                row.remove(field);
+            }
+            rows.push(row);
+        }
+        to_dataframe(rows)
+    }
+}
+
+fn to_dataframe(rows: Vec<serde_json::Map<String, Value>>) -> PolarsResult<DataFrame> {
+    let columns = tabular::columns(rows)
+        .into_iter()
+        .map(|column| {
+            let name = PlSmallStr::from(column.name.as_str());
+            match column.kind {
+                ColumnKind::Boolean => Column::new(name, column.booleans()),
+                ColumnKind::Integer => Column::new(name, column.integers()),
+                ColumnKind::Float => Column::new(name, column.floats()),
+                ColumnKind::Text => Column::new(name, column.texts()),
+            }
+        })
+        .collect();
+    DataFrame::new(columns)
+}
+
+fn to_polars_error(error: serde_json::Error) -> PolarsError {
+    PolarsError::ComputeError(error.to_string().into())
+}
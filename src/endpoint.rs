@@ -0,0 +1,44 @@
+use crate::cache::CacheCategory;
+
+/// A request to an endpoint of the Riot API, described without sending it.
+/// The blocking client sends it with `RiotApi::send`; an async client would send the same descriptions,
+/// so an endpoint is only defined once whatever the transport.
+pub(crate) struct Endpoint<'a> {
+    /// The routing value the request counts against in the rate limits ("euw1", "europe", ...).
+    pub(crate) route: &'a str,
+    /// The method id of the endpoint ("summoner-v4.getByPUUID", ...), rate limited on its own.
+    pub(crate) method: &'static str,
+    pub(crate) category: CacheCategory,
+    pub(crate) url: String,
+}
+
+/// Defines endpoints of the Riot API from their route, path and method id.
+/// Each definition generates a function building its [`Endpoint`] and sending it through the client,
+/// the path segments being percent-encoded before they are written in the url.
+macro_rules! endpoint {
+    ($(
+        $(#[$attr:meta])*
+        fn $name:ident($route:ident: &$route_type:ident $(, $segment:ident: &str)* $(,)?)
+            => $method:literal, $category:ident, $path:literal;
+    )*) => {$(
+        $(#[$attr])*
+        fn $name(
+            api: &RiotApi,
+            $route: &$route_type,
+            $($segment: &str,)*
+        ) -> Result<Option<Value>, SamiraError> {
+            api.send(&Endpoint {
+                route: $route.id(),
+                method: $method,
+                category: CacheCategory::$category,
+                url: format!(
+                    concat!("{server}", $path),
+                    server = api.route_url($route),
+                    $($segment = encode_component($segment),)*
+                ),
+            })
+        }
+    )*};
+}
+
+pub(crate) use endpoint;
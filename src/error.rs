@@ -0,0 +1,96 @@
+use std::{error::Error, fmt, io};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamiraError {
+    /// The server answered with an unexpected HTTP status.
+    Http { status: u16 },
+    /// The rate limit was exceeded, `retry_after` is given in seconds when the server provides it.
+    RateLimited { retry_after: Option<u64> },
+    /// The response could not be deserialized into the expected model.
+    Deserialization(String),
+    /// The requested resource does not exist.
+    NotFound,
+    /// The token is missing, invalid or expired.
+    InvalidToken,
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    /// The request was cancelled or its deadline passed.
+    Cancelled,
+    /// The filter of a request is invalid, e.g. it has no field set.
+    InvalidFilter(String),
+    /// A rune page breaks the rules of the rune trees, e.g. two runes of the same slot.
+    InvalidRunePage(String),
+    /// A match store could not be read or written.
+    Storage(String),
+}
+
+impl SamiraError {
+    pub(crate) fn from_status(status: u16, retry_after: Option<&str>) -> SamiraError {
+        match status {
+            401 | 403 => SamiraError::InvalidToken,
+            404 => SamiraError::NotFound,
+            429 => SamiraError::RateLimited {
+                retry_after: retry_after.and_then(|retry_after| retry_after.parse().ok()),
+            },
+            _ => SamiraError::Http { status },
+        }
+    }
+
+    pub(crate) fn malformed(message: &str) -> SamiraError {
+        SamiraError::Deserialization(message.to_string())
+    }
+}
+
+impl fmt::Display for SamiraError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SamiraError::Http { status } => write!(f, "unexpected HTTP status {}", status),
+            SamiraError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {}s", retry_after),
+            SamiraError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            SamiraError::Deserialization(message) => {
+                write!(f, "could not deserialize response: {}", message)
+            }
+            SamiraError::NotFound => write!(f, "resource not found"),
+            SamiraError::InvalidToken => write!(f, "invalid token"),
+            SamiraError::Transport(message) => write!(f, "transport error: {}", message),
+            SamiraError::Cancelled => write!(f, "request cancelled"),
+            SamiraError::InvalidFilter(message) => write!(f, "invalid filter: {}", message),
+            SamiraError::InvalidRunePage(message) => write!(f, "invalid rune page: {}", message),
+            SamiraError::Storage(message) => write!(f, "storage error: {}", message),
+        }
+    }
+}
+
+impl Error for SamiraError {}
+
+#[cfg(feature = "ureq")]
+impl From<ureq::Error> for SamiraError {
+    fn from(error: ureq::Error) -> SamiraError {
+        match error {
+            ureq::Error::Status(status, response) => {
+                SamiraError::from_status(status, response.header("Retry-After"))
+            }
+            ureq::Error::Transport(transport) => SamiraError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for SamiraError {
+    fn from(error: io::Error) -> SamiraError {
+        match error.kind() {
+            io::ErrorKind::InvalidData => SamiraError::Deserialization(error.to_string()),
+            _ => SamiraError::Transport(error.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for SamiraError {
+    fn from(error: serde_json::Error) -> SamiraError {
+        match error.is_io() {
+            true => SamiraError::Transport(error.to_string()),
+            false => SamiraError::Deserialization(error.to_string()),
+        }
+    }
+}
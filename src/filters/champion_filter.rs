@@ -0,0 +1,98 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+// std's SystemTime panics in browsers.
+#[cfg(target_arch = "wasm32")]
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::champion_model::Champion;
+
+/// The filters of a random champion roll, see [`UtilsApi::random_champion`](crate::utils_api::UtilsApi::random_champion).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{filters::champion_filter::*, models::champion_model::*};
+///
+/// let filter = ChampionFilter::new().tag("Marksman").partype("mana").exclude(360);
+/// let jinx = Champion {key: 222, tags: vec!["Marksman".to_string()], partype: "Mana".to_string(), ..Default::default()};
+/// let samira = Champion {key: 360, tags: vec!["Marksman".to_string()], partype: "None".to_string(), ..Default::default()};
+/// assert!(filter.matches(&jinx));
+/// assert!(!filter.matches(&samira));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct ChampionFilter {
+    /// Only the champions with one of these tags ("Mage", "Assassin", ...), whatever their case. Any champion if empty.
+    pub tags: Vec<String>,
+    /// Only the champions using this resource ("Mana", "Energy", "None", ...), whatever its case.
+    pub partype: Option<String>,
+    /// The keys of the champions never rolled, e.g. the champions already picked.
+    pub excluded: Vec<i64>,
+    /// The seed of the roll, the same seed rolling the same champions from the same version.
+    /// The roll is seeded from the clock if None.
+    pub seed: Option<u64>,
+}
+
+impl ChampionFilter {
+    /// Creates a filter rolling any champion.
+    pub fn new() -> ChampionFilter {
+        ChampionFilter::default()
+    }
+
+    /// Adds a tag the champions can have.
+    pub fn tag(mut self, tag: &str) -> ChampionFilter {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Only rolls the champions using this resource.
+    pub fn partype(mut self, partype: &str) -> ChampionFilter {
+        self.partype = Some(partype.to_string());
+        self
+    }
+
+    /// Never rolls the champion of this key.
+    pub fn exclude(mut self, key: i64) -> ChampionFilter {
+        self.excluded.push(key);
+        self
+    }
+
+    /// Never rolls the champions of these keys.
+    pub fn exclude_all(mut self, keys: impl IntoIterator<Item = i64>) -> ChampionFilter {
+        self.excluded.extend(keys);
+        self
+    }
+
+    /// Seeds the roll, to replay it.
+    pub fn seed(mut self, seed: u64) -> ChampionFilter {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns true if the champion can be rolled.
+    pub fn matches(&self, champion: &Champion) -> bool {
+        (self.tags.is_empty()
+            || champion.tags.iter().any(|tag| {
+                self.tags
+                    .iter()
+                    .any(|wanted| wanted.eq_ignore_ascii_case(tag))
+            }))
+            && self
+                .partype
+                .as_ref()
+                .is_none_or(|partype| partype.eq_ignore_ascii_case(&champion.partype))
+            && !self.excluded.contains(&champion.key)
+    }
+
+    /// Returns the seed of the roll, from the clock if none was set.
+    pub(crate) fn seed_or_clock(&self) -> u64 {
+        self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+        })
+    }
+}
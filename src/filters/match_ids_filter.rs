@@ -0,0 +1,116 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{game::MatchType, queue::Queue};
+
+/// The filters of the match ids of a player, see [`RiotApi::get_match_ids`](crate::riot_api::RiotApi::get_match_ids).
+/// Times accept anything converting into a [`SystemTime`], such as chrono's `DateTime`,
+/// and are sent as the epoch seconds Riot expects.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use samira::{filters::match_ids_filter::*, game::*, queue::*};
+///
+/// let filter = MatchIdsFilter::new()
+///     .start_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+///     .queue(Queue::RANKED_SOLO_5x5)
+///     .match_type(MatchType::RANKED)
+///     .count(20);
+/// assert_eq!(
+///     filter.to_query(),
+///     [("startTime", "1700000000"), ("queue", "420"), ("type", "ranked"), ("count", "20")]
+///         .map(|(name, value)| (name, value.to_string())),
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct MatchIdsFilter {
+    /// Only the matches played after this time, Riot only keeps the matches played since June 16th, 2021.
+    pub start_time: Option<SystemTime>,
+    /// Only the matches played before this time.
+    pub end_time: Option<SystemTime>,
+    pub queue: Option<Queue>,
+    pub match_type: Option<MatchType>,
+    /// The index of the first match id, 0 by default.
+    pub start: Option<u32>,
+    /// The number of match ids, from 0 to 100, 20 by default.
+    pub count: Option<u32>,
+}
+
+impl MatchIdsFilter {
+    /// Creates a filter listing the latest 20 matches.
+    pub fn new() -> MatchIdsFilter {
+        MatchIdsFilter::default()
+    }
+
+    /// Only lists the matches played after this time.
+    pub fn start_time(mut self, start_time: impl Into<SystemTime>) -> MatchIdsFilter {
+        self.start_time = Some(start_time.into());
+        self
+    }
+
+    /// Only lists the matches played before this time.
+    pub fn end_time(mut self, end_time: impl Into<SystemTime>) -> MatchIdsFilter {
+        self.end_time = Some(end_time.into());
+        self
+    }
+
+    /// Only lists the matches of a queue.
+    pub fn queue(mut self, queue: Queue) -> MatchIdsFilter {
+        self.queue = Some(queue);
+        self
+    }
+
+    /// Only lists the matches of a type.
+    pub fn match_type(mut self, match_type: MatchType) -> MatchIdsFilter {
+        self.match_type = Some(match_type);
+        self
+    }
+
+    /// Skips the latest `start` matches, to page through the history.
+    pub fn start(mut self, start: u32) -> MatchIdsFilter {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the number of match ids listed, at most 100.
+    pub fn count(mut self, count: u32) -> MatchIdsFilter {
+        self.count = Some(count);
+        self
+    }
+
+    /// Returns the query parameters of the filter as Riot expects them.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(start_time) = self.start_time {
+            query.push(("startTime", epoch_seconds(start_time).to_string()));
+        }
+        if let Some(end_time) = self.end_time {
+            query.push(("endTime", epoch_seconds(end_time).to_string()));
+        }
+        if let Some(queue) = self.queue {
+            query.push(("queue", queue.id().to_string()));
+        }
+        if let Some(match_type) = self.match_type {
+            query.push(("type", match_type.as_str().to_string()));
+        }
+        if let Some(start) = self.start {
+            query.push(("start", start.to_string()));
+        }
+        if let Some(count) = self.count {
+            query.push(("count", count.to_string()));
+        }
+        query
+    }
+}
+
+/// Times before the epoch are clamped to it, Riot has no match that old.
+fn epoch_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
@@ -1 +1,3 @@
+pub mod champion_filter;
+pub mod match_ids_filter;
 pub mod summoner_filter;
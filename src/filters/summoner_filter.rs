@@ -1,9 +1,191 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+use crate::{error::SamiraError, ids::*, models::summoner_model::Summoner};
+
+/// The identifiers a summoner is looked up by, see [`RiotApi::get_summoner`](crate::riot_api::RiotApi::get_summoner).
+/// Every identifier set is tried until one finds the summoner, so at least one is required.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::filters::summoner_filter::*;
+///
+/// let filter = SummonerFilter::by_riot_id("RqndomHax", "EUW").puuid("Y22N0dvmtG6NsF5GTpPJ4yhxI2t3zMvP5solMwWSqj1Ld");
+/// assert_eq!(filter.riot_id, Some(("RqndomHax".to_string(), "EUW".to_string())));
+/// assert!(filter.validate().is_ok());
+/// assert!(SummonerFilter::default().validate().is_err());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
 pub struct SummonerFilter {
-    pub account_id: Option<String>,
+    pub account_id: Option<AccountId>,
     pub name: Option<String>,
-    pub id: Option<String>,
-    pub puuid: Option<String>,
+    pub id: Option<SummonerId>,
+    pub puuid: Option<Puuid>,
+    /// The game name and tag line of the Riot ID, e.g. `("RqndomHax", "EUW")` for RqndomHax#EUW.
+    pub riot_id: Option<(String, String)>,
+    /// The order the identifiers are tried in, [`SummonerField::DEFAULT_ORDER`] if None.
+    /// Identifiers missing from the order are never tried.
+    pub order: Option<Vec<SummonerField>>,
+}
+
+/// An identifier of a [`SummonerFilter`], used to choose the order they are tried in.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SummonerField {
+    AccountId,
+    Name,
+    Id,
+    Puuid,
+    RiotId,
+}
+
+impl SummonerField {
+    /// The order the identifiers are tried in unless the filter sets its own.
+    pub const DEFAULT_ORDER: [SummonerField; 5] = [
+        SummonerField::AccountId,
+        SummonerField::Name,
+        SummonerField::Id,
+        SummonerField::Puuid,
+        SummonerField::RiotId,
+    ];
+
+    /// Tries the most stable identifiers first: PUUIDs never change, while names do.
+    pub const PUUID_FIRST: [SummonerField; 5] = [
+        SummonerField::Puuid,
+        SummonerField::Id,
+        SummonerField::AccountId,
+        SummonerField::RiotId,
+        SummonerField::Name,
+    ];
+}
+
+/// A summoner found by [`RiotApi::resolve_summoner`](crate::riot_api::RiotApi::resolve_summoner),
+/// with the identifiers tried to find it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedSummoner {
+    pub summoner: Summoner,
+    /// The identifier which found the summoner.
+    pub matched: SummonerField,
+    /// The identifiers tried before which found no summoner, e.g. outdated names or ids, in the order they were tried.
+    pub missed: Vec<SummonerField>,
+}
+
+impl SummonerFilter {
+    /// Creates a filter looking up a summoner by its encrypted account id.
+    pub fn by_account_id(account_id: impl Into<AccountId>) -> SummonerFilter {
+        SummonerFilter::default().account_id(account_id)
+    }
+
+    /// Creates a filter looking up a summoner by its name.
+    pub fn by_name(name: &str) -> SummonerFilter {
+        SummonerFilter::default().name(name)
+    }
+
+    /// Creates a filter looking up a summoner by its encrypted summoner id.
+    pub fn by_id(id: impl Into<SummonerId>) -> SummonerFilter {
+        SummonerFilter::default().id(id)
+    }
+
+    /// Creates a filter looking up a summoner by its PUUID.
+    pub fn by_puuid(puuid: impl Into<Puuid>) -> SummonerFilter {
+        SummonerFilter::default().puuid(puuid)
+    }
+
+    /// Creates a filter looking up a summoner by the game name and tag line of its Riot ID.
+    pub fn by_riot_id(game_name: &str, tag_line: &str) -> SummonerFilter {
+        SummonerFilter::default().riot_id(game_name, tag_line)
+    }
+
+    /// Also looks up the summoner by its encrypted account id.
+    pub fn account_id(mut self, account_id: impl Into<AccountId>) -> SummonerFilter {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Also looks up the summoner by its name.
+    pub fn name(mut self, name: &str) -> SummonerFilter {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Also looks up the summoner by its encrypted summoner id.
+    pub fn id(mut self, id: impl Into<SummonerId>) -> SummonerFilter {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Also looks up the summoner by its PUUID.
+    pub fn puuid(mut self, puuid: impl Into<Puuid>) -> SummonerFilter {
+        self.puuid = Some(puuid.into());
+        self
+    }
+
+    /// Also looks up the summoner by the game name and tag line of its Riot ID.
+    pub fn riot_id(mut self, game_name: &str, tag_line: &str) -> SummonerFilter {
+        self.riot_id = Some((game_name.to_string(), tag_line.to_string()));
+        self
+    }
+
+    /// Sets the order the identifiers are tried in, the identifiers left out are never tried.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::filters::summoner_filter::*;
+    ///
+    /// let filter = SummonerFilter::by_name("RqndomHax").puuid("PUUID").order(&[SummonerField::Name]);
+    /// assert_eq!(filter.lookup_order(), &[SummonerField::Name]);
+    /// ```
+    pub fn order(mut self, order: &[SummonerField]) -> SummonerFilter {
+        self.order = Some(order.to_vec());
+        self
+    }
+
+    /// Tries the PUUID first, since it is the most stable identifier, see [`SummonerField::PUUID_FIRST`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::filters::summoner_filter::*;
+    ///
+    /// let filter = SummonerFilter::by_name("RqndomHax").puuid("PUUID").puuid_first();
+    /// assert_eq!(filter.lookup_order()[0], SummonerField::Puuid);
+    /// ```
+    pub fn puuid_first(self) -> SummonerFilter {
+        self.order(&SummonerField::PUUID_FIRST)
+    }
+
+    /// Returns the order the identifiers are tried in.
+    pub fn lookup_order(&self) -> &[SummonerField] {
+        match &self.order {
+            Some(order) => order,
+            None => &SummonerField::DEFAULT_ORDER,
+        }
+    }
+
+    /// Returns true if no identifier is set, in which case no summoner can be found.
+    pub fn is_empty(&self) -> bool {
+        self.account_id.is_none()
+            && self.name.is_none()
+            && self.id.is_none()
+            && self.puuid.is_none()
+            && self.riot_id.is_none()
+    }
+
+    /// Checks that at least one identifier is set.
+    /// It fails with [`SamiraError::InvalidFilter`] otherwise.
+    pub fn validate(&self) -> Result<(), SamiraError> {
+        match self.is_empty() {
+            true => Err(SamiraError::InvalidFilter(
+                "at least one identifier must be set".to_string(),
+            )),
+            false => Ok(()),
+        }
+    }
 }
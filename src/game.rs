@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+/// The game mode of a match, as written by match-v5 and spectator-v4 ("CLASSIC", "ARAM", ...).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum GameMode {
+    /// Summoner's Rift games, ranked or not.
+    CLASSIC,
+    ARAM,
+    URF,
+    ONEFORALL,
+    NEXUSBLITZ,
+    ULTBOOK,
+    /// Arena games.
+    CHERRY,
+    /// Swiftplay games.
+    SWIFTPLAY,
+    PRACTICETOOL,
+    TUTORIAL,
+    TUTORIAL_MODULE_1,
+    TUTORIAL_MODULE_2,
+    TUTORIAL_MODULE_3,
+    /// Dominion games.
+    ODIN,
+    ASCENSION,
+    FIRSTBLOOD,
+    KINGPORO,
+    SIEGE,
+    ASSASSINATE,
+    ARSR,
+    DARKSTAR,
+    STARGUARDIAN,
+    PROJECT,
+    GAMEMODEX,
+    ODYSSEY,
+    DOOMBOTSTEEMO,
+    /// Swarm games.
+    STRAWBERRY,
+}
+
+impl GameMode {
+    /// Returns the game mode as written by the Riot API.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::game::*;
+    ///
+    /// let mode: GameMode = serde_json::from_str(r#""CHERRY""#).unwrap();
+    /// assert_eq!(mode, GameMode::CHERRY);
+    /// assert_eq!(mode.as_str(), "CHERRY");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameMode::CLASSIC => "CLASSIC",
+            GameMode::ARAM => "ARAM",
+            GameMode::URF => "URF",
+            GameMode::ONEFORALL => "ONEFORALL",
+            GameMode::NEXUSBLITZ => "NEXUSBLITZ",
+            GameMode::ULTBOOK => "ULTBOOK",
+            GameMode::CHERRY => "CHERRY",
+            GameMode::SWIFTPLAY => "SWIFTPLAY",
+            GameMode::PRACTICETOOL => "PRACTICETOOL",
+            GameMode::TUTORIAL => "TUTORIAL",
+            GameMode::TUTORIAL_MODULE_1 => "TUTORIAL_MODULE_1",
+            GameMode::TUTORIAL_MODULE_2 => "TUTORIAL_MODULE_2",
+            GameMode::TUTORIAL_MODULE_3 => "TUTORIAL_MODULE_3",
+            GameMode::ODIN => "ODIN",
+            GameMode::ASCENSION => "ASCENSION",
+            GameMode::FIRSTBLOOD => "FIRSTBLOOD",
+            GameMode::KINGPORO => "KINGPORO",
+            GameMode::SIEGE => "SIEGE",
+            GameMode::ASSASSINATE => "ASSASSINATE",
+            GameMode::ARSR => "ARSR",
+            GameMode::DARKSTAR => "DARKSTAR",
+            GameMode::STARGUARDIAN => "STARGUARDIAN",
+            GameMode::PROJECT => "PROJECT",
+            GameMode::GAMEMODEX => "GAMEMODEX",
+            GameMode::ODYSSEY => "ODYSSEY",
+            GameMode::DOOMBOTSTEEMO => "DOOMBOTSTEEMO",
+            GameMode::STRAWBERRY => "STRAWBERRY",
+        }
+    }
+}
+
+/// The type of a match, as written by match-v5 and spectator-v4 ("MATCHED_GAME", ...).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types, clippy::enum_variant_names)]
+pub enum GameType {
+    /// Custom games.
+    CUSTOM_GAME,
+    /// Tutorial games.
+    TUTORIAL_GAME,
+    /// Every other game, found through matchmaking.
+    MATCHED_GAME,
+}
+
+impl GameType {
+    /// Returns the game type as written by the Riot API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameType::CUSTOM_GAME => "CUSTOM_GAME",
+            GameType::TUTORIAL_GAME => "TUTORIAL_GAME",
+            GameType::MATCHED_GAME => "MATCHED_GAME",
+        }
+    }
+}
+
+/// The type of the matches listed by match-v5, used to filter the match ids of a player.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchType {
+    RANKED,
+    NORMAL,
+    TOURNEY,
+    TUTORIAL,
+}
+
+impl MatchType {
+    /// Returns the match type as written in the match-v5 queries ("ranked", "normal", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchType::RANKED => "ranked",
+            MatchType::NORMAL => "normal",
+            MatchType::TOURNEY => "tourney",
+            MatchType::TUTORIAL => "tutorial",
+        }
+    }
+}
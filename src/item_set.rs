@@ -0,0 +1,186 @@
+//! Builds item sets in the format of the League client, to be imported in its shop.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{champion_model::Champion, item_model::Item};
+
+/// An item set, serialized in the format of the League client item set files and of its `/lol-item-sets` endpoints.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{item_set::*, models::item_model::*};
+///
+/// let dorans_blade = Item { id: 1055, name: "Doran's Blade".to_string(), ..Default::default() };
+/// let potion = Item { id: 2003, name: "Health Potion".to_string(), ..Default::default() };
+/// let item_set = ItemSet::new("Samira")
+///     .champion_key(360)
+///     .map(11)
+///     .block(ItemBlock::new("Starting items").item(&dorans_blade, 1).item(&potion, 1))
+///     .block(ItemBlock::new("Core").item_id(6672, 1).item_id(3031, 1));
+///
+/// let json = serde_json::to_value(&item_set).unwrap();
+/// assert_eq!(json["associatedChampions"][0], 360);
+/// assert_eq!(json["blocks"][0]["items"][0]["id"], "1055");
+/// assert_eq!(json["blocks"][1]["type"], "Core");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ItemSet {
+    pub title: String,
+    /// "custom" for the sets made by the player.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The map of the set ("any", "SR", "HA", ...), prefer `associated_maps`.
+    pub map: String,
+    /// The game mode of the set ("any", "CLASSIC", "ARAM", ...).
+    pub mode: String,
+    /// True to show the set above the recommended items.
+    pub priority: bool,
+    #[serde(rename = "sortrank")]
+    pub sort_rank: i32,
+    /// The keys of the champions the set is shown for, every champion if empty.
+    pub associated_champions: Vec<i64>,
+    /// The ids of the maps the set is shown on, every map if empty.
+    pub associated_maps: Vec<i32>,
+    pub blocks: Vec<ItemBlock>,
+}
+
+impl Default for ItemSet {
+    fn default() -> ItemSet {
+        ItemSet {
+            title: String::new(),
+            kind: "custom".to_string(),
+            map: "any".to_string(),
+            mode: "any".to_string(),
+            priority: false,
+            sort_rank: 0,
+            associated_champions: Vec::new(),
+            associated_maps: Vec::new(),
+            blocks: Vec::new(),
+        }
+    }
+}
+
+impl ItemSet {
+    /// Creates an empty item set, shown for every champion on every map.
+    pub fn new(title: &str) -> ItemSet {
+        ItemSet {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Shows the set for a champion.
+    pub fn champion(self, champion: &Champion) -> ItemSet {
+        self.champion_key(champion.key)
+    }
+
+    /// Shows the set for a champion, from its key.
+    pub fn champion_key(mut self, key: i64) -> ItemSet {
+        self.associated_champions.push(key);
+        self
+    }
+
+    /// Shows the set on a map (11 for Summoner's Rift, 12 for Howling Abyss, ...).
+    pub fn map(mut self, map_id: i32) -> ItemSet {
+        self.associated_maps.push(map_id);
+        self
+    }
+
+    /// Sets the game mode of the set ("CLASSIC", "ARAM", ...), "any" by default.
+    pub fn mode(mut self, mode: &str) -> ItemSet {
+        self.mode = mode.to_string();
+        self
+    }
+
+    /// Shows the set above the recommended items.
+    pub fn priority(mut self, priority: bool) -> ItemSet {
+        self.priority = priority;
+        self
+    }
+
+    /// Adds a block of items below the previous ones.
+    pub fn block(mut self, block: ItemBlock) -> ItemSet {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Returns the items of the set which are not available on one of its maps, e.g. to warn before exporting it.
+    /// Items missing from `items` are ignored.
+    pub fn unavailable_items<'a>(&self, items: &'a [Item]) -> Vec<&'a Item> {
+        items
+            .iter()
+            .filter(|item| {
+                self.blocks
+                    .iter()
+                    .flat_map(|block| &block.items)
+                    .any(|entry| entry.id == item.id.to_string())
+            })
+            .filter(|item| {
+                self.associated_maps
+                    .iter()
+                    .any(|map_id| !item.is_available_on(*map_id))
+            })
+            .collect()
+    }
+}
+
+/// A titled row of items of an [`ItemSet`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ItemBlock {
+    /// The title of the block.
+    #[serde(rename = "type")]
+    pub title: String,
+    pub items: Vec<ItemSetItem>,
+    /// Only shows the block if the player took this summoner spell, e.g. "SummonerSmite".
+    pub show_if_summoner_spell: String,
+    /// Hides the block if the player took this summoner spell.
+    pub hide_if_summoner_spell: String,
+}
+
+impl ItemBlock {
+    pub fn new(title: &str) -> ItemBlock {
+        ItemBlock {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an item to the block, shown with a count if it is greater than 1.
+    pub fn item(self, item: &Item, count: i32) -> ItemBlock {
+        self.item_id(item.id, count)
+    }
+
+    /// Adds an item to the block from its id.
+    pub fn item_id(mut self, id: i64, count: i32) -> ItemBlock {
+        self.items.push(ItemSetItem {
+            id: id.to_string(),
+            count,
+        });
+        self
+    }
+
+    /// Only shows the block if the player took this summoner spell, e.g. "SummonerSmite".
+    pub fn show_if_summoner_spell(mut self, spell: &str) -> ItemBlock {
+        self.show_if_summoner_spell = spell.to_string();
+        self
+    }
+
+    /// Hides the block if the player took this summoner spell.
+    pub fn hide_if_summoner_spell(mut self, spell: &str) -> ItemBlock {
+        self.hide_if_summoner_spell = spell.to_string();
+        self
+    }
+}
+
+/// An item of an [`ItemBlock`], its id written as a string like in DDragon.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+pub struct ItemSetItem {
+    pub id: String,
+    pub count: i32,
+}
@@ -0,0 +1,353 @@
+//! The REST API of the League client (LCU), served on this machine while the client is open.
+//! Its port and password change every time the client starts, they are read from its lockfile or its command line.
+
+use std::{fmt, fs, path::Path, process::Command, sync::Arc};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use zeroize::Zeroize;
+
+use crate::{
+    error::SamiraError,
+    models::lcu_model::*,
+    request::read_json,
+    rune_page::RunePage,
+    transport::{local_transport, HttpTransport},
+};
+
+/// Where the client writes its lockfile when installed in the default directory.
+const LOCKFILE_PATHS: &[&str] = &[
+    r"C:\Riot Games\League of Legends\lockfile",
+    "/Applications/League of Legends.app/Contents/LoL/lockfile",
+];
+
+/// The port and password of a running League client, which are never printed and are erased from memory when dropped.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::lcu::*;
+///
+/// let credentials = LcuCredentials::parse_lockfile("LeagueClient:12345:54321:PASSWORD:https").unwrap();
+/// assert_eq!(credentials.port, 54321);
+/// assert_eq!(credentials.url(), "https://127.0.0.1:54321");
+/// assert_eq!(format!("{:?}", credentials).contains("PASSWORD"), false);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct LcuCredentials {
+    /// The process id of the client, 0 if it is unknown.
+    pub pid: u32,
+    pub port: u16,
+    password: String,
+}
+
+impl fmt::Debug for LcuCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LcuCredentials")
+            .field("pid", &self.pid)
+            .field("port", &self.port)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Drop for LcuCredentials {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
+
+impl LcuCredentials {
+    /// Creates credentials from the port and password of the client.
+    pub fn new(port: u16, password: &str) -> LcuCredentials {
+        LcuCredentials {
+            pid: 0,
+            port,
+            password: password.to_string(),
+        }
+    }
+
+    /// Finds the credentials of the running client, from its lockfile in the default install directory,
+    /// else from the command line of its `LeagueClientUx` process.
+    /// Fails with [`SamiraError::NotFound`] if the client is not running.
+    pub fn discover() -> Result<LcuCredentials, SamiraError> {
+        LOCKFILE_PATHS
+            .iter()
+            .find_map(|path| LcuCredentials::from_lockfile(path).ok())
+            .or_else(|| {
+                client_command_lines()
+                    .iter()
+                    .find_map(|command_line| LcuCredentials::parse_command_line(command_line))
+            })
+            .ok_or(SamiraError::NotFound)
+    }
+
+    /// Reads the credentials from the lockfile of the client, found in its install directory.
+    pub fn from_lockfile(path: impl AsRef<Path>) -> Result<LcuCredentials, SamiraError> {
+        LcuCredentials::parse_lockfile(&fs::read_to_string(path)?)
+    }
+
+    /// Parses the content of a lockfile ("LeagueClient:<pid>:<port>:<password>:https").
+    pub fn parse_lockfile(content: &str) -> Result<LcuCredentials, SamiraError> {
+        let malformed = || SamiraError::malformed("invalid lockfile");
+        let mut fields = content.trim().split(':').skip(1);
+        let pid = fields.next().ok_or_else(malformed)?;
+        let port = fields.next().ok_or_else(malformed)?;
+        let password = fields.next().ok_or_else(malformed)?;
+        Ok(LcuCredentials {
+            pid: pid.parse().map_err(|_| malformed())?,
+            port: port.parse().map_err(|_| malformed())?,
+            password: password.to_string(),
+        })
+    }
+
+    /// Parses the command line of the `LeagueClientUx` process,
+    /// None if it has no `--app-port` or `--remoting-auth-token` argument.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::lcu::*;
+    ///
+    /// let credentials = LcuCredentials::parse_command_line(
+    ///     r#""LeagueClientUx.exe" "--remoting-auth-token=PASSWORD" "--app-port=54321" "--app-pid=12345""#,
+    /// ).unwrap();
+    /// assert_eq!((credentials.pid, credentials.port), (12345, 54321));
+    /// ```
+    pub fn parse_command_line(command_line: &str) -> Option<LcuCredentials> {
+        let argument = |name: &str| {
+            let start = command_line.find(name)? + name.len();
+            command_line[start..]
+                .split(|c: char| c == '"' || c.is_whitespace())
+                .next()
+        };
+        Some(LcuCredentials {
+            pid: argument("--app-pid=")
+                .and_then(|pid| pid.parse().ok())
+                .unwrap_or_default(),
+            port: argument("--app-port=")?.parse().ok()?,
+            password: argument("--remoting-auth-token=")?.to_string(),
+        })
+    }
+
+    /// Returns the url of the API of the client.
+    pub fn url(&self) -> String {
+        format!("https://127.0.0.1:{}", self.port)
+    }
+
+    /// Returns the value of the Authorization header, the password with the `riot` user.
+    fn authorization(&self) -> String {
+        format!(
+            "Basic {}",
+            encode_base64(&format!("riot:{}", self.password))
+        )
+    }
+}
+
+/// A client of the REST API of the League client.
+/// The client answers over HTTPS with a self-signed certificate, which the default transport accepts for loopback addresses only.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{lcu::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new().with_json(
+///     "https://127.0.0.1:54321/lol-summoner/v1/current-summoner",
+///     r#"{"puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW", "summonerLevel": 312}"#,
+/// );
+/// let client = LcuClient::new(LcuCredentials::new(54321, "PASSWORD")).with_transport(transport);
+/// assert_eq!(client.get_current_summoner().unwrap().summoner_level, 312);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LcuClient {
+    credentials: LcuCredentials,
+    authorization: Authorization,
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl LcuClient {
+    /// Creates a client of the League client reached with the given credentials.
+    pub fn new(credentials: LcuCredentials) -> LcuClient {
+        LcuClient {
+            authorization: Authorization(credentials.authorization()),
+            credentials,
+            transport: local_transport(),
+        }
+    }
+
+    /// Creates a client of the running League client, see [`LcuCredentials::discover`].
+    pub fn discover() -> Result<LcuClient, SamiraError> {
+        Ok(LcuClient::new(LcuCredentials::discover()?))
+    }
+
+    /// Sets the HTTP client used to send the requests,
+    /// a [`UreqTransport::local`](crate::transport::ureq_transport::UreqTransport::local) by default.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> LcuClient {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns the credentials the client was created with.
+    pub fn credentials(&self) -> &LcuCredentials {
+        &self.credentials
+    }
+
+    /// Retrieve the summoner logged in the client.
+    pub fn get_current_summoner(&self) -> Option<LcuSummoner> {
+        self.try_get_current_summoner().ok()
+    }
+
+    /// Retrieve the summoner logged in the client.
+    /// Unlike [`LcuClient::get_current_summoner`] it returns the reason of the failure.
+    pub fn try_get_current_summoner(&self) -> Result<LcuSummoner, SamiraError> {
+        self.execute("/lol-summoner/v1/current-summoner")
+    }
+
+    /// Retrieve the champion select the client is in, None outside of a champion select.
+    pub fn get_champ_select_session(&self) -> Option<ChampSelectSession> {
+        self.try_get_champ_select_session().ok()
+    }
+
+    /// Retrieve the champion select the client is in.
+    /// Unlike [`LcuClient::get_champ_select_session`] it returns the reason of the failure,
+    /// [`SamiraError::NotFound`] outside of a champion select.
+    pub fn try_get_champ_select_session(&self) -> Result<ChampSelectSession, SamiraError> {
+        self.execute("/lol-champ-select/v1/session")
+    }
+
+    /// Imports a rune page in the client, selected if the page is `current`.
+    /// The client fails with [`SamiraError::Http`] when its rune pages are all used.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{lcu::*, rune_page::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json("*/lol-perks/v1/pages", r#"{"id": 1234, "name": "Samira"}"#);
+    /// let client = LcuClient::new(LcuCredentials::new(54321, "PASSWORD")).with_transport(transport);
+    /// let page = RunePage { name: "Samira".to_string(), ..Default::default() };
+    /// assert_eq!(client.try_import_rune_page(&page), Ok(()));
+    /// ```
+    pub fn try_import_rune_page(&self, page: &RunePage) -> Result<(), SamiraError> {
+        self.execute_post::<Value>("/lol-perks/v1/pages", page)
+            .map(|_| ())
+    }
+
+    /// Sends a GET request to any endpoint of the client and deserializes its response.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{lcu::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json("*/lol-gameflow/v1/gameflow-phase", r#""ChampSelect""#);
+    /// let client = LcuClient::new(LcuCredentials::new(54321, "PASSWORD")).with_transport(transport);
+    /// let phase: String = client.execute("lol-gameflow/v1/gameflow-phase").unwrap();
+    /// assert_eq!(phase, "ChampSelect");
+    /// ```
+    pub fn execute<T: DeserializeOwned>(&self, path: &str) -> Result<T, SamiraError> {
+        let request = format!(
+            "{}/{}",
+            self.credentials.url(),
+            path.trim_start_matches('/')
+        );
+        let headers = [
+            ("Authorization", self.authorization.0.as_str()),
+            ("Accept", "application/json"),
+        ];
+        let response =
+            read_json(self.transport.get(&request, &headers)?)?.ok_or(SamiraError::NotFound)?;
+        Ok(T::deserialize(&response)?)
+    }
+
+    /// Sends a POST request with a JSON body to any endpoint of the client and deserializes its response.
+    pub fn execute_post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<T, SamiraError> {
+        let request = format!(
+            "{}/{}",
+            self.credentials.url(),
+            path.trim_start_matches('/')
+        );
+        let headers = [
+            ("Authorization", self.authorization.0.as_str()),
+            ("Accept", "application/json"),
+        ];
+        let body = serde_json::to_string(body)?;
+        let response = read_json(self.transport.post(&request, &headers, &body)?)?
+            .ok_or(SamiraError::NotFound)?;
+        Ok(T::deserialize(&response)?)
+    }
+}
+
+/// The Authorization header of a [`LcuClient`], which is never printed and is erased from memory when dropped.
+#[derive(Clone)]
+struct Authorization(String);
+
+impl fmt::Debug for Authorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Authorization(<redacted>)")
+    }
+}
+
+impl Drop for Authorization {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Returns the command lines of the running `LeagueClientUx` processes, empty if they cannot be listed.
+fn client_command_lines() -> Vec<String> {
+    let output = match cfg!(windows) {
+        true => Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-Command",
+                "Get-CimInstance Win32_Process -Filter \"name='LeagueClientUx.exe'\" | Select-Object -ExpandProperty CommandLine",
+            ])
+            .output(),
+        false => Command::new("ps").args(["-A", "-ww", "-o", "args="]).output(),
+    };
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.contains("LeagueClientUx"))
+            .map(str::to_string)
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Encodes bytes in standard base64, with padding.
+fn encode_base64(input: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.as_bytes().chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for index in 0..4 {
+            match index <= chunk.len() {
+                true => encoded.push(ALPHABET[(group >> (18 - 6 * index) & 63) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+    encoded
+}
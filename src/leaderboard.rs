@@ -0,0 +1,409 @@
+//! Tracks the apex ladders over time: snapshots taken on a schedule are saved to a directory
+//! and compared to follow the LP gains, the players climbing into the ladder and those dropping out of it.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cancellation::CancellationToken,
+    error::SamiraError,
+    ids::*,
+    models::league_model::LeagueList,
+    platform::{get_platform_id, Platform},
+    queue::Queue,
+    rank::Tier,
+    riot_api::RiotApi,
+};
+
+/// The apex tiers, from the highest to the lowest.
+const APEX_TIERS: [Tier; 3] = [Tier::CHALLENGER, Tier::GRANDMASTER, Tier::MASTER];
+
+/// A player of a [`LadderSnapshot`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct LadderEntry {
+    pub puuid: Option<Puuid>,
+    pub summoner_id: SummonerId,
+    pub tier: Tier,
+    /// The LP of the player, which do not reset between the apex tiers.
+    pub league_points: i32,
+    pub wins: i32,
+    pub losses: i32,
+}
+
+impl LadderEntry {
+    /// Returns the key identifying the player between snapshots, the PUUID if known else the summoner id.
+    fn key(&self) -> &str {
+        match &self.puuid {
+            Some(puuid) => puuid.as_str(),
+            None => self.summoner_id.as_str(),
+        }
+    }
+
+    /// Returns the number of games played by the player this season.
+    pub fn games(&self) -> i32 {
+        self.wins + self.losses
+    }
+}
+
+/// The apex ladder of a queue at a given time.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{leaderboard::*, models::league_model::*, queue::*, rank::*};
+///
+/// let league = |tier: &str, players: &[(&str, i32)]| LeagueList {
+///     tier: tier.to_string(),
+///     entries: players
+///         .iter()
+///         .map(|(puuid, league_points)| LeagueItem {
+///             puuid: Some(puuid.to_string().into()),
+///             league_points: *league_points,
+///             ..Default::default()
+///         })
+///         .collect(),
+///     ..Default::default()
+/// };
+/// let before = LadderSnapshot::from_leagues(
+///     "euw1", Queue::RANKED_SOLO_5x5, 1000,
+///     &[league("CHALLENGER", &[("A", 1500), ("B", 1400)]), league("GRANDMASTER", &[("C", 700)])],
+/// );
+/// let after = LadderSnapshot::from_leagues(
+///     "euw1", Queue::RANKED_SOLO_5x5, 2000,
+///     &[league("CHALLENGER", &[("B", 1550), ("A", 1480)]), league("MASTER", &[("D", 300)])],
+/// );
+/// assert_eq!(after.position(&"B".into()), Some(1));
+///
+/// let diff = before.diff(&after);
+/// let b = diff.changes.iter().find(|change| change.entry.puuid == Some("B".into())).unwrap();
+/// assert_eq!((b.league_points, b.previous_position, b.position), (150, 2, 1));
+/// assert_eq!(diff.new_entries[0].puuid, Some("D".into()));
+/// assert_eq!(diff.dropouts[0].puuid, Some("C".into()));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct LadderSnapshot {
+    /// The platform of the ladder, as written in its host ("euw1", "na1", ...).
+    pub platform_id: String,
+    pub queue: Queue,
+    /// When the snapshot was taken, in seconds since the Unix epoch.
+    pub taken_at: u64,
+    /// The players from the first to the last of the ladder.
+    pub entries: Vec<LadderEntry>,
+}
+
+impl LadderSnapshot {
+    /// Gathers the apex leagues of a queue in a single ladder, sorted by tier then by LP.
+    /// Leagues of unknown tiers are ignored.
+    pub fn from_leagues(
+        platform_id: &str,
+        queue: Queue,
+        taken_at: u64,
+        leagues: &[LeagueList],
+    ) -> LadderSnapshot {
+        let mut entries: Vec<LadderEntry> = leagues
+            .iter()
+            .filter_map(|league| Some((league.tier()?, league)))
+            .flat_map(|(tier, league)| {
+                league.entries.iter().map(move |entry| LadderEntry {
+                    puuid: entry.puuid.clone(),
+                    summoner_id: entry.summoner_id.clone(),
+                    tier,
+                    league_points: entry.league_points,
+                    wins: entry.wins,
+                    losses: entry.losses,
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (b.tier, b.league_points, b.wins).cmp(&(a.tier, a.league_points, a.wins))
+        });
+        LadderSnapshot {
+            platform_id: platform_id.to_string(),
+            queue,
+            taken_at,
+            entries,
+        }
+    }
+
+    /// Returns the position of a player in the ladder, starting at 1.
+    pub fn position(&self, puuid: &Puuid) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.puuid.as_ref() == Some(puuid))
+            .map(|index| index + 1)
+    }
+
+    /// Returns the changes of the ladder between this snapshot and a newer one.
+    pub fn diff(&self, newer: &LadderSnapshot) -> LadderDiff {
+        let previous: HashMap<&str, (usize, &LadderEntry)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry.key(), (index + 1, entry)))
+            .collect();
+        let current: HashSet<&str> = newer.entries.iter().map(LadderEntry::key).collect();
+        let mut diff = LadderDiff {
+            from: self.taken_at,
+            to: newer.taken_at,
+            ..Default::default()
+        };
+        for (index, entry) in newer.entries.iter().enumerate() {
+            match previous.get(entry.key()) {
+                Some((previous_position, previous)) => diff.changes.push(LadderChange {
+                    entry: entry.clone(),
+                    previous_tier: previous.tier,
+                    previous_position: *previous_position,
+                    position: index + 1,
+                    league_points: entry.league_points - previous.league_points,
+                    games: entry.games() - previous.games(),
+                }),
+                None => diff.new_entries.push(entry.clone()),
+            }
+        }
+        diff.dropouts = self
+            .entries
+            .iter()
+            .filter(|entry| !current.contains(entry.key()))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// The changes of a ladder between two snapshots, see [`LadderSnapshot::diff`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct LadderDiff {
+    /// When the older snapshot was taken.
+    pub from: u64,
+    /// When the newer snapshot was taken.
+    pub to: u64,
+    /// The players of both snapshots, in their new order.
+    pub changes: Vec<LadderChange>,
+    /// The players who entered the ladder, in their order.
+    pub new_entries: Vec<LadderEntry>,
+    /// The players who left the ladder, by decay, demotion or inactivity.
+    pub dropouts: Vec<LadderEntry>,
+}
+
+impl LadderDiff {
+    /// Returns the players who played since the older snapshot.
+    pub fn active(&self) -> impl Iterator<Item = &LadderChange> {
+        self.changes.iter().filter(|change| change.games != 0)
+    }
+}
+
+/// How a player of both snapshots moved in the ladder.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct LadderChange {
+    /// The player in the newer snapshot.
+    pub entry: LadderEntry,
+    pub previous_tier: Tier,
+    pub previous_position: usize,
+    pub position: usize,
+    /// The LP gained, negative when LP were lost.
+    pub league_points: i32,
+    /// The games played between the snapshots.
+    pub games: i32,
+}
+
+/// Takes snapshots of the apex ladder of a platform and queue, and saves them in a directory
+/// where they can be read back with [`LeaderboardTracker::history`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::env;
+/// use samira::{leaderboard::*, platform::*, queue::*, riot_api::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new()
+///     .with_json(
+///         "*/lol/league/v4/challengerleagues/by-queue/RANKED_SOLO_5x5",
+///         r#"{"tier": "CHALLENGER", "entries": [{"summonerId": "ID", "puuid": "PUUID", "leaguePoints": 1500}]}"#,
+///     )
+///     .with_json("*/lol/league/v4/grandmasterleagues/by-queue/RANKED_SOLO_5x5", r#"{"tier": "GRANDMASTER", "entries": []}"#)
+///     .with_json("*/lol/league/v4/masterleagues/by-queue/RANKED_SOLO_5x5", r#"{"tier": "MASTER", "entries": []}"#);
+/// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+/// let directory = env::temp_dir().join("samira-doc-leaderboard");
+/// # let _ = std::fs::remove_dir_all(&directory);
+/// let tracker = LeaderboardTracker::new(api, Platform::EUW1, Queue::RANKED_SOLO_5x5, &directory).unwrap();
+///
+/// let snapshot = tracker.snapshot().unwrap();
+/// assert_eq!(snapshot.position(&"PUUID".into()), Some(1));
+/// assert_eq!(tracker.latest().unwrap(), Some(snapshot));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LeaderboardTracker {
+    api: RiotApi,
+    platform: Platform,
+    queue: Queue,
+    directory: PathBuf,
+}
+
+impl LeaderboardTracker {
+    /// Creates a tracker saving its snapshots in the given directory, creating it if needed.
+    /// Several trackers can share a directory, each only reads the snapshots of its platform and queue.
+    pub fn new(
+        api: RiotApi,
+        platform: Platform,
+        queue: Queue,
+        directory: impl AsRef<Path>,
+    ) -> Result<LeaderboardTracker, SamiraError> {
+        fs::create_dir_all(directory.as_ref())?;
+        Ok(LeaderboardTracker {
+            api,
+            platform,
+            queue,
+            directory: directory.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Retrieves the apex leagues, then saves and returns their snapshot.
+    pub fn snapshot(&self) -> Result<LadderSnapshot, SamiraError> {
+        let mut leagues = Vec::new();
+        for tier in APEX_TIERS {
+            leagues.push(
+                self.api
+                    .try_get_apex_league(&self.platform, tier, self.queue)?,
+            );
+        }
+        let taken_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let snapshot = LadderSnapshot::from_leagues(
+            get_platform_id(&self.platform),
+            self.queue,
+            taken_at,
+            &leagues,
+        );
+        self.save(&snapshot)?;
+        Ok(snapshot)
+    }
+
+    /// Returns the saved snapshots taken between `since` and `until` included, in seconds since the Unix epoch,
+    /// from the oldest to the newest.
+    pub fn history(&self, since: u64, until: u64) -> Result<Vec<LadderSnapshot>, SamiraError> {
+        self.taken_at()?
+            .into_iter()
+            .filter(|taken_at| (since..=until).contains(taken_at))
+            .map(|taken_at| self.load(taken_at))
+            .collect()
+    }
+
+    /// Returns the newest saved snapshot.
+    pub fn latest(&self) -> Result<Option<LadderSnapshot>, SamiraError> {
+        match self.taken_at()?.last() {
+            Some(taken_at) => Ok(Some(self.load(*taken_at)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the LP and position of a player in each snapshot taken between `since` and `until`,
+    /// skipping the snapshots the player is not in.
+    pub fn player_history(
+        &self,
+        puuid: &Puuid,
+        since: u64,
+        until: u64,
+    ) -> Result<Vec<(u64, usize, LadderEntry)>, SamiraError> {
+        Ok(self
+            .history(since, until)?
+            .into_iter()
+            .filter_map(|snapshot| {
+                let position = snapshot.position(puuid)?;
+                let entry = snapshot.entries.into_iter().nth(position - 1)?;
+                Some((snapshot.taken_at, position, entry))
+            })
+            .collect())
+    }
+
+    /// Takes a snapshot every `interval` until the token is cancelled, calling `on_diff` with the changes
+    /// since the previous snapshot, saved snapshots included, or with the error of a failed snapshot.
+    /// A failed snapshot does not stop the tracking.
+    pub fn run(
+        &self,
+        interval: Duration,
+        cancellation: &CancellationToken,
+        mut on_diff: impl FnMut(Result<LadderDiff, SamiraError>),
+    ) -> Result<(), SamiraError> {
+        let mut previous = self.latest()?;
+        while !cancellation.is_cancelled() {
+            match self.snapshot() {
+                Ok(snapshot) => {
+                    if let Some(previous) = &previous {
+                        on_diff(Ok(previous.diff(&snapshot)));
+                    }
+                    previous = Some(snapshot);
+                }
+                Err(error) => on_diff(Err(error)),
+            }
+            if cancellation.sleep(interval).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the prefix of the files of the tracker, the time of the snapshot follows it.
+    fn prefix(&self) -> String {
+        format!(
+            "{}_{}_",
+            get_platform_id(&self.platform),
+            self.queue.as_str()
+        )
+    }
+
+    fn path(&self, taken_at: u64) -> PathBuf {
+        self.directory
+            .join(format!("{}{}.json", self.prefix(), taken_at))
+    }
+
+    /// Returns when the saved snapshots were taken, from the oldest to the newest.
+    fn taken_at(&self) -> Result<Vec<u64>, SamiraError> {
+        let prefix = self.prefix();
+        let mut taken_at = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let name = entry?.file_name();
+            let time: Option<u64> = name
+                .to_str()
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|name| name.strip_suffix(".json"))
+                .and_then(|time| time.parse().ok());
+            taken_at.extend(time);
+        }
+        taken_at.sort_unstable();
+        Ok(taken_at)
+    }
+
+    fn load(&self, taken_at: u64) -> Result<LadderSnapshot, SamiraError> {
+        Ok(serde_json::from_slice(&fs::read(self.path(taken_at))?)?)
+    }
+
+    fn save(&self, snapshot: &LadderSnapshot) -> Result<(), SamiraError> {
+        let path = self.path(snapshot.taken_at);
+        let temporary = path.with_extension("tmp");
+        // Written then renamed so a concurrent reader never sees a partial snapshot.
+        let written = serde_json::to_vec(snapshot)
+            .map_err(io::Error::from)
+            .and_then(|content| fs::write(&temporary, content))
+            .and_then(|_| fs::rename(&temporary, &path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+        Ok(written?)
+    }
+}
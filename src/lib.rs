@@ -1,8 +1,53 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod assets;
+mod bulk;
+pub mod cache;
+pub mod cancellation;
+pub mod champion_id;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod crawler;
+pub mod damage;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+mod endpoint;
+pub mod error;
 pub mod filters;
+pub mod game;
+pub mod ids;
+pub mod item_set;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod lcu;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod leaderboard;
+pub mod live_client;
+pub mod metrics;
 pub mod models;
 
 pub mod platform;
+pub mod position;
+pub mod prelude;
+pub mod profile_url;
+pub mod queue;
+pub mod rank;
 pub mod region;
+pub mod replay;
+
+pub mod rate_limiter;
+mod request;
+pub mod retry_policy;
 
 pub mod riot_api;
+pub mod route;
+pub mod rune_page;
+pub mod spectate;
+#[cfg(feature = "storage")]
+pub mod storage;
+pub mod summoner_tracker;
+#[cfg(any(feature = "polars", feature = "arrow"))]
+mod tabular;
+pub mod team;
+pub mod transport;
 pub mod utils_api;
+pub mod version;
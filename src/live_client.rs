@@ -0,0 +1,150 @@
+//! The Live Client Data API, served by the game on this machine while a game is played or spectated.
+//! It needs no token: overlays and companion apps can read the state of the game as it unfolds.
+
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::SamiraError,
+    models::live_client_model::*,
+    request::read_json,
+    transport::{local_transport, HttpTransport},
+};
+
+/// The address of the Live Client Data API, always served on port 2999.
+pub const LIVE_CLIENT_URL: &str = "https://127.0.0.1:2999/liveclientdata";
+
+/// A client of the Live Client Data API.
+/// The game answers over HTTPS with a self-signed certificate, which the default transport accepts for loopback addresses only.
+///
+/// Requests fail with [`SamiraError::Transport`] when no game is running,
+/// and with [`SamiraError::NotFound`] while it is loading.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{live_client::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new().with_json(
+///     "https://127.0.0.1:2999/liveclientdata/activeplayer",
+///     r#"{"riotId": "RqndomHax#EUW", "level": 11, "currentGold": 1250.0}"#,
+/// );
+/// let client = LiveClient::new().with_transport(transport);
+/// assert_eq!(client.get_active_player().unwrap().level, 11);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveClient {
+    url: String,
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl Default for LiveClient {
+    fn default() -> LiveClient {
+        LiveClient {
+            url: LIVE_CLIENT_URL.to_string(),
+            transport: local_transport(),
+        }
+    }
+}
+
+impl LiveClient {
+    /// Creates a client of the game running on this machine.
+    pub fn new() -> LiveClient {
+        LiveClient::default()
+    }
+
+    /// Sets the url of the Live Client Data API, [`LIVE_CLIENT_URL`] by default.
+    pub fn with_url(mut self, url: &str) -> LiveClient {
+        self.url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests,
+    /// a [`UreqTransport::local`](crate::transport::ureq_transport::UreqTransport::local) by default.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> LiveClient {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns true if a game is running and has finished loading.
+    pub fn is_in_game(&self) -> bool {
+        self.get::<LiveGameData>("gamestats").is_ok()
+    }
+
+    /// Retrieve everything known about the game.
+    pub fn get_all_game_data(&self) -> Option<AllGameData> {
+        self.try_get_all_game_data().ok()
+    }
+
+    /// Retrieve everything known about the game.
+    /// Unlike [`LiveClient::get_all_game_data`] it returns the reason of the failure.
+    pub fn try_get_all_game_data(&self) -> Result<AllGameData, SamiraError> {
+        self.get("allgamedata")
+    }
+
+    /// Retrieve the player running the client, None while spectating.
+    pub fn get_active_player(&self) -> Option<ActivePlayer> {
+        self.try_get_active_player().ok()
+    }
+
+    /// Retrieve the player running the client.
+    /// Unlike [`LiveClient::get_active_player`] it returns the reason of the failure.
+    pub fn try_get_active_player(&self) -> Result<ActivePlayer, SamiraError> {
+        self.get("activeplayer")
+    }
+
+    /// Retrieve the players of the game.
+    pub fn get_player_list(&self) -> Vec<LivePlayer> {
+        self.try_get_player_list().unwrap_or_default()
+    }
+
+    /// Retrieve the players of the game.
+    /// Unlike [`LiveClient::get_player_list`] it returns the reason of the failure.
+    pub fn try_get_player_list(&self) -> Result<Vec<LivePlayer>, SamiraError> {
+        self.get("playerlist")
+    }
+
+    /// Retrieve the events of the game since its start.
+    pub fn get_events(&self) -> Vec<LiveEvent> {
+        self.try_get_events().unwrap_or_default()
+    }
+
+    /// Retrieve the events of the game since its start.
+    /// Unlike [`LiveClient::get_events`] it returns the reason of the failure.
+    pub fn try_get_events(&self) -> Result<Vec<LiveEvent>, SamiraError> {
+        Ok(self.get::<LiveEvents>("eventdata")?.events)
+    }
+
+    /// Retrieve the events of the game from the given event id, so a poller only reads the new ones.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{live_client::*, models::live_client_model::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/eventdata?eventID=1",
+    ///     r#"{"Events": [{"EventID": 1, "EventName": "FirstBlood", "EventTime": 201.4, "Recipient": "RqndomHax"}]}"#,
+    /// );
+    /// let client = LiveClient::new().with_transport(transport);
+    /// let events = client.try_get_events_from(1).unwrap();
+    /// assert_eq!(events[0].kind, LiveEventKind::FirstBlood { recipient: "RqndomHax".to_string() });
+    /// ```
+    pub fn try_get_events_from(&self, event_id: i64) -> Result<Vec<LiveEvent>, SamiraError> {
+        Ok(self
+            .get::<LiveEvents>(&format!("eventdata?eventID={}", event_id))?
+            .events)
+    }
+
+    fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, SamiraError> {
+        let request = format!("{}/{}", self.url, endpoint);
+        let response =
+            read_json(self.transport.get(&request, &[])?)?.ok_or(SamiraError::NotFound)?;
+        Ok(T::deserialize(&response)?)
+    }
+}
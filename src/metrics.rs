@@ -0,0 +1,72 @@
+use std::{fmt::Debug, time::Duration};
+
+/// A request sent to the Riot API, reported once answered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics<'a> {
+    /// The platform or region the request was sent to ("euw1", "europe", ...).
+    pub route: &'a str,
+    /// The Riot API method ("summoner-v4.getByPUUID", ...).
+    pub method: &'a str,
+    /// The response status, None if the request could not be sent.
+    pub status: Option<u16>,
+    /// The time to receive the response headers.
+    pub latency: Duration,
+    /// The attempt of this request, starting at 1 and increased on each retry.
+    pub attempt: u32,
+}
+
+/// The usage of a rate limit window, reported after each response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitUsage<'a> {
+    /// The platform or region of the limit.
+    pub route: &'a str,
+    /// The method of a method limit, None for an app limit.
+    pub method: Option<&'a str>,
+    /// The number of requests sent in the current window.
+    pub count: u32,
+    /// The number of requests allowed in a window.
+    pub limit: u32,
+    /// The duration of a window.
+    pub window: Duration,
+}
+
+/// Receives metrics about the requests sent to the Riot API, e.g. to export them to Prometheus.
+/// Every method does nothing by default, so only the wanted metrics need to be implemented.
+/// Implementations must be thread safe as a sink can be shared by several clients.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::sync::{atomic::{AtomicU32, Ordering}, Arc};
+/// use samira::{metrics::*, platform::*, riot_api::*, transport::mock_transport::*};
+///
+/// #[derive(Debug, Default)]
+/// struct RequestCounter(AtomicU32);
+///
+/// impl MetricsSink for RequestCounter {
+///     fn on_request(&self, _request: &RequestMetrics) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = Arc::new(RequestCounter::default());
+/// let api = RiotApi::new_unchecked("TOKEN_HERE")
+///     .with_transport(MockTransport::new())
+///     .with_metrics(counter.clone());
+/// api.get_champion_rotations(&Platform::EUW1);
+/// assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+/// ```
+pub trait MetricsSink: Debug + Send + Sync {
+    /// Called when a request is answered or could not be sent.
+    fn on_request(&self, _request: &RequestMetrics) {}
+    /// Called for each rate limit window of a route and method after a response.
+    fn on_rate_limit(&self, _usage: &RateLimitUsage) {}
+}
+
+/// A MetricsSink ignoring every metric, used by default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoMetrics;
+
+impl MetricsSink for NoMetrics {}
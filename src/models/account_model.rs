@@ -0,0 +1,51 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ids::*;
+
+/// A Riot account of account-v1, shared by every Riot game.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::account_model::*;
+///
+/// let account: Account = serde_json::from_str(r#"{"puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW"}"#).unwrap();
+/// assert_eq!(account.riot_id(), Some("RqndomHax#EUW".to_string()));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Account {
+    pub puuid: Puuid,
+    /// The game name of the Riot ID, None if the account never chose one.
+    #[serde(default)]
+    pub game_name: Option<String>,
+    /// The tag line of the Riot ID, None if the account never chose one.
+    #[serde(default)]
+    pub tag_line: Option<String>,
+}
+
+impl Account {
+    /// Returns the Riot ID of the account ("Name#TAG"), None if the account has none.
+    pub fn riot_id(&self) -> Option<String> {
+        Some(format!(
+            "{}#{}",
+            self.game_name.as_ref()?,
+            self.tag_line.as_ref()?
+        ))
+    }
+}
+
+impl fmt::Display for Account {
+    /// Writes the Riot ID of the account, or its PUUID if it has none.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.riot_id() {
+            Some(riot_id) => f.write_str(&riot_id),
+            None => self.puuid.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ids::*;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChallengeLevel {
+    NONE,
+    IRON,
+    BRONZE,
+    SILVER,
+    GOLD,
+    PLATINUM,
+    DIAMOND,
+    MASTER,
+    GRANDMASTER,
+    CHALLENGER,
+}
+
+impl ChallengeLevel {
+    /// Returns the level as written by the Riot API ("IRON", "MASTER", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeLevel::NONE => "NONE",
+            ChallengeLevel::IRON => "IRON",
+            ChallengeLevel::BRONZE => "BRONZE",
+            ChallengeLevel::SILVER => "SILVER",
+            ChallengeLevel::GOLD => "GOLD",
+            ChallengeLevel::PLATINUM => "PLATINUM",
+            ChallengeLevel::DIAMOND => "DIAMOND",
+            ChallengeLevel::MASTER => "MASTER",
+            ChallengeLevel::GRANDMASTER => "GRANDMASTER",
+            ChallengeLevel::CHALLENGER => "CHALLENGER",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengeReward {
+    pub category: String,
+    pub quantity: i64,
+    pub title: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengeThreshold {
+    pub value: f64,
+    #[serde(default)]
+    pub rewards: Vec<ChallengeReward>,
+}
+
+/// The configuration of a challenge, of lol-challenges-v1.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::challenge_model::*;
+///
+/// let config: ChallengeConfig = serde_json::from_str(r#"{
+///     "id": 101000, "state": "ENABLED", "tracking": "LIFETIME", "leaderboard": true,
+///     "localizedNames": {"en_US": {"description": "Earn points from challenges in the ARAM category", "name": "ARAM Authority", "shortDescription": "Earn points from ARAM challenges"}},
+///     "thresholds": {"IRON": 25.0, "MASTER": 1100.0}
+/// }"#).unwrap();
+/// assert_eq!(config.localized_name("en_US"), Some("ARAM Authority"));
+/// assert_eq!(config.thresholds[&ChallengeLevel::MASTER], 1100.0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengeConfig {
+    pub id: i64,
+    /// The names and descriptions of the challenge, keyed by locale ("en_US", "fr_FR", ...).
+    pub localized_names: HashMap<String, ChallengeName>,
+    /// "DISABLED", "HIDDEN", "ENABLED" or "ARCHIVED".
+    pub state: String,
+    /// "LIFETIME" or "SEASON".
+    pub tracking: String,
+    /// The start of a seasonal challenge, in milliseconds since the epoch.
+    pub start_timestamp: Option<i64>,
+    /// The end of a seasonal challenge, in milliseconds since the epoch.
+    pub end_timestamp: Option<i64>,
+    /// True if the challenge has a leaderboard of its apex players.
+    pub leaderboard: bool,
+    /// The value needed to reach each level.
+    pub thresholds: HashMap<ChallengeLevel, f64>,
+}
+
+impl ChallengeConfig {
+    /// Returns the name of the challenge in a locale, None if it is not translated in this locale.
+    pub fn localized_name(&self, locale: &str) -> Option<&str> {
+        self.localized_names
+            .get(locale)
+            .map(|name| name.name.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengeName {
+    pub description: String,
+    pub name: String,
+    pub short_description: String,
+}
+
+/// The points earned by a player, in total or in a category of challenges.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengePoints {
+    pub level: ChallengeLevel,
+    pub current: f64,
+    pub max: f64,
+    /// The share of the players with less points, from 0 to 1.
+    #[serde(default)]
+    pub percentile: Option<f64>,
+}
+
+/// The progress of a player on a challenge.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChallengeInfo {
+    pub challenge_id: i64,
+    pub percentile: f64,
+    pub level: ChallengeLevel,
+    pub value: f64,
+    /// When the current level was reached, in milliseconds since the epoch.
+    #[serde(default)]
+    pub achieved_time: Option<i64>,
+    /// The position of the player on the leaderboard of the challenge, for the apex levels.
+    #[serde(default)]
+    pub position: Option<i64>,
+    #[serde(default)]
+    pub players_in_level: Option<i64>,
+}
+
+/// The challenges a player displays on its profile.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlayerClientPreferences {
+    pub banner_accent: String,
+    /// The id of the title, empty if the player displays none.
+    pub title: String,
+    /// The challenges displayed as tokens, at most 3.
+    pub challenge_ids: Vec<i64>,
+    pub crest_border: String,
+    pub prestige_crest_border_level: i32,
+}
+
+/// The challenges of a player, of lol-challenges-v1.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::challenge_model::*;
+///
+/// let player: PlayerChallenges = serde_json::from_str(r#"{
+///     "challenges": [{"challengeId": 101000, "percentile": 0.2, "level": "GOLD", "value": 400.0}],
+///     "preferences": {"challengeIds": [101000]},
+///     "totalPoints": {"level": "PLATINUM", "current": 5000.0, "max": 20000.0},
+///     "categoryPoints": {"TEAMWORK": {"level": "GOLD", "current": 900.0, "max": 2000.0}}
+/// }"#).unwrap();
+/// assert_eq!(player.challenges[0].level, ChallengeLevel::GOLD);
+/// assert_eq!(player.category_points["TEAMWORK"].current, 900.0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlayerChallenges {
+    pub challenges: Vec<ChallengeInfo>,
+    #[serde(default)]
+    pub preferences: PlayerClientPreferences,
+    pub total_points: ChallengePoints,
+    /// The points of each category ("COLLECTION", "EXPERTISE", "IMAGINATION", "TEAMWORK", "VETERANCY").
+    pub category_points: HashMap<String, ChallengePoints>,
+}
+
+/// A player of the leaderboard of a challenge, at an apex level.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ApexPlayer {
+    pub puuid: Puuid,
+    pub value: f64,
+    pub position: i32,
+}
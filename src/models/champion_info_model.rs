@@ -1,11 +1,42 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+use crate::champion_id::ChampionId;
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ChampionInfo {
-    #[serde(alias = "maxNewPlayerLevel")]
     pub max_new_player_level: i32,
-    #[serde(alias = "freeChampionIdsForNewPlayers")]
+    #[serde(rename = "freeChampionIdsForNewPlayers")]
     pub free_champions_ids_for_new_players: Vec<i32>,
-    #[serde(alias = "freeChampionIds")]
     pub free_champion_ids: Vec<i32>,
 }
+
+impl ChampionInfo {
+    /// Returns the champions of the rotation, skipping the ones unknown by this version of samira.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{champion_id::*, models::champion_info_model::*};
+    ///
+    /// let rotation = ChampionInfo {free_champion_ids: vec![360, 777], ..Default::default()};
+    /// assert_eq!(rotation.free_champions(), vec![ChampionId::Samira, ChampionId::Yone]);
+    /// ```
+    pub fn free_champions(&self) -> Vec<ChampionId> {
+        self.free_champion_ids
+            .iter()
+            .filter_map(|key| ChampionId::from_key(*key))
+            .collect()
+    }
+
+    /// Returns the champions of the rotation for new players, skipping the ones unknown by this version of samira.
+    pub fn free_champions_for_new_players(&self) -> Vec<ChampionId> {
+        self.free_champions_ids_for_new_players
+            .iter()
+            .filter_map(|key| ChampionId::from_key(*key))
+            .collect()
+    }
+}
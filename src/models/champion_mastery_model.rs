@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{champion_id::ChampionId, ids::*};
+
+/// The mastery of a player on a champion, of champion-mastery-v4.
+/// Since the mastery rework of patch 14.10 the levels are unbounded,
+/// and leveling up past level 4 requires marks earned through the season milestones.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{champion_id::*, models::champion_mastery_model::*};
+///
+/// let mastery: ChampionMastery = serde_json::from_str(r#"{
+///     "puuid": "PUUID", "championId": 360, "championLevel": 12, "championPoints": 152000,
+///     "lastPlayTime": 1700000000000, "championPointsSinceLastLevel": 5000, "championPointsUntilNextLevel": 6000,
+///     "markRequiredForNextLevel": 2, "tokensEarned": 1, "championSeasonMilestone": 3,
+///     "milestoneGrades": ["S-", "A+"],
+///     "nextSeasonMilestone": {"requireGradeCounts": {"A-": 1}, "rewardMarks": 1, "bonus": false, "totalGamesRequires": 1}
+/// }"#).unwrap();
+/// assert_eq!(mastery.champion(), Some(ChampionId::Samira));
+/// assert_eq!(mastery.next_season_milestone.require_grade_counts["A-"], 1);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampionMastery {
+    pub puuid: Puuid,
+    pub champion_id: i32,
+    pub champion_level: i32,
+    pub champion_points: i32,
+    /// The last time the champion was played, in milliseconds since the epoch.
+    pub last_play_time: i64,
+    pub champion_points_since_last_level: i64,
+    /// The points left to reach the next level, negative once they are reached but marks are missing.
+    pub champion_points_until_next_level: i64,
+    /// Removed with the hextech chests, false for the latest responses.
+    #[serde(default)]
+    pub chest_granted: bool,
+    /// The marks required to level up, on top of the points.
+    #[serde(default)]
+    pub mark_required_for_next_level: i32,
+    /// The marks earned towards the next level.
+    #[serde(default)]
+    pub tokens_earned: i32,
+    /// The season milestone reached on the champion.
+    #[serde(default)]
+    pub champion_season_milestone: i32,
+    /// The grades ("S+", "A-", ...) earned towards the next season milestone.
+    #[serde(default)]
+    pub milestone_grades: Vec<String>,
+    #[serde(default)]
+    pub next_season_milestone: SeasonMilestone,
+}
+
+impl ChampionMastery {
+    /// Returns the champion of the mastery, None if it is unknown by this version of samira.
+    pub fn champion(&self) -> Option<ChampionId> {
+        ChampionId::from_key(self.champion_id)
+    }
+}
+
+/// The requirements of the next season milestone of a [`ChampionMastery`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SeasonMilestone {
+    /// The number of games to play with at least a grade, keyed by the grade ("A-", "B-", ...).
+    pub require_grade_counts: HashMap<String, i32>,
+    /// The marks earned when reaching the milestone.
+    pub reward_marks: i32,
+    pub bonus: bool,
+    pub total_games_requires: i32,
+    pub reward_config: Option<MilestoneRewardConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MilestoneRewardConfig {
+    pub reward_value: String,
+    pub reward_type: String,
+    pub maximum_reward: i32,
+}
@@ -1,19 +1,30 @@
-use serde::{Deserialize, Serialize};
+use std::{fmt, sync::OnceLock};
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+
+use crate::{champion_id::ChampionId, error::SamiraError};
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Passive {
     pub name: String,
     pub description: String,
     pub image: Image,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct LevelTip {
     pub label: Vec<String>,
     pub effect: Vec<String>,
 }
 
-#[derive(Deserialize, Serialize, Default, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Spell {
     pub id: String,
     pub name: String,
@@ -22,25 +33,85 @@ pub struct Spell {
     pub leveltip: Option<LevelTip>,
     pub maxrank: i32,
     pub cooldown: Vec<f64>,
-    #[serde(alias = "cooldownBurn")]
     pub cooldown_burn: String,
     pub cost: Vec<f64>,
-    #[serde(alias = "costBurn")]
     pub cost_burn: String,
     pub effect: Vec<Option<Vec<f64>>>,
-    #[serde(alias = "effectBurn")]
     pub effect_burn: Vec<Option<String>>,
-    #[serde(alias = "costType")]
+    /// The ratios of the spell, referenced by its tooltip as `{{ a1 }}`, `{{ f1 }}`, ...
+    /// DDragon stopped filling them in recent versions, where they are usually empty.
+    pub vars: Vec<SpellVar>,
     pub cost_type: String,
     pub maxammo: String,
     pub range: Vec<i64>,
-    #[serde(alias = "rangeBurn")]
     pub range_burn: String,
     pub image: Image,
     pub resource: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+impl Spell {
+    /// Returns the ratio of the spell referenced by a key of its tooltip ("a1", "f2", ...), None if it is missing.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::models::champion_model::*;
+    ///
+    /// let spell: Spell = serde_json::from_str(r#"{
+    ///     "id": "AnnieQ", "name": "Disintegrate",
+    ///     "vars": [{"link": "spelldamage", "coeff": 0.8, "key": "a1"}, {"link": "bonusattackdamage", "coeff": [0.5, 0.6], "key": "a2"}]
+    /// }"#).unwrap();
+    /// assert_eq!(spell.var("a1").unwrap().coeff_at(3), Some(0.8));
+    /// assert_eq!(spell.var("a2").unwrap().coeff_at(2), Some(0.6));
+    /// assert!(spell.var("a3").is_none());
+    /// ```
+    pub fn var(&self, key: &str) -> Option<&SpellVar> {
+        self.vars.iter().find(|var| var.key == key)
+    }
+}
+
+/// A ratio of a [`Spell`], scaling its effects with a stat of the champion.
+#[derive(Deserialize, Serialize, Clone, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SpellVar {
+    /// The key referencing the ratio in the tooltip ("a1", "f2", ...).
+    pub key: String,
+    /// The stat the ratio scales with ("spelldamage", "bonusattackdamage", "armor", ...).
+    pub link: String,
+    /// The ratio, either one for every rank or one per rank, written as a number or an array by DDragon.
+    #[serde(deserialize_with = "deserialize_coeff")]
+    pub coeff: Vec<f64>,
+}
+
+impl SpellVar {
+    /// Returns the ratio at a rank of the spell, starting at 1, None if the rank has none.
+    pub fn coeff_at(&self, rank: usize) -> Option<f64> {
+        match self.coeff.as_slice() {
+            [coeff] => Some(*coeff),
+            coeffs => coeffs.get(rank.checked_sub(1)?).copied(),
+        }
+    }
+}
+
+fn deserialize_coeff<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Coeff {
+        One(f64),
+        PerRank(Vec<f64>),
+    }
+    Ok(match Coeff::deserialize(deserializer)? {
+        Coeff::One(coeff) => vec![coeff],
+        Coeff::PerRank(coeffs) => coeffs,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Stats {
     pub hp: f64,
     pub hpperlevel: f64,
@@ -64,7 +135,9 @@ pub struct Stats {
     pub attackspeed: f64,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Info {
     pub attack: i32,
     pub defense: i32,
@@ -72,7 +145,9 @@ pub struct Info {
     pub difficulty: i32,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Skin {
     pub id: String,
     pub num: i32,
@@ -80,7 +155,9 @@ pub struct Skin {
     pub chromas: bool,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Image {
     pub full: String,
     pub sprite: String,
@@ -91,10 +168,35 @@ pub struct Image {
     pub h: i32,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+/// A champion of the DDragon championFull.json file.
+/// Fields missing from older versions of the file, like the skins `chromas` or the spells `maxammo`,
+/// are left to their default value.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::champion_model::*;
+///
+/// // A champion as written by DDragon 3.7.1, before chromas and ammo were added.
+/// let champion: Champion = serde_json::from_str(r#"{
+///     "id": "Annie", "key": "1", "name": "Annie", "title": "the Dark Child",
+///     "skins": [{"id": "1000", "num": 0, "name": "default"}],
+///     "spells": [{"id": "Disintegrate", "name": "Disintegrate", "maxrank": 5, "cooldown": [4, 4, 4, 4, 4]}]
+/// }"#).unwrap();
+/// assert_eq!(champion.key, 1);
+/// assert_eq!(champion.skins[0].chromas, false);
+/// assert_eq!(champion.spells[0].maxammo, "");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Champion {
     pub id: String,
-    pub key: String,
+    /// The numeric key referencing the champion in the Riot API, written as a string by DDragon.
+    #[serde(deserialize_with = "deserialize_key")]
+    pub key: i64,
     pub name: String,
     pub title: String,
     pub image: Image,
@@ -110,3 +212,168 @@ pub struct Champion {
     pub spells: Vec<Spell>,
     pub passive: Passive,
 }
+
+impl Champion {
+    /// Returns the [`ChampionId`] of the champion, None if it is unknown by this version of samira.
+    pub fn champion_id(&self) -> Option<ChampionId> {
+        ChampionId::from_key(i32::try_from(self.key).ok()?)
+    }
+}
+
+impl fmt::Display for Champion {
+    /// Writes the name and the title of the champion ("Samira — the Desert Rose").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} — {}", self.name, self.title)
+    }
+}
+
+impl fmt::Display for LazyChampion {
+    /// Writes the name and the title of the champion ("Samira — the Desert Rose").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} — {}", self.name, self.title)
+    }
+}
+
+/// Deserializes a champion key from its DDragon string form ("360"), or from a number.
+fn deserialize_key<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Key {
+        Number(i64),
+        String(String),
+    }
+    match Key::deserialize(deserializer)? {
+        Key::Number(key) => Ok(key),
+        Key::String(key) => key.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// A field kept as raw JSON until it is first accessed.
+#[derive(Clone, Default)]
+struct Lazy<T> {
+    raw: Option<Box<RawValue>>,
+    value: OnceLock<T>,
+}
+
+impl<T> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.raw {
+            Some(raw) => write!(f, "Lazy({} bytes)", raw.get().len()),
+            None => write!(f, "Lazy(missing)"),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Lazy<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Lazy<T>, D::Error> {
+        Ok(Lazy {
+            raw: Some(Box::<RawValue>::deserialize(deserializer)?),
+            value: OnceLock::new(),
+        })
+    }
+}
+
+impl<T: DeserializeOwned + Default> Lazy<T> {
+    fn get(&self) -> Result<&T, SamiraError> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        let value = match &self.raw {
+            Some(raw) => serde_json::from_str(raw.get())?,
+            None => T::default(),
+        };
+        // Another thread may have parsed it first, both values are the same.
+        let _ = self.value.set(value);
+        Ok(self.value.get().unwrap())
+    }
+}
+
+/// A champion of the DDragon championFull.json file whose heavy fields (lore, tips, skins, spells and passive)
+/// are kept as raw JSON and only deserialized on first access,
+/// so listing the champions names and images does not pay for parsing every spell.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::champion_model::*;
+///
+/// let champion: LazyChampion = serde_json::from_str(r#"{
+///     "id": "Samira", "key": "360", "name": "Samira", "title": "the Desert Rose",
+///     "spells": [{"id": "SamiraQ", "name": "Flair", "maxrank": 5}]
+/// }"#).unwrap();
+/// assert_eq!(champion.to_string(), "Samira — the Desert Rose");
+/// assert_eq!(champion.spells().unwrap()[0].name, "Flair");
+/// assert_eq!(champion.to_champion().unwrap().spells.len(), 1);
+/// ```
+#[derive(Deserialize, Clone, Default, Debug)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LazyChampion {
+    pub id: String,
+    #[serde(deserialize_with = "deserialize_key")]
+    pub key: i64,
+    pub name: String,
+    pub title: String,
+    pub image: Image,
+    pub blurb: String,
+    pub tags: Vec<String>,
+    pub partype: String,
+    pub info: Info,
+    pub stats: Stats,
+    lore: Lazy<String>,
+    allytips: Lazy<Vec<String>>,
+    enemytips: Lazy<Vec<String>>,
+    skins: Lazy<Vec<Skin>>,
+    spells: Lazy<Vec<Spell>>,
+    passive: Lazy<Passive>,
+}
+
+impl LazyChampion {
+    pub fn lore(&self) -> Result<&str, SamiraError> {
+        self.lore.get().map(String::as_str)
+    }
+
+    pub fn allytips(&self) -> Result<&[String], SamiraError> {
+        self.allytips.get().map(Vec::as_slice)
+    }
+
+    pub fn enemytips(&self) -> Result<&[String], SamiraError> {
+        self.enemytips.get().map(Vec::as_slice)
+    }
+
+    pub fn skins(&self) -> Result<&[Skin], SamiraError> {
+        self.skins.get().map(Vec::as_slice)
+    }
+
+    pub fn spells(&self) -> Result<&[Spell], SamiraError> {
+        self.spells.get().map(Vec::as_slice)
+    }
+
+    pub fn passive(&self) -> Result<&Passive, SamiraError> {
+        self.passive.get()
+    }
+
+    /// Deserializes every lazy field, returning the full [`Champion`].
+    pub fn to_champion(&self) -> Result<Champion, SamiraError> {
+        Ok(Champion {
+            id: self.id.clone(),
+            key: self.key,
+            name: self.name.clone(),
+            title: self.title.clone(),
+            image: self.image.clone(),
+            skins: self.skins()?.to_vec(),
+            lore: self.lore()?.to_string(),
+            blurb: self.blurb.clone(),
+            allytips: self.allytips()?.to_vec(),
+            enemytips: self.enemytips()?.to_vec(),
+            tags: self.tags.clone(),
+            partype: self.partype.clone(),
+            info: self.info.clone(),
+            stats: self.stats.clone(),
+            spells: self.spells()?.to_vec(),
+            passive: self.passive()?.clone(),
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ids::*, position::TeamPosition};
+
+/// A player registered to a clash tournament, of clash-v1.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{models::clash_model::*, position::*};
+///
+/// let player: ClashPlayer = serde_json::from_str(
+///     r#"{"summonerId": "S", "puuid": "P", "teamId": "T", "position": "UTILITY", "role": "CAPTAIN"}"#,
+/// ).unwrap();
+/// assert_eq!(player.position.team_position(), Some(TeamPosition::UTILITY));
+/// assert_eq!(player.role, ClashRole::CAPTAIN);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ClashPlayer {
+    #[serde(default)]
+    pub summoner_id: SummonerId,
+    #[serde(default)]
+    pub puuid: Option<Puuid>,
+    /// The team of the player, None while it has not joined one.
+    #[serde(default)]
+    pub team_id: Option<String>,
+    pub position: ClashPosition,
+    pub role: ClashRole,
+}
+
+/// The position a [`ClashPlayer`] registered for.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClashPosition {
+    UNSELECTED,
+    FILL,
+    TOP,
+    JUNGLE,
+    MIDDLE,
+    BOTTOM,
+    UTILITY,
+}
+
+impl ClashPosition {
+    /// Returns the position as a [`TeamPosition`], None if the player did not choose one.
+    pub fn team_position(&self) -> Option<TeamPosition> {
+        match self {
+            ClashPosition::UNSELECTED | ClashPosition::FILL => None,
+            ClashPosition::TOP => Some(TeamPosition::TOP),
+            ClashPosition::JUNGLE => Some(TeamPosition::JUNGLE),
+            ClashPosition::MIDDLE => Some(TeamPosition::MIDDLE),
+            ClashPosition::BOTTOM => Some(TeamPosition::BOTTOM),
+            ClashPosition::UTILITY => Some(TeamPosition::UTILITY),
+        }
+    }
+}
+
+/// The role of a [`ClashPlayer`] in its team.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClashRole {
+    CAPTAIN,
+    MEMBER,
+}
+
+/// A team registered to a clash tournament.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ClashTeam {
+    pub id: String,
+    pub tournament_id: i32,
+    pub name: String,
+    pub icon_id: i32,
+    /// The tier of the team, from 1 (the highest) to 4.
+    pub tier: i32,
+    /// The summoner id of the captain.
+    pub captain: SummonerId,
+    pub abbreviation: String,
+    pub players: Vec<ClashPlayer>,
+}
+
+impl ClashTeam {
+    /// Returns the captain of the team, None if it is not listed in the players.
+    pub fn captain_player(&self) -> Option<&ClashPlayer> {
+        self.players
+            .iter()
+            .find(|player| player.role == ClashRole::CAPTAIN)
+    }
+}
+
+/// A clash tournament, played over one or several phases.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::clash_model::*;
+///
+/// let tournament: Tournament = serde_json::from_str(r#"{
+///     "id": 1, "themeId": 2, "nameKey": "bilgewater", "nameKeySecondary": "day_1",
+///     "schedule": [{"id": 3, "registrationTime": 1700000000000, "startTime": 1700010000000, "cancelled": false}]
+/// }"#).unwrap();
+/// assert_eq!(tournament.schedule[0].start_time, 1700010000000);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Tournament {
+    pub id: i32,
+    pub theme_id: i32,
+    pub name_key: String,
+    pub name_key_secondary: String,
+    /// The phases of the tournament, each played on its own day.
+    pub schedule: Vec<TournamentPhase>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TournamentPhase {
+    pub id: i32,
+    /// The opening of the registrations, in milliseconds since the epoch.
+    pub registration_time: i64,
+    /// The start of the phase, in milliseconds since the epoch.
+    pub start_time: i64,
+    pub cancelled: bool,
+}
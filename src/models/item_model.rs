@@ -0,0 +1,418 @@
+use std::{collections::HashMap, fmt, iter::Sum, ops::Add};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::champion_model::Image;
+
+/// An item of the DDragon item.json file.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::item_model::*;
+///
+/// let item: Item = serde_json::from_str(r#"{
+///     "name": "Doran's Blade", "plaintext": "Good starting item for attackers",
+///     "gold": {"base": 450, "purchasable": true, "total": 450, "sell": 180},
+///     "tags": ["Damage", "Health", "LifeSteal", "Lane"], "maps": {"11": true, "12": false},
+///     "stats": {"FlatHPPoolMod": 80, "FlatPhysicalDamageMod": 10}
+/// }"#).unwrap();
+/// assert_eq!(item.is_available_on(11), true);
+/// assert_eq!(item.stats["FlatPhysicalDamageMod"], 10.0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Item {
+    /// The id of the item, the key of the item in the file.
+    pub id: i64,
+    pub name: String,
+    /// The description of the item, with its markup (`<stats>`, `<passive>`, ...).
+    pub description: String,
+    /// The other names the shop searches the item by.
+    pub colloquialism: String,
+    pub plaintext: String,
+    /// The ids of the items built from this item.
+    pub into: Vec<String>,
+    /// The ids of the components of this item.
+    pub from: Vec<String>,
+    pub image: Image,
+    pub gold: Gold,
+    pub tags: Vec<String>,
+    /// Whether the item is available on each map, keyed by map id ("11", "12", ...).
+    pub maps: HashMap<String, bool>,
+    /// The stats given by the item, keyed by their DDragon name ("FlatPhysicalDamageMod", ...).
+    pub stats: HashMap<String, f64>,
+    /// The depth of the item in its build tree, 1 for the basic items. Missing for items without components nor upgrades.
+    pub depth: Option<i32>,
+    /// The champion the item is restricted to, e.g. "Kalista" for Black Spear.
+    pub required_champion: String,
+    pub required_ally: String,
+    /// False for the items which cannot be bought in the shop. Missing when the item is in the store.
+    pub in_store: Option<bool>,
+    pub hide_from_all: bool,
+    pub consumed: bool,
+    pub consume_on_full: bool,
+    pub stacks: i32,
+    pub special_recipe: i64,
+    /// The values of the effects of the item, written as strings ("Effect1Amount": "0.5").
+    pub effect: HashMap<String, String>,
+    pub required_buff_currency_name: String,
+    pub required_buff_currency_cost: i32,
+}
+
+impl Item {
+    /// Returns true if the item is available on the given map (11 for Summoner's Rift, 12 for Howling Abyss, ...).
+    pub fn is_available_on(&self, map_id: i32) -> bool {
+        self.maps
+            .get(&map_id.to_string())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the item can be bought in the shop.
+    pub fn is_purchasable(&self) -> bool {
+        self.gold.purchasable && self.in_store != Some(false)
+    }
+
+    /// Returns true for the boots, upgraded or not.
+    pub fn is_boots(&self) -> bool {
+        self.has_tag("Boots")
+    }
+
+    /// Returns true for the items used up when activated, like potions and elixirs.
+    pub fn is_consumable(&self) -> bool {
+        self.consumed || self.has_tag("Consumable")
+    }
+
+    /// Returns true for the trinkets, the wards and lenses of the trinket slot.
+    pub fn is_trinket(&self) -> bool {
+        self.has_tag("Trinket")
+    }
+
+    /// Returns true for the completed items which can be bought, the boots aside:
+    /// the items at the third depth of a build tree or deeper.
+    pub fn is_legendary(&self) -> bool {
+        self.is_purchasable()
+            && !self.is_boots()
+            && !self.is_consumable()
+            && !self.is_trinket()
+            && self.depth.unwrap_or(1) >= 3
+    }
+
+    /// Returns true for the items built into completed items, like Long Sword or Pickaxe.
+    pub fn is_component(&self) -> bool {
+        self.is_purchasable()
+            && !self.is_consumable()
+            && !self.is_trinket()
+            && !self.into.is_empty()
+            && self.depth.unwrap_or(1) < 3
+    }
+
+    /// Returns the group of the item in the shop, see [`ItemCategory`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::models::item_model::*;
+    ///
+    /// let long_sword: Item = serde_json::from_str(r#"{
+    ///     "name": "Long Sword", "into": ["3133", "6692"], "depth": 1,
+    ///     "gold": {"base": 350, "purchasable": true, "total": 350, "sell": 245}, "tags": ["Damage"]
+    /// }"#).unwrap();
+    /// assert!(long_sword.is_component());
+    /// assert_eq!(long_sword.category(), ItemCategory::Component);
+    ///
+    /// let infinity_edge: Item = serde_json::from_str(r#"{
+    ///     "name": "Infinity Edge", "from": ["1038", "1018", "1037"], "depth": 3,
+    ///     "gold": {"base": 625, "purchasable": true, "total": 3400, "sell": 2380}, "tags": ["Damage", "CriticalStrike"]
+    /// }"#).unwrap();
+    /// assert!(infinity_edge.is_legendary());
+    /// assert_eq!(infinity_edge.category(), ItemCategory::Legendary);
+    ///
+    /// let boots: Item = serde_json::from_str(r#"{
+    ///     "name": "Berserker's Greaves", "from": ["1001", "1042"], "depth": 2,
+    ///     "gold": {"purchasable": true}, "tags": ["Boots", "AttackSpeed"]
+    /// }"#).unwrap();
+    /// assert_eq!(boots.category(), ItemCategory::Boots);
+    /// ```
+    pub fn category(&self) -> ItemCategory {
+        if self.is_trinket() {
+            ItemCategory::Trinket
+        } else if self.is_consumable() {
+            ItemCategory::Consumable
+        } else if self.is_boots() {
+            ItemCategory::Boots
+        } else if self.is_legendary() {
+            ItemCategory::Legendary
+        } else if self.is_component() {
+            ItemCategory::Component
+        } else {
+            ItemCategory::Other
+        }
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|item_tag| item_tag == tag)
+    }
+
+    /// Returns the stats given by the item, read from its stats and completed by the `<stats>` block of its description,
+    /// see [`ItemStats`].
+    pub fn item_stats(&self) -> ItemStats {
+        let mut stats = ItemStats::from_ddragon(&self.stats);
+        let mut described = ItemStats::from_description(&self.description);
+        for (stat, described) in stats.values_mut().into_iter().zip(described.values_mut()) {
+            if *stat == 0.0 {
+                *stat = *described;
+            }
+        }
+        stats
+    }
+}
+
+impl fmt::Display for Item {
+    /// Writes the name of the item.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// The group of an [`Item`] in the shop, see [`Item::category`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemCategory {
+    Trinket,
+    Consumable,
+    Boots,
+    Legendary,
+    Component,
+    /// The starting items, the items which cannot be bought and the items of the special modes.
+    Other,
+}
+
+/// The price of an [`Item`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Gold {
+    /// The price of the recipe, components excluded.
+    pub base: i32,
+    pub purchasable: bool,
+    /// The price of the item, components included.
+    pub total: i32,
+    pub sell: i32,
+}
+
+/// The stats given by an [`Item`], read from its stats and the `<stats>` block of its description,
+/// which lists the stats DDragon leaves out of the stats (ability haste, lethality, ...).
+/// The percentages are written as fractions, 0.25 for 25% attack speed.
+/// Item stats add up, to aggregate the stats of a build.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::item_model::*;
+///
+/// let item: Item = serde_json::from_str(r#"{
+///     "name": "Youmuu's Ghostblade",
+///     "description": "<mainText><stats><attention>55</attention> Attack Damage<br><attention>18</attention> Lethality<br><attention>15</attention> Ability Haste</stats></mainText>",
+///     "stats": {"FlatPhysicalDamageMod": 55}
+/// }"#).unwrap();
+/// let stats = item.item_stats();
+/// assert_eq!(stats.attack_damage, 55.0);
+/// assert_eq!(stats.lethality, 18.0);
+/// assert_eq!(stats.ability_haste, 15.0);
+///
+/// let build: ItemStats = [stats, stats].into_iter().sum();
+/// assert_eq!(build.attack_damage, 110.0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ItemStats {
+    pub attack_damage: f64,
+    pub ability_power: f64,
+    pub health: f64,
+    pub mana: f64,
+    pub armor: f64,
+    pub magic_resist: f64,
+    pub attack_speed: f64,
+    pub critical_strike_chance: f64,
+    pub life_steal: f64,
+    pub omnivamp: f64,
+    pub ability_haste: f64,
+    pub lethality: f64,
+    pub armor_penetration: f64,
+    /// The flat magic penetration.
+    pub magic_penetration: f64,
+    pub magic_penetration_percent: f64,
+    /// The flat move speed.
+    pub move_speed: f64,
+    pub move_speed_percent: f64,
+    /// The flat health regeneration per 5 seconds, from the stats.
+    pub health_regen: f64,
+    /// The base health regeneration bonus, 1.0 for 100%.
+    pub base_health_regen: f64,
+    /// The base mana regeneration bonus, 1.0 for 100%.
+    pub base_mana_regen: f64,
+    pub heal_and_shield_power: f64,
+    pub tenacity: f64,
+}
+
+impl ItemStats {
+    /// Reads the stats of DDragon, keyed by their DDragon name ("FlatPhysicalDamageMod", ...).
+    /// The unknown stats are ignored.
+    pub fn from_ddragon(stats: &HashMap<String, f64>) -> ItemStats {
+        let mut item_stats = ItemStats::default();
+        for (name, &value) in stats {
+            if let Some(stat) = item_stats.ddragon_stat(name) {
+                *stat += value;
+            }
+        }
+        item_stats
+    }
+
+    /// Reads the `<stats>` block of a description, one stat per line ("<attention>55</attention> Attack Damage").
+    /// The unknown stats are ignored.
+    pub fn from_description(description: &str) -> ItemStats {
+        let mut item_stats = ItemStats::default();
+        let block = match description
+            .split_once("<stats>")
+            .and_then(|(_, stats)| stats.split_once("</stats>"))
+        {
+            Some((block, _)) => block,
+            None => return item_stats,
+        };
+        for line in block.split("<br>") {
+            let line = strip_markup(line);
+            let (value, name) = match line.trim().split_once(' ') {
+                Some(stat) => stat,
+                None => continue,
+            };
+            let (value, percent) = match value.strip_suffix('%') {
+                Some(value) => (value, true),
+                None => (value, false),
+            };
+            let value: f64 = match value.trim_start_matches('+').parse() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            let value = if percent { value / 100.0 } else { value };
+            if let Some(stat) = item_stats.description_stat(name.trim(), percent) {
+                *stat += value;
+            }
+        }
+        item_stats
+    }
+
+    fn ddragon_stat(&mut self, name: &str) -> Option<&mut f64> {
+        match name {
+            "FlatPhysicalDamageMod" => Some(&mut self.attack_damage),
+            "FlatMagicDamageMod" => Some(&mut self.ability_power),
+            "FlatHPPoolMod" => Some(&mut self.health),
+            "FlatMPPoolMod" => Some(&mut self.mana),
+            "FlatArmorMod" => Some(&mut self.armor),
+            "FlatSpellBlockMod" => Some(&mut self.magic_resist),
+            "PercentAttackSpeedMod" => Some(&mut self.attack_speed),
+            "FlatCritChanceMod" => Some(&mut self.critical_strike_chance),
+            "PercentLifeStealMod" => Some(&mut self.life_steal),
+            "FlatMovementSpeedMod" => Some(&mut self.move_speed),
+            "PercentMovementSpeedMod" => Some(&mut self.move_speed_percent),
+            "FlatHPRegenMod" => Some(&mut self.health_regen),
+            _ => None,
+        }
+    }
+
+    fn description_stat(&mut self, name: &str, percent: bool) -> Option<&mut f64> {
+        match (name, percent) {
+            ("Attack Damage", _) => Some(&mut self.attack_damage),
+            ("Ability Power", _) => Some(&mut self.ability_power),
+            ("Health", _) => Some(&mut self.health),
+            ("Mana", _) => Some(&mut self.mana),
+            ("Armor", _) => Some(&mut self.armor),
+            ("Magic Resist", _) => Some(&mut self.magic_resist),
+            ("Attack Speed", _) => Some(&mut self.attack_speed),
+            ("Critical Strike Chance", _) | ("Critical Strike", _) => {
+                Some(&mut self.critical_strike_chance)
+            }
+            ("Life Steal", _) => Some(&mut self.life_steal),
+            ("Omnivamp", _) => Some(&mut self.omnivamp),
+            ("Ability Haste", _) => Some(&mut self.ability_haste),
+            ("Lethality", _) => Some(&mut self.lethality),
+            ("Armor Penetration", _) => Some(&mut self.armor_penetration),
+            ("Magic Penetration", false) => Some(&mut self.magic_penetration),
+            ("Magic Penetration", true) => Some(&mut self.magic_penetration_percent),
+            ("Move Speed", false) => Some(&mut self.move_speed),
+            ("Move Speed", true) => Some(&mut self.move_speed_percent),
+            ("Base Health Regen", _) => Some(&mut self.base_health_regen),
+            ("Base Mana Regen", _) => Some(&mut self.base_mana_regen),
+            ("Heal and Shield Power", _) => Some(&mut self.heal_and_shield_power),
+            ("Tenacity", _) => Some(&mut self.tenacity),
+            _ => None,
+        }
+    }
+
+    /// Returns every stat, in the order of the fields.
+    fn values_mut(&mut self) -> [&mut f64; 22] {
+        [
+            &mut self.attack_damage,
+            &mut self.ability_power,
+            &mut self.health,
+            &mut self.mana,
+            &mut self.armor,
+            &mut self.magic_resist,
+            &mut self.attack_speed,
+            &mut self.critical_strike_chance,
+            &mut self.life_steal,
+            &mut self.omnivamp,
+            &mut self.ability_haste,
+            &mut self.lethality,
+            &mut self.armor_penetration,
+            &mut self.magic_penetration,
+            &mut self.magic_penetration_percent,
+            &mut self.move_speed,
+            &mut self.move_speed_percent,
+            &mut self.health_regen,
+            &mut self.base_health_regen,
+            &mut self.base_mana_regen,
+            &mut self.heal_and_shield_power,
+            &mut self.tenacity,
+        ]
+    }
+}
+
+impl Add for ItemStats {
+    type Output = ItemStats;
+
+    fn add(mut self, mut other: ItemStats) -> ItemStats {
+        for (stat, other) in self.values_mut().into_iter().zip(other.values_mut()) {
+            *stat += *other;
+        }
+        self
+    }
+}
+
+impl Sum for ItemStats {
+    fn sum<I: Iterator<Item = ItemStats>>(iter: I) -> ItemStats {
+        iter.fold(ItemStats::default(), Add::add)
+    }
+}
+
+/// Removes the tags of a line of a description ("<attention>55</attention> Attack Damage").
+fn strip_markup(line: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for character in line.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            character if !in_tag => text.push(character),
+            _ => {}
+        }
+    }
+    text
+}
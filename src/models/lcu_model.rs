@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{champion_id::ChampionId, ids::Puuid};
+
+/// The summoner logged in the League client, of `/lol-summoner/v1/current-summoner`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::lcu_model::*;
+///
+/// let summoner: LcuSummoner = serde_json::from_str(r#"{
+///     "accountId": 200000000, "summonerId": 100000000, "puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW",
+///     "summonerLevel": 312, "profileIconId": 4568, "xpSinceLastLevel": 1200, "xpUntilNextLevel": 3000
+/// }"#).unwrap();
+/// assert_eq!(summoner.riot_id(), "RqndomHax#EUW");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LcuSummoner {
+    pub account_id: i64,
+    pub summoner_id: i64,
+    pub puuid: Puuid,
+    pub game_name: String,
+    pub tag_line: String,
+    /// The former summoner name, replaced by the Riot ID.
+    pub display_name: String,
+    pub internal_name: String,
+    pub summoner_level: i64,
+    pub profile_icon_id: i32,
+    pub percent_complete_for_next_level: i32,
+    pub xp_since_last_level: i64,
+    pub xp_until_next_level: i64,
+    pub name_change_flag: bool,
+    pub unnamed: bool,
+}
+
+impl LcuSummoner {
+    /// Returns the Riot ID of the summoner ("Name#TAG").
+    pub fn riot_id(&self) -> String {
+        format!("{}#{}", self.game_name, self.tag_line)
+    }
+}
+
+/// The champion select the League client is in, of `/lol-champ-select/v1/session`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{champion_id::*, models::lcu_model::*};
+///
+/// let session: ChampSelectSession = serde_json::from_str(r#"{
+///     "gameId": 6000000000, "localPlayerCellId": 3,
+///     "myTeam": [{"cellId": 3, "championId": 360, "assignedPosition": "bottom", "spell1Id": 4, "spell2Id": 7, "team": 1}],
+///     "theirTeam": [],
+///     "actions": [[{"id": 1, "actorCellId": 3, "championId": 360, "completed": false, "isAllyAction": true, "isInProgress": true, "type": "pick"}]],
+///     "bans": {"myTeamBans": [157], "theirTeamBans": [], "numBans": 10},
+///     "timer": {"adjustedTimeLeftInPhase": 27000, "phase": "BAN_PICK", "isInfinite": false, "totalTimeInPhase": 30000}
+/// }"#).unwrap();
+/// assert_eq!(session.local_player().unwrap().champion(), Some(ChampionId::Samira));
+/// assert_eq!(session.current_action().unwrap().kind, "pick");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampSelectSession {
+    pub game_id: i64,
+    /// The picks and bans, grouped by turn.
+    pub actions: Vec<Vec<ChampSelectAction>>,
+    pub bans: ChampSelectBans,
+    /// The cell of the player running the client.
+    pub local_player_cell_id: i64,
+    pub my_team: Vec<ChampSelectPlayer>,
+    pub their_team: Vec<ChampSelectPlayer>,
+    pub timer: ChampSelectTimer,
+    pub is_spectating: bool,
+    pub allow_rerolling: bool,
+    pub bench_enabled: bool,
+    /// The champion keys on the bench, for ARAM.
+    pub bench_champions: Vec<BenchChampion>,
+}
+
+impl ChampSelectSession {
+    /// Returns the player running the client, None while spectating.
+    pub fn local_player(&self) -> Option<&ChampSelectPlayer> {
+        self.my_team
+            .iter()
+            .find(|player| player.cell_id == self.local_player_cell_id)
+    }
+
+    /// Returns the pick or ban being made.
+    pub fn current_action(&self) -> Option<&ChampSelectAction> {
+        self.actions
+            .iter()
+            .flatten()
+            .find(|action| action.is_in_progress)
+    }
+}
+
+/// A pick or a ban made by a [`ChampSelectPlayer`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampSelectAction {
+    pub id: i64,
+    pub actor_cell_id: i64,
+    /// The key of the hovered or locked champion, 0 if none.
+    pub champion_id: i64,
+    pub completed: bool,
+    pub is_ally_action: bool,
+    pub is_in_progress: bool,
+    /// The kind of action ("pick", "ban", "ten_bans_reveal", ...).
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub pick_turn: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampSelectBans {
+    pub my_team_bans: Vec<i64>,
+    pub their_team_bans: Vec<i64>,
+    pub num_bans: i32,
+}
+
+/// A player of a [`ChampSelectSession`], the fields of the enemy players are mostly hidden.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampSelectPlayer {
+    pub cell_id: i64,
+    /// The key of the locked champion, 0 if none.
+    pub champion_id: i64,
+    /// The key of the champion the player intends to pick, 0 if none.
+    pub champion_pick_intent: i64,
+    /// The assigned position ("top", "jungle", "middle", "bottom", "utility"), empty in blind pick.
+    pub assigned_position: String,
+    pub selected_skin_id: i64,
+    /// The summoner spells, u64::MAX if none is selected.
+    pub spell1_id: u64,
+    pub spell2_id: u64,
+    pub summoner_id: i64,
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+    /// The team of the player, 1 for the blue side and 2 for the red side.
+    pub team: i32,
+}
+
+impl ChampSelectPlayer {
+    /// Returns the locked champion, None if the player did not lock one or if it is unknown by this version of samira.
+    pub fn champion(&self) -> Option<ChampionId> {
+        ChampionId::from_key(self.champion_id as i32)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampSelectTimer {
+    /// The time left in the phase, in milliseconds.
+    pub adjusted_time_left_in_phase: i64,
+    pub total_time_in_phase: i64,
+    /// The phase ("PLANNING", "BAN_PICK", "FINALIZATION", "GAME_STARTING").
+    pub phase: String,
+    pub is_infinite: bool,
+    pub internal_now_in_epoch_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BenchChampion {
+    pub champion_id: i64,
+    pub is_priority: bool,
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ids::*, queue::Queue, rank::*};
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LeagueEntry {
+    pub league_id: String,
+    pub summoner_id: SummonerId,
+    /// Riot stopped sending the summoner names, it is empty for the latest responses.
+    #[serde(default)]
+    pub summoner_name: String,
+    #[serde(default)]
+    pub puuid: Option<Puuid>,
+    pub queue_type: String,
+    pub tier: String,
+    pub rank: String,
+    pub league_points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    #[serde(default)]
+    pub veteran: bool,
+    #[serde(default)]
+    pub inactive: bool,
+    #[serde(default)]
+    pub fresh_blood: bool,
+    #[serde(default)]
+    pub hot_streak: bool,
+    /// The promotion series the player is playing, if any.
+    #[serde(default)]
+    pub mini_series: Option<MiniSeries>,
+}
+
+impl LeagueEntry {
+    /// Returns the queue of the entry, None if it is unknown.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::league_model::*, queue::*};
+    ///
+    /// let entry = LeagueEntry {queue_type: "RANKED_SOLO_5x5".to_string(), ..Default::default()};
+    /// assert_eq!(entry.queue(), Some(Queue::RANKED_SOLO_5x5));
+    /// ```
+    pub fn queue(&self) -> Option<Queue> {
+        Queue::from_name(&self.queue_type)
+    }
+
+    /// Returns the rank of the entry, None if the tier or the division is unknown.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::league_model::*, rank::*};
+    ///
+    /// let entry = LeagueEntry {tier: "GOLD".to_string(), rank: "II".to_string(), league_points: 42, ..Default::default()};
+    /// assert_eq!(entry.rank(), Some(Rank::new(Tier::GOLD, Division::II, 42)));
+    /// ```
+    pub fn rank(&self) -> Option<Rank> {
+        Some(Rank::new(
+            Tier::from_name(&self.tier)?,
+            Division::from_name(&self.rank)?,
+            self.league_points,
+        ))
+    }
+}
+
+impl fmt::Display for LeagueEntry {
+    /// Writes the rank of the entry ("Gold II, 56 LP"), as written by the Riot API if it is unknown.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::models::league_model::*;
+    ///
+    /// let entry = LeagueEntry {tier: "GOLD".to_string(), rank: "II".to_string(), league_points: 56, ..Default::default()};
+    /// assert_eq!(entry.to_string(), "Gold II, 56 LP");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rank() {
+            Some(rank) => rank.fmt(f),
+            None => write!(f, "{} {}, {} LP", self.tier, self.rank, self.league_points),
+        }
+    }
+}
+
+/// A promotion series, played to reach the next tier.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::league_model::*;
+///
+/// let entry: LeagueEntry = serde_json::from_str(r#"{
+///     "leagueId": "", "summonerId": "", "queueType": "RANKED_SOLO_5x5", "tier": "GOLD", "rank": "I",
+///     "leaguePoints": 100, "wins": 20, "losses": 18, "hotStreak": true,
+///     "miniSeries": {"losses": 1, "progress": "WLWNN", "target": 3, "wins": 2}
+/// }"#).unwrap();
+/// let series = entry.mini_series.unwrap();
+/// assert_eq!(series.results(), [Some(true), Some(false), Some(true), None, None]);
+/// assert!(!series.is_over());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MiniSeries {
+    pub losses: i32,
+    /// The games of the series in order, "W" for a win, "L" for a loss and "N" for a game left to play.
+    pub progress: String,
+    /// The number of wins needed to be promoted.
+    pub target: i32,
+    pub wins: i32,
+}
+
+impl MiniSeries {
+    /// Returns the games of the series in order, true for a win, false for a loss and None for a game left to play.
+    pub fn results(&self) -> Vec<Option<bool>> {
+        self.progress
+            .chars()
+            .map(|game| match game {
+                'W' => Some(true),
+                'L' => Some(false),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns true if the player was promoted, false if the series is not over or was lost.
+    pub fn is_won(&self) -> bool {
+        self.wins >= self.target
+    }
+
+    /// Returns true if the series was won or lost.
+    pub fn is_over(&self) -> bool {
+        self.is_won() || !self.progress.contains('N')
+    }
+}
+
+/// A league of league-v4, the whole ladder of an apex tier.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{models::league_model::*, rank::*};
+///
+/// let league: LeagueList = serde_json::from_str(r#"{
+///     "tier": "CHALLENGER", "leagueId": "ID", "queue": "RANKED_SOLO_5x5", "name": "Samira's Hellions",
+///     "entries": [{"summonerId": "ID", "puuid": "PUUID", "leaguePoints": 1500, "rank": "I", "wins": 200, "losses": 150}]
+/// }"#).unwrap();
+/// assert_eq!(league.tier(), Some(Tier::CHALLENGER));
+/// assert_eq!(league.entries[0].league_points, 1500);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LeagueList {
+    pub league_id: String,
+    pub entries: Vec<LeagueItem>,
+    pub tier: String,
+    pub name: String,
+    /// The queue type of the league ("RANKED_SOLO_5x5", ...).
+    pub queue: String,
+}
+
+impl LeagueList {
+    /// Returns the tier of the league, None if it is unknown.
+    pub fn tier(&self) -> Option<Tier> {
+        Tier::from_name(&self.tier)
+    }
+}
+
+/// A player of a [`LeagueList`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LeagueItem {
+    pub summoner_id: SummonerId,
+    /// Riot stopped sending the summoner names, it is empty for the latest responses.
+    pub summoner_name: String,
+    pub puuid: Option<Puuid>,
+    pub league_points: i32,
+    /// The division of the player, always "I" in the apex tiers.
+    pub rank: String,
+    pub wins: i32,
+    pub losses: i32,
+    pub veteran: bool,
+    pub inactive: bool,
+    pub fresh_blood: bool,
+    pub hot_streak: bool,
+    pub mini_series: Option<MiniSeries>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct RankedEmblem {
+    pub emblem: String,
+    pub wings: String,
+    pub crest: String,
+}
@@ -0,0 +1,411 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::team::TeamId;
+
+/// Everything the Live Client Data API knows about the game being played on this machine.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::live_client_model::*;
+///
+/// let game: AllGameData = serde_json::from_str(r#"{
+///     "activePlayer": {"riotId": "RqndomHax#EUW", "level": 6, "currentGold": 512.5},
+///     "allPlayers": [{
+///         "championName": "Samira", "rawChampionName": "game_character_displayname_Samira", "level": 6,
+///         "isBot": false, "isDead": false, "respawnTimer": 0.0, "position": "BOTTOM", "team": "CHAOS",
+///         "riotId": "RqndomHax#EUW", "skinID": 0,
+///         "items": [{"itemID": 1055, "displayName": "Doran's Blade", "count": 1, "slot": 0, "price": 450, "canUse": false, "consumable": false}],
+///         "scores": {"kills": 2, "deaths": 0, "assists": 1, "creepScore": 40, "wardScore": 3.5}
+///     }],
+///     "events": {"Events": [{"EventID": 0, "EventName": "GameStart", "EventTime": 0.05}]},
+///     "gameData": {"gameMode": "CLASSIC", "gameTime": 612.3, "mapName": "Map11", "mapNumber": 11, "mapTerrain": "Default"}
+/// }"#).unwrap();
+/// assert_eq!(game.active_player.level, 6);
+/// assert_eq!(game.active_player().unwrap().scores.kills, 2);
+/// assert_eq!(game.events.events[0].kind, LiveEventKind::GameStart);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AllGameData {
+    pub active_player: ActivePlayer,
+    pub all_players: Vec<LivePlayer>,
+    pub events: LiveEvents,
+    pub game_data: LiveGameData,
+}
+
+impl AllGameData {
+    /// Returns the entry of the player list of the player running the client, None while spectating.
+    pub fn active_player(&self) -> Option<&LivePlayer> {
+        self.all_players.iter().find(|player| {
+            !player.riot_id.is_empty() && player.riot_id == self.active_player.riot_id
+        })
+    }
+}
+
+/// The player running the client, its stats are only known to itself.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ActivePlayer {
+    pub abilities: Abilities,
+    pub champion_stats: LiveChampionStats,
+    pub current_gold: f64,
+    pub full_runes: FullRunes,
+    pub level: i32,
+    /// The Riot ID of the player ("Name#TAG").
+    pub riot_id: String,
+    pub riot_id_game_name: String,
+    pub riot_id_tag_line: String,
+    pub summoner_name: String,
+    /// True if the client colors the teams from the player's point of view.
+    pub team_relative_colors: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Abilities {
+    #[serde(rename = "Passive")]
+    pub passive: Ability,
+    #[serde(rename = "Q")]
+    pub q: Ability,
+    #[serde(rename = "W")]
+    pub w: Ability,
+    #[serde(rename = "E")]
+    pub e: Ability,
+    #[serde(rename = "R")]
+    pub r: Ability,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Ability {
+    /// The points spent in the ability, 0 for the passive.
+    pub ability_level: i32,
+    pub display_name: String,
+    pub id: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+/// The current stats of the [`ActivePlayer`], items and buffs included.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveChampionStats {
+    pub ability_haste: f64,
+    pub ability_power: f64,
+    pub armor: f64,
+    pub armor_penetration_flat: f64,
+    pub armor_penetration_percent: f64,
+    pub attack_damage: f64,
+    pub attack_range: f64,
+    pub attack_speed: f64,
+    pub bonus_armor_penetration_percent: f64,
+    pub bonus_magic_penetration_percent: f64,
+    pub crit_chance: f64,
+    pub crit_damage: f64,
+    pub current_health: f64,
+    pub heal_shield_power: f64,
+    pub health_regen_rate: f64,
+    pub life_steal: f64,
+    pub magic_lethality: f64,
+    pub magic_penetration_flat: f64,
+    pub magic_penetration_percent: f64,
+    pub magic_resist: f64,
+    pub max_health: f64,
+    pub move_speed: f64,
+    pub omnivamp: f64,
+    pub physical_lethality: f64,
+    pub physical_vamp: f64,
+    pub resource_max: f64,
+    pub resource_regen_rate: f64,
+    /// The resource of the champion ("MANA", "ENERGY", "NONE", ...).
+    pub resource_type: String,
+    pub resource_value: f64,
+    pub spell_vamp: f64,
+    pub tenacity: f64,
+}
+
+/// The runes of the [`ActivePlayer`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FullRunes {
+    pub general_runes: Vec<LiveRune>,
+    pub keystone: LiveRune,
+    pub primary_rune_tree: LiveRune,
+    pub secondary_rune_tree: LiveRune,
+    pub stat_runes: Vec<LiveRune>,
+}
+
+/// A rune or a rune path, its id is the one of DDragon's runes.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveRune {
+    pub display_name: String,
+    pub id: i64,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+/// A player of the game, as listed by every client.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LivePlayer {
+    pub champion_name: String,
+    pub raw_champion_name: String,
+    pub is_bot: bool,
+    pub is_dead: bool,
+    pub items: Vec<LiveItem>,
+    pub level: i32,
+    /// The position of the player ("TOP", "JUNGLE", "MIDDLE", "BOTTOM", "UTILITY"), empty outside of Summoner's Rift.
+    pub position: String,
+    /// The time left before the player respawns, in seconds.
+    pub respawn_timer: f64,
+    pub runes: LiveRunes,
+    pub scores: LiveScores,
+    #[serde(rename = "skinID")]
+    pub skin_id: i32,
+    pub summoner_name: String,
+    pub summoner_spells: LiveSummonerSpells,
+    pub team: LiveTeam,
+    /// The Riot ID of the player ("Name#TAG").
+    pub riot_id: String,
+    pub riot_id_game_name: String,
+    pub riot_id_tag_line: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveItem {
+    pub can_use: bool,
+    pub consumable: bool,
+    pub count: i32,
+    pub display_name: String,
+    #[serde(rename = "itemID")]
+    pub item_id: i64,
+    pub price: i32,
+    pub raw_description: String,
+    pub raw_display_name: String,
+    /// The inventory slot of the item, from 0 to 6 where 6 is the trinket.
+    pub slot: i32,
+}
+
+/// The runes of a [`LivePlayer`], the other clients only know its keystone and rune paths.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveRunes {
+    pub keystone: LiveRune,
+    pub primary_rune_tree: LiveRune,
+    pub secondary_rune_tree: LiveRune,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveScores {
+    pub assists: i32,
+    pub creep_score: i32,
+    pub deaths: i32,
+    pub kills: i32,
+    pub ward_score: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveSummonerSpells {
+    pub summoner_spell_one: LiveSummonerSpell,
+    pub summoner_spell_two: LiveSummonerSpell,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveSummonerSpell {
+    pub display_name: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+/// The side of a team as named by the game client.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub enum LiveTeam {
+    /// The blue side.
+    #[default]
+    ORDER,
+    /// The red side.
+    CHAOS,
+}
+
+impl LiveTeam {
+    /// Returns the team as a [`TeamId`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::live_client_model::*, team::*};
+    ///
+    /// assert_eq!(LiveTeam::CHAOS.team_id(), TeamId::RED);
+    /// ```
+    pub fn team_id(&self) -> TeamId {
+        match self {
+            LiveTeam::ORDER => TeamId::BLUE,
+            LiveTeam::CHAOS => TeamId::RED,
+        }
+    }
+}
+
+/// The events of the game, in the order they happened.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveEvents {
+    #[serde(rename = "Events")]
+    pub events: Vec<LiveEvent>,
+}
+
+/// An event of the game, its kind holds the fields specific to it.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::live_client_model::*;
+///
+/// let event: LiveEvent = serde_json::from_str(r#"{
+///     "EventID": 12, "EventName": "DragonKill", "EventTime": 631.2, "DragonType": "Fire",
+///     "Stolen": "False", "KillerName": "RqndomHax", "Assisters": []
+/// }"#).unwrap();
+/// assert_eq!(event.id, 12);
+/// assert!(matches!(event.kind, LiveEventKind::DragonKill { stolen: false, .. }));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LiveEvent {
+    #[serde(rename = "EventID")]
+    pub id: i64,
+    /// The time of the event since the start of the game, in seconds.
+    #[serde(rename = "EventTime")]
+    pub time: f64,
+    #[serde(flatten)]
+    pub kind: LiveEventKind,
+}
+
+/// The kind of a [`LiveEvent`], named after its `EventName`.
+/// Events unknown by this version of samira are kept as raw JSON in [`LiveEventKind::Other`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "EventName", rename_all_fields = "PascalCase")]
+pub enum LiveEventKind {
+    GameStart,
+    MinionsSpawning,
+    FirstBrick {
+        killer_name: String,
+    },
+    FirstBlood {
+        recipient: String,
+    },
+    TurretKilled {
+        killer_name: String,
+        /// The internal name of the turret, e.g. "Turret_T2_R_03_A".
+        turret_killed: String,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    InhibKilled {
+        killer_name: String,
+        inhib_killed: String,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    InhibRespawningSoon {
+        inhib_respawning_soon: String,
+    },
+    InhibRespawned {
+        inhib_respawned: String,
+    },
+    DragonKill {
+        killer_name: String,
+        /// The element of the dragon ("Fire", "Earth", "Water", "Air", "Hextech", "Chemtech", "Elder").
+        dragon_type: String,
+        #[serde(deserialize_with = "deserialize_bool")]
+        stolen: bool,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    HeraldKill {
+        killer_name: String,
+        #[serde(deserialize_with = "deserialize_bool")]
+        stolen: bool,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    BaronKill {
+        killer_name: String,
+        #[serde(deserialize_with = "deserialize_bool")]
+        stolen: bool,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    ChampionKill {
+        killer_name: String,
+        victim_name: String,
+        #[serde(default)]
+        assisters: Vec<String>,
+    },
+    Multikill {
+        killer_name: String,
+        kill_streak: i32,
+    },
+    Ace {
+        acer: String,
+        acing_team: LiveTeam,
+    },
+    GameEnd {
+        /// "Win" or "Lose", from the point of view of the player running the client.
+        result: String,
+    },
+    #[serde(untagged)]
+    Other(Value),
+}
+
+/// The state of the game itself.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LiveGameData {
+    /// The game mode ("CLASSIC", "ARAM", "PRACTICETOOL", ...).
+    pub game_mode: String,
+    /// The time elapsed since the start of the game, in seconds.
+    pub game_time: f64,
+    pub map_name: String,
+    pub map_number: i32,
+    pub map_terrain: String,
+}
+
+/// Deserializes a boolean written by the game as a string ("True" or "False").
+fn deserialize_bool<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Bool {
+        Bool(bool),
+        String(String),
+    }
+    match Bool::deserialize(deserializer)? {
+        Bool::Bool(value) => Ok(value),
+        Bool::String(value) => Ok(value.eq_ignore_ascii_case("true")),
+    }
+}
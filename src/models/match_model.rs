@@ -1,15 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    champion_id::ChampionId,
+    game::*,
+    ids::*,
+    position::{deserialize_position, *},
+    team::*,
+    version::GameVersion,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Metadata {
     pub data_version: String,
-    pub match_id: String,
-    pub participants: Vec<String>,
+    pub match_id: MatchId,
+    pub participants: Vec<Puuid>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PerkStats {
     pub defense: i32,
     pub flex: i32,
     pub offense: i32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PerkStyleSelection {
     pub perk: i32,
     pub var1: i32,
@@ -17,20 +37,30 @@ pub struct PerkStyleSelection {
     pub var3: i32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PerkStyle {
     pub description: String,
     pub selections: Vec<PerkStyleSelection>,
     pub style: i32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Perks {
     pub stat_perks: PerkStats,
     pub styles: Vec<PerkStyle>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Participant {
     pub assists: i32,
     pub baron_kills: i32,
+    #[serde(default)]
     pub bounty_level: i32,
     pub champ_experience: i32,
     pub champ_level: i32,
@@ -54,9 +84,12 @@ pub struct Participant {
     pub game_ended_in_surrender: bool,
     pub gold_earned: i32,
     pub gold_spent: i32,
-    pub individual_position: String,
+    #[serde(default, deserialize_with = "deserialize_position")]
+    pub individual_position: Option<TeamPosition>,
     pub inhibitor_kills: i32,
+    #[serde(default)]
     pub inhibitor_takedowns: i32,
+    #[serde(default)]
     pub inhibitors_lost: i32,
     pub item0: i32,
     pub item1: i32,
@@ -68,7 +101,7 @@ pub struct Participant {
     pub items_purchased: i32,
     pub killing_sprees: i32,
     pub kills: i32,
-    pub lane: String,
+    pub lane: Lane,
     pub largest_critical_strike: i32,
     pub largest_killing_spree: i32,
     pub largest_multi_kill: i32,
@@ -78,22 +111,28 @@ pub struct Participant {
     pub magic_damage_taken: i32,
     pub neutral_minions_killed: i32,
     pub nexus_kills: i32,
+    #[serde(default)]
     pub nexus_takedowns: i32,
+    #[serde(default)]
     pub nexus_lost: i32,
     pub objectives_stolen: i32,
+    #[serde(rename = "objectivesStolenAssists")]
     pub objectives_stolen_assits: i32,
     pub participant_id: i32,
     pub penta_kills: i32,
+    #[serde(default)]
     pub perks: Perks,
     pub physical_damage_dealt: i32,
     pub physical_damage_dealt_to_champions: i32,
     pub physical_damage_taken: i32,
     pub profile_icon: i32,
-    pub puuid: String,
+    pub puuid: Puuid,
     pub quadra_kills: i32,
+    #[serde(default, rename = "riotIdGameName", alias = "riotIdName")]
     pub riot_id_name: String,
+    #[serde(default)]
     pub riot_id_tagline: String,
-    pub role: String,
+    pub role: Role,
     pub sight_wards_bought_in_game: i32,
     pub spell1_casts: i32,
     pub spell2_casts: i32,
@@ -103,21 +142,27 @@ pub struct Participant {
     pub summoner1_id: i32,
     pub summoner2_casts: i32,
     pub summoner2_id: i32,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
     pub summoner_level: i32,
+    /// Riot stopped sending the summoner names, it is empty for the latest matches.
+    #[serde(default)]
     pub summoner_name: String,
     pub team_early_surrendered: bool,
-    pub team_id: i32,
-    pub team_position: String,
+    pub team_id: TeamId,
+    #[serde(default, deserialize_with = "deserialize_position")]
+    pub team_position: Option<TeamPosition>,
+    #[serde(rename = "timeCCingOthers")]
     pub time_ccing_others: i32,
     pub time_played: i32,
     pub total_damage_dealt: i32,
+    #[serde(rename = "totalDamageDealtToChampions")]
     pub total_damage_deal_to_champions: i32,
     pub total_damage_shielded_on_teammates: i32,
     pub total_damage_taken: i32,
     pub total_heal: i32,
     pub total_heals_on_teammates: i32,
     pub total_minions_killed: i32,
+    #[serde(rename = "totalTimeCCDealt")]
     pub total_time_cc_dealt: i32,
     pub total_time_spent_dead: i32,
     pub total_units_healed: i32,
@@ -126,7 +171,9 @@ pub struct Participant {
     pub true_damage_dealt_to_champions: i32,
     pub true_damage_taken: i32,
     pub turret_kills: i32,
+    #[serde(default)]
     pub turret_takedowns: i32,
+    #[serde(default)]
     pub turrets_lost: i32,
     pub unreal_kills: i32,
     pub vision_score: i32,
@@ -134,13 +181,245 @@ pub struct Participant {
     pub wards_killed: i32,
     pub wards_placed: i32,
     pub win: bool,
+    /// The challenges progressed during the match, since patch 12.9.
+    #[serde(default)]
+    pub challenges: Option<Challenges>,
+    #[serde(default)]
+    pub total_ally_jungle_minions_killed: i32,
+    #[serde(default)]
+    pub total_enemy_jungle_minions_killed: i32,
+    #[serde(default)]
+    pub damage_dealt_to_epic_monsters: i32,
+    #[serde(default)]
+    pub eligible_for_progression: bool,
+    #[serde(default)]
+    pub all_in_pings: i32,
+    #[serde(default)]
+    pub assist_me_pings: i32,
+    #[serde(default)]
+    pub basic_pings: i32,
+    #[serde(default)]
+    pub command_pings: i32,
+    #[serde(default)]
+    pub danger_pings: i32,
+    #[serde(default)]
+    pub enemy_missing_pings: i32,
+    #[serde(default)]
+    pub enemy_vision_pings: i32,
+    #[serde(default)]
+    pub get_back_pings: i32,
+    #[serde(default)]
+    pub hold_pings: i32,
+    #[serde(default)]
+    pub need_vision_pings: i32,
+    #[serde(default)]
+    pub on_my_way_pings: i32,
+    #[serde(default)]
+    pub push_pings: i32,
+    #[serde(default)]
+    pub retreat_pings: i32,
+    #[serde(default)]
+    pub vision_cleared_pings: i32,
+    /// The augments picked in Arena games, 0 for an empty slot.
+    #[serde(default)]
+    pub player_augment1: i32,
+    #[serde(default)]
+    pub player_augment2: i32,
+    #[serde(default)]
+    pub player_augment3: i32,
+    #[serde(default)]
+    pub player_augment4: i32,
+    /// The duo of the participant in Arena games.
+    #[serde(default)]
+    pub player_subteam_id: i32,
+    /// The final placement of the duo in Arena games.
+    #[serde(default)]
+    pub subteam_placement: i32,
+    #[serde(default)]
+    pub placement: i32,
+}
+
+/// The progress of the challenges during a match, see [`Participant::challenges`].
+/// Riot adds and removes challenges every few patches, missing ones default to zero.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::match_model::*;
+///
+/// let challenges: Challenges = serde_json::from_str(r#"{"kda": 4.5, "12AssistStreakCount": 1, "soloKills": 2}"#).unwrap();
+/// assert_eq!(challenges.kda, 4.5);
+/// assert_eq!(challenges.assist_streak_count_12, 1);
+/// assert_eq!(challenges.earliest_baron, None);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Challenges {
+    #[serde(rename = "12AssistStreakCount")]
+    pub assist_streak_count_12: i32,
+    #[serde(rename = "InfernalScalePickup")]
+    pub infernal_scale_pickup: i32,
+    pub ability_uses: i32,
+    pub aces_before15_minutes: i32,
+    pub allied_jungle_monster_kills: f64,
+    pub baron_buff_gold_advantage_over_threshold: i32,
+    pub baron_takedowns: i32,
+    pub blast_cone_opposite_opponent_count: i32,
+    pub bounty_gold: f64,
+    pub buffs_stolen: i32,
+    pub complete_support_quest_in_time: i32,
+    pub control_ward_time_coverage_in_river_or_enemy_half: Option<f64>,
+    pub control_wards_placed: i32,
+    pub damage_per_minute: f64,
+    pub damage_taken_on_team_percentage: f64,
+    pub danced_with_rift_herald: i32,
+    pub deaths_by_enemy_champs: i32,
+    pub dodge_skill_shots_small_window: i32,
+    pub double_aces: i32,
+    pub dragon_takedowns: i32,
+    pub earliest_baron: Option<f64>,
+    pub earliest_dragon_takedown: Option<f64>,
+    pub earliest_elder_dragon: Option<f64>,
+    pub early_laning_phase_gold_exp_advantage: f64,
+    pub effective_heal_and_shielding: f64,
+    pub elder_dragon_kills_with_opposing_soul: i32,
+    pub elder_dragon_multikills: i32,
+    pub enemy_champion_immobilizations: i32,
+    pub enemy_jungle_monster_kills: f64,
+    pub epic_monster_kills_near_enemy_jungler: i32,
+    pub epic_monster_kills_within30_seconds_of_spawn: i32,
+    pub epic_monster_steals: i32,
+    pub epic_monster_stolen_without_smite: i32,
+    pub faster_support_quest_completion: Option<i32>,
+    pub fastest_legendary: Option<f64>,
+    pub first_turret_killed: i32,
+    pub first_turret_killed_time: Option<f64>,
+    pub fist_bump_participation: i32,
+    pub flawless_aces: i32,
+    pub full_team_takedown: i32,
+    pub game_length: f64,
+    pub get_takedowns_in_all_lanes_early_jungle_as_laner: i32,
+    pub gold_per_minute: f64,
+    pub had_afk_teammate: i32,
+    pub had_open_nexus: i32,
+    pub highest_champion_damage: i32,
+    pub highest_crowd_control_score: i32,
+    pub highest_ward_kills: i32,
+    pub immobilize_and_kill_with_ally: i32,
+    pub initial_buff_count: i32,
+    pub initial_crab_count: i32,
+    pub jungle_cs_before10_minutes: f64,
+    pub jungler_kills_early_jungle: i32,
+    pub jungler_takedowns_near_damaged_epic_monster: i32,
+    pub k_turrets_destroyed_before_plates_fall: i32,
+    pub kda: f64,
+    pub kill_after_hidden_with_ally: i32,
+    pub kill_participation: f64,
+    pub killed_champ_took_full_team_damage_survived: i32,
+    pub killing_sprees: i32,
+    pub kills_near_enemy_turret: i32,
+    pub kills_on_laners_early_jungle_as_jungler: i32,
+    pub kills_on_other_lanes_early_jungle_as_laner: i32,
+    pub kills_on_recently_healed_by_aram_pack: i32,
+    pub kills_under_own_turret: i32,
+    pub kills_with_help_from_epic_monster: i32,
+    pub knock_enemy_into_team_and_kill: i32,
+    pub land_skill_shots_early_game: i32,
+    pub lane_minions_first10_minutes: i32,
+    pub laning_phase_gold_exp_advantage: f64,
+    pub legendary_count: i32,
+    pub legendary_item_used: Vec<i32>,
+    pub lost_an_inhibitor: i32,
+    pub max_cs_advantage_on_lane_opponent: f64,
+    pub max_kill_deficit: i32,
+    pub max_level_lead_lane_opponent: i32,
+    pub mejais_full_stack_in_time: i32,
+    pub more_enemy_jungle_than_opponent: f64,
+    pub multi_kill_one_spell: i32,
+    pub multi_turret_rift_herald_count: i32,
+    pub multikills: i32,
+    pub multikills_after_aggressive_flash: i32,
+    pub mythic_item_used: Option<i32>,
+    pub outer_turret_executes_before10_minutes: i32,
+    pub outnumbered_kills: i32,
+    pub outnumbered_nexus_kill: i32,
+    pub perfect_dragon_souls_taken: i32,
+    pub perfect_game: i32,
+    pub pick_kill_with_ally: i32,
+    pub played_champ_select_position: Option<i32>,
+    pub poro_explosions: i32,
+    pub quick_cleanse: i32,
+    pub quick_first_turret: i32,
+    pub quick_solo_kills: i32,
+    pub rift_herald_takedowns: i32,
+    pub save_ally_from_death: i32,
+    pub scuttle_crab_kills: i32,
+    pub shortest_time_to_ace_from_first_takedown: Option<f64>,
+    pub skillshots_dodged: i32,
+    pub skillshots_hit: i32,
+    pub snowballs_hit: i32,
+    pub solo_baron_kills: i32,
+    pub solo_kills: i32,
+    pub solo_turrets_lategame: Option<i32>,
+    pub stealth_wards_placed: i32,
+    pub survived_single_digit_hp_count: i32,
+    pub survived_three_immobilizes_in_fight: i32,
+    pub takedown_on_first_turret: i32,
+    pub takedowns: i32,
+    pub takedowns_after_gaining_level_advantage: i32,
+    pub takedowns_before_jungle_minion_spawn: i32,
+    pub takedowns_first_x_minutes: i32,
+    pub takedowns_in_alcove: i32,
+    pub takedowns_in_enemy_fountain: i32,
+    pub team_baron_kills: i32,
+    pub team_damage_percentage: f64,
+    pub team_elder_dragon_kills: i32,
+    pub team_rift_herald_kills: i32,
+    pub teleport_takedowns: i32,
+    pub third_inhibitor_destroyed_time: Option<f64>,
+    pub three_wards_one_sweeper_count: i32,
+    pub took_large_damage_survived: i32,
+    pub turret_plates_taken: i32,
+    pub turret_takedowns: i32,
+    pub turrets_taken_with_rift_herald: i32,
+    pub twenty_minions_in3_seconds_count: i32,
+    pub two_wards_one_sweeper_count: i32,
+    pub unseen_recalls: i32,
+    pub vision_score_advantage_lane_opponent: f64,
+    pub vision_score_per_minute: f64,
+    pub void_monster_kill: i32,
+    pub ward_takedowns: i32,
+    pub ward_takedowns_before20_m: i32,
+    pub wards_guarded: i32,
 }
 
+impl Participant {
+    /// Returns the position of the participant, falling back to its individual position
+    /// when the team position could not be determined.
+    pub fn position(&self) -> Option<TeamPosition> {
+        self.team_position.or(self.individual_position)
+    }
+
+    /// Returns the champion played by the participant, None if it is unknown by this version of samira.
+    pub fn champion(&self) -> Option<ChampionId> {
+        ChampionId::from_key(self.champion_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Objective {
     pub first: bool,
     pub kills: i32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Objectives {
     pub baron: Objective,
     pub champion: Objective,
@@ -148,38 +427,103 @@ pub struct Objectives {
     pub inhibitor: Objective,
     pub rift_herald: Objective,
     pub tower: Objective,
+    /// The voidgrubs, since patch 14.1.
+    pub horde: Objective,
+    /// Since patch 25.1.
+    pub atakhan: Objective,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Ban {
     pub champion_id: i32,
     pub pick_turn: i32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Team {
     pub bans: Vec<Ban>,
     pub objectives: Objectives,
-    pub team_id: i32,
+    pub team_id: TeamId,
     pub win: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Info {
     pub game_creation: i64,
+    /// The duration of the match, in seconds since patch 11.20 but in milliseconds before,
+    /// when `game_end_timestamp` is missing.
     pub game_duration: i64,
+    #[serde(default)]
     pub game_end_timestamp: i64,
     pub game_id: i64,
-    pub game_mode: String,
+    pub game_mode: GameMode,
     pub game_name: String,
     pub game_start_timestamp: i64,
-    pub game_type: String,
-    pub game_version: String,
+    pub game_type: GameType,
+    pub game_version: GameVersion,
     pub map_id: i32,
     pub participants: Vec<Participant>,
     pub platform_id: String,
     pub queue_id: i32,
     pub teams: Vec<Team>,
+    #[serde(default)]
     pub tournament_code: String,
+    /// Why the match ended ("GameComplete", "Abort_Unexpected", ...), since patch 14.3.
+    #[serde(default)]
+    pub end_of_game_result: Option<String>,
+}
+
+impl Info {
+    /// Returns the participants of a team.
+    pub fn team_participants(&self, team_id: TeamId) -> impl Iterator<Item = &Participant> {
+        self.participants
+            .iter()
+            .filter(move |participant| participant.team_id == team_id)
+    }
+
+    /// Returns the team which won the match, None for a remake or an unfinished match.
+    pub fn winning_team(&self) -> Option<TeamId> {
+        self.teams
+            .iter()
+            .find(|team| team.win)
+            .map(|team| team.team_id)
+    }
 }
 
+/// A match of match-v5, the fields added or removed over the patches default to zero or empty when missing.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{game::*, models::match_model::*, team::*};
+///
+/// let game: Match = serde_json::from_str(r#"{
+///     "metadata": {"dataVersion": "2", "matchId": "EUW1_6000000000", "participants": []},
+///     "info": {
+///         "gameCreation": 1700000000000, "gameDuration": 1800, "gameId": 6000000000, "gameMode": "CLASSIC",
+///         "gameName": "", "gameStartTimestamp": 1700000000000, "gameType": "MATCHED_GAME",
+///         "gameVersion": "13.22.541.5047", "mapId": 11, "participants": [], "platformId": "EUW1", "queueId": 420,
+///         "teams": [
+///             {"bans": [{"championId": 157, "pickTurn": 1}], "objectives": {"baron": {"first": true, "kills": 1}}, "teamId": 100, "win": true},
+///             {"bans": [], "objectives": {}, "teamId": 200, "win": false}
+///         ]
+///     }
+/// }"#).unwrap();
+/// assert_eq!(game.info.game_mode, GameMode::CLASSIC);
+/// assert_eq!(game.info.winning_team(), Some(TeamId::BLUE));
+/// assert_eq!(game.info.teams[1].objectives.horde.kills, 0);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Match {
     pub metadata: Metadata,
     pub info: Info,
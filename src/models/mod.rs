@@ -1,5 +1,18 @@
+pub mod account_model;
+pub mod challenge_model;
 pub mod champion_info_model;
+pub mod champion_mastery_model;
 pub mod champion_model;
+pub mod clash_model;
+pub mod item_model;
+pub mod lcu_model;
+pub mod league_model;
+pub mod live_client_model;
 pub mod match_model;
+pub mod replay_model;
 pub mod rune_model;
+pub mod spectator_model;
+pub mod status_model;
 pub mod summoner_model;
+pub mod timeline_model;
+pub mod tournament_model;
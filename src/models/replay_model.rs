@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+
+/// The game process playing the replay, of `/replay/game`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReplayGame {
+    #[serde(rename = "processID")]
+    pub process_id: i64,
+}
+
+/// The playback state of the replay, of `/replay/playback`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::replay_model::*;
+///
+/// let playback: Playback = serde_json::from_str(
+///     r#"{"length": 1834.2, "paused": false, "seeking": false, "speed": 2.0, "time": 605.5}"#,
+/// ).unwrap();
+/// assert_eq!(playback.remaining(), 1228.7);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Playback {
+    /// The duration of the replay, in seconds.
+    pub length: f64,
+    pub paused: bool,
+    pub seeking: bool,
+    /// The playback speed, 1.0 being real time.
+    pub speed: f64,
+    /// The playback position since the start of the game, in seconds.
+    pub time: f64,
+}
+
+impl Playback {
+    /// Returns the time left before the end of the replay, in seconds.
+    pub fn remaining(&self) -> f64 {
+        (self.length - self.time).max(0.0)
+    }
+}
+
+/// The changes to apply to the [`Playback`], the fields left to None are kept as is.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::replay_model::*;
+///
+/// let update = PlaybackUpdate { time: Some(300.0), ..Default::default() };
+/// assert_eq!(serde_json::to_string(&update).unwrap(), r#"{"time":300.0}"#);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlaybackUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seeking: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<f64>,
+}
+
+/// The render settings of the replay, of `/replay/render`.
+/// Every field is optional so the same model reads the settings and changes some of them,
+/// the fields left to None are kept as is.
+/// Only the most used settings are modeled, the others are ignored even with the `strict` feature.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Render {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_mode: Option<CameraMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_attached: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_position: Option<Vector3>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_rotation: Option<Vector3>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_move_speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_look_speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_of_view: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub near_clip: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub far_clip: Option<f64>,
+    /// The name of the unit followed by the camera, e.g. a champion name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_offset: Option<Vector3>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fog_of_war: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banners: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub characters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floating_text: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub particles: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_bar_champions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_bar_minions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_bar_pets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_bar_structures: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_bar_wards: Option<bool>,
+    /// Hides or shows every element of the interface at once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_all: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_announce: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_chat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_frames: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_kill_callouts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_minimap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_neutral_timers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_quests: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_replay: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_score: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_scoreboard: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_target: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_timeline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline_hover: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline_select: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_fog_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_fog_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_of_field_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_fog_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_fog_color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skybox_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sun_direction: Option<Vector3>,
+}
+
+/// How the camera of the replay moves.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CameraMode {
+    /// The default camera, above the map.
+    TOP,
+    /// The free camera, moved with the keyboard and the mouse.
+    FPS,
+    /// The third person camera, following the selected unit.
+    TPS,
+    /// The camera locked on the selected unit.
+    FOCUS,
+    /// The camera following the keyframes of the [`Sequence`].
+    PATH,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// A color whose components range from 0.0 to 1.0.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+/// The keyframes animating the replay, of `/replay/sequence`.
+/// Each property is interpolated between its keyframes while the replay plays, the properties without keyframes are left as is.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::replay_model::*;
+///
+/// let sequence = Sequence {
+///     playback_speed: vec![Keyframe::new(600.0, 1.0), Keyframe::new(610.0, 0.25).blend(Blend::SmoothStep)],
+///     ..Default::default()
+/// };
+/// let json = serde_json::to_value(&sequence).unwrap();
+/// assert_eq!(json["playbackSpeed"][1]["blend"], "smoothStep");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Sequence {
+    pub camera_position: Vec<Keyframe<Vector3>>,
+    pub camera_rotation: Vec<Keyframe<Vector3>>,
+    pub field_of_view: Vec<Keyframe<f64>>,
+    pub near_clipping_plane: Vec<Keyframe<f64>>,
+    pub far_clipping_plane: Vec<Keyframe<f64>>,
+    pub playback_speed: Vec<Keyframe<f64>>,
+    pub selection_name: Vec<Keyframe<String>>,
+    pub depth_fog_enabled: Vec<Keyframe<bool>>,
+    pub depth_fog_color: Vec<Keyframe<Color>>,
+    pub height_fog_enabled: Vec<Keyframe<bool>>,
+    pub height_fog_color: Vec<Keyframe<Color>>,
+    pub sun_direction: Vec<Keyframe<Vector3>>,
+}
+
+/// The value of a property at a time of the replay.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Keyframe<T> {
+    /// The time of the keyframe since the start of the game, in seconds.
+    pub time: f64,
+    pub value: T,
+    /// The interpolation from the previous keyframe to this one.
+    #[serde(default)]
+    pub blend: Blend,
+}
+
+impl<T> Keyframe<T> {
+    /// Creates a keyframe reached with a linear interpolation.
+    pub fn new(time: f64, value: T) -> Keyframe<T> {
+        Keyframe {
+            time,
+            value,
+            blend: Blend::default(),
+        }
+    }
+
+    /// Sets the interpolation from the previous keyframe to this one.
+    pub fn blend(mut self, blend: Blend) -> Keyframe<T> {
+        self.blend = blend;
+        self
+    }
+}
+
+/// The easing functions interpolating between two [`Keyframe`]s.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum Blend {
+    #[default]
+    Linear,
+    /// Jumps to the value of the keyframe without interpolation.
+    Snap,
+    SmoothStep,
+    SmootherStep,
+    QuadraticEaseIn,
+    QuadraticEaseOut,
+    QuadraticEaseInOut,
+    CubicEaseIn,
+    CubicEaseOut,
+    CubicEaseInOut,
+    QuarticEaseIn,
+    QuarticEaseOut,
+    QuarticEaseInOut,
+    QuinticEaseIn,
+    QuinticEaseOut,
+    QuinticEaseInOut,
+    SineEaseIn,
+    SineEaseOut,
+    SineEaseInOut,
+    CircularEaseIn,
+    CircularEaseOut,
+    CircularEaseInOut,
+    ExponentialEaseIn,
+    ExponentialEaseOut,
+    ExponentialEaseInOut,
+    ElasticEaseIn,
+    ElasticEaseOut,
+    ElasticEaseInOut,
+    BackEaseIn,
+    BackEaseOut,
+    BackEaseInOut,
+    BounceEaseIn,
+    BounceEaseOut,
+    BounceEaseInOut,
+}
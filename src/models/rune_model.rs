@@ -1,23 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Serialize, Default, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RuneData {
     pub id: i32,
     pub key: String,
     pub icon: String,
     pub name: String,
-    #[serde(alias = "shortDesc")]
     pub short_desc: String,
-    #[serde(alias = "longDesc")]
     pub long_desc: String,
 }
 
-#[derive(Deserialize, Serialize, Default, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RuneSlot {
     pub runes: Vec<RuneData>,
 }
 
-#[derive(Deserialize, Serialize, Default, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Rune {
     pub id: i32,
     pub key: String,
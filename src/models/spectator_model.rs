@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{champion_id::ChampionId, game::*, ids::*, team::*};
+
+/// A game in progress, of spectator-v5 active games and featured games.
+/// The featured games only list the participants' champions and summoner spells, their other fields are empty.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{champion_id::*, models::spectator_model::*, team::*};
+///
+/// let game: CurrentGameInfo = serde_json::from_str(r#"{
+///     "gameId": 6000000000, "gameType": "MATCHED_GAME", "gameStartTime": 1700000000000, "mapId": 11,
+///     "gameLength": 300, "platformId": "EUW1", "gameMode": "CLASSIC", "gameQueueConfigId": 420,
+///     "bannedChampions": [{"pickTurn": 1, "championId": 157, "teamId": 100}],
+///     "observers": {"encryptionKey": "KEY"},
+///     "participants": [{
+///         "championId": 360, "profileIconId": 1, "bot": false, "teamId": 200, "puuid": "PUUID",
+///         "spell1Id": 4, "spell2Id": 7, "riotId": "RqndomHax#EUW",
+///         "perks": {"perkIds": [8008, 9111], "perkStyle": 8000, "perkSubStyle": 8100},
+///         "gameCustomizationObjects": []
+///     }]
+/// }"#).unwrap();
+/// assert_eq!(game.observers.encryption_key, "KEY");
+/// assert_eq!(game.banned_champions[0].champion(), Some(ChampionId::Yasuo));
+/// assert_eq!(game.team_participants(TeamId::RED).next().unwrap().champion(), Some(ChampionId::Samira));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CurrentGameInfo {
+    pub game_id: i64,
+    pub game_type: GameType,
+    /// The start of the game, in milliseconds since the epoch.
+    pub game_start_time: i64,
+    pub map_id: i64,
+    /// The time elapsed since the start of the game, in seconds.
+    pub game_length: i64,
+    pub platform_id: String,
+    pub game_mode: GameMode,
+    #[serde(default)]
+    pub banned_champions: Vec<BannedChampion>,
+    /// The queue id of the game, missing for custom games.
+    #[serde(default)]
+    pub game_queue_config_id: Option<i64>,
+    pub observers: Observer,
+    pub participants: Vec<CurrentGameParticipant>,
+}
+
+impl CurrentGameInfo {
+    /// Returns the participants of a team.
+    pub fn team_participants(
+        &self,
+        team_id: TeamId,
+    ) -> impl Iterator<Item = &CurrentGameParticipant> {
+        self.participants
+            .iter()
+            .filter(move |participant| participant.team_id == team_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CurrentGameParticipant {
+    pub champion_id: i64,
+    #[serde(default)]
+    pub perks: Perks,
+    #[serde(default)]
+    pub profile_icon_id: i64,
+    pub bot: bool,
+    pub team_id: TeamId,
+    /// The PUUID of the player, None for bots.
+    #[serde(default)]
+    pub puuid: Option<Puuid>,
+    /// Riot stopped sending the summoner ids, None for the latest responses.
+    #[serde(default)]
+    pub summoner_id: Option<SummonerId>,
+    pub spell1_id: i64,
+    pub spell2_id: i64,
+    #[serde(default)]
+    pub game_customization_objects: Vec<GameCustomizationObject>,
+    /// The Riot ID of the player ("Name#TAG").
+    #[serde(default)]
+    pub riot_id: String,
+}
+
+impl CurrentGameParticipant {
+    /// Returns the champion played by the participant, None if it is unknown by this version of samira.
+    pub fn champion(&self) -> Option<ChampionId> {
+        ChampionId::from_key(self.champion_id as i32)
+    }
+}
+
+/// The runes of a [`CurrentGameParticipant`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Perks {
+    /// The runes, then the stat shards.
+    pub perk_ids: Vec<i64>,
+    /// The primary rune path.
+    pub perk_style: i64,
+    /// The secondary rune path.
+    pub perk_sub_style: i64,
+}
+
+/// A customization of a participant, such as its selected skin or its emotes.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameCustomizationObject {
+    pub category: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BannedChampion {
+    pub pick_turn: i32,
+    /// The key of the banned champion, -1 if the team banned none.
+    pub champion_id: i64,
+    pub team_id: TeamId,
+}
+
+impl BannedChampion {
+    /// Returns the banned champion, None if the team banned none or if it is unknown by this version of samira.
+    pub fn champion(&self) -> Option<ChampionId> {
+        ChampionId::from_key(self.champion_id as i32)
+    }
+}
+
+/// The observer data of a game, needed to spectate it.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Observer {
+    /// The key decrypting the spectated game.
+    pub encryption_key: String,
+}
+
+/// The games featured in the client, refreshed every `client_refresh_interval` seconds.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FeaturedGames {
+    pub game_list: Vec<CurrentGameInfo>,
+    pub client_refresh_interval: i64,
+}
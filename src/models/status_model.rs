@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a platform, of lol-status-v4: its ongoing maintenances and incidents.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::status_model::*;
+///
+/// let platform: PlatformData = serde_json::from_str(r#"{
+///     "id": "EUW1", "name": "EU West", "locales": ["en_GB"], "maintenances": [],
+///     "incidents": [{
+///         "id": 1, "maintenance_status": null, "incident_severity": "warning",
+///         "titles": [{"locale": "en_GB", "content": "Ranked queues disabled"}],
+///         "updates": [{
+///             "id": 2, "author": "", "publish": true, "publish_locations": ["riotclient", "riotstatus"],
+///             "translations": [{"locale": "en_GB", "content": "We are investigating."}],
+///             "created_at": "2024-01-01T10:00:00Z", "updated_at": "2024-01-01T10:00:00Z"
+///         }],
+///         "created_at": "2024-01-01T10:00:00Z", "archive_at": null, "updated_at": null, "platforms": ["windows", "macos"]
+///     }]
+/// }"#).unwrap();
+/// let incident = &platform.incidents[0];
+/// assert!(incident.is_incident());
+/// assert_eq!(incident.incident_severity, Some(IncidentSeverity::WARNING));
+/// assert_eq!(incident.title("en_GB"), Some("Ranked queues disabled"));
+/// assert_eq!(incident.updates[0].publish_locations[0], PublishLocation::RIOTCLIENT);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlatformData {
+    pub id: String,
+    pub name: String,
+    pub locales: Vec<String>,
+    pub maintenances: Vec<Status>,
+    pub incidents: Vec<Status>,
+}
+
+impl PlatformData {
+    /// Returns the maintenances, then the incidents of the platform.
+    pub fn statuses(&self) -> impl Iterator<Item = &Status> {
+        self.maintenances.iter().chain(self.incidents.iter())
+    }
+}
+
+/// A maintenance or an incident, told apart by which of `maintenance_status` and `incident_severity` is set.
+/// Unlike the other models, lol-status-v4 writes its fields in snake_case.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Status {
+    pub id: i32,
+    /// The progress of a maintenance, None for an incident.
+    pub maintenance_status: Option<MaintenanceStatus>,
+    /// The severity of an incident, None for a maintenance.
+    pub incident_severity: Option<IncidentSeverity>,
+    pub titles: Vec<Content>,
+    pub updates: Vec<Update>,
+    /// The creation of the status, as an RFC 3339 date.
+    pub created_at: String,
+    pub archive_at: Option<String>,
+    pub updated_at: Option<String>,
+    /// The platforms affected ("windows", "macos", "android", "ios", "ps4", "xbone", "switch").
+    pub platforms: Vec<String>,
+}
+
+impl Status {
+    /// Returns true for a maintenance.
+    pub fn is_maintenance(&self) -> bool {
+        self.maintenance_status.is_some()
+    }
+
+    /// Returns true for an incident.
+    pub fn is_incident(&self) -> bool {
+        !self.is_maintenance()
+    }
+
+    /// Returns the title of the status in a locale, None if it is not translated in this locale.
+    pub fn title(&self, locale: &str) -> Option<&str> {
+        translation(&self.titles, locale)
+    }
+}
+
+/// A message of a [`Status`], posted while it is being handled.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Update {
+    pub id: i32,
+    pub author: String,
+    pub publish: bool,
+    pub publish_locations: Vec<PublishLocation>,
+    pub translations: Vec<Content>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Update {
+    /// Returns the message in a locale, None if it is not translated in this locale.
+    pub fn translation(&self, locale: &str) -> Option<&str> {
+        translation(&self.translations, locale)
+    }
+}
+
+/// A text translated in a locale.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Content {
+    pub locale: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+#[allow(non_camel_case_types)]
+pub enum MaintenanceStatus {
+    SCHEDULED,
+    IN_PROGRESS,
+    COMPLETE,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum IncidentSeverity {
+    INFO,
+    WARNING,
+    CRITICAL,
+}
+
+/// Where an [`Update`] is displayed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum PublishLocation {
+    RIOTCLIENT,
+    RIOTSTATUS,
+    GAME,
+}
+
+fn translation<'a>(contents: &'a [Content], locale: &str) -> Option<&'a str> {
+    contents
+        .iter()
+        .find(|content| content.locale == locale)
+        .map(|content| content.content.as_str())
+}
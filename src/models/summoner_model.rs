@@ -1,16 +1,39 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+use crate::ids::*;
+
+/// A summoner of summoner-v4, serialized back with the same camelCase fields as the Riot API.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::summoner_model::*;
+///
+/// let json = r#"{"accountId":"A","profileIconId":1,"revisionDate":2,"name":"RqndomHax","id":"S","puuid":"P","summonerLevel":30}"#;
+/// let summoner: Summoner = serde_json::from_str(json).unwrap();
+/// assert_eq!(serde_json::to_string(&summoner).unwrap(), json);
+/// assert_eq!(summoner.to_string(), "RqndomHax (level 30)");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Summoner {
-    #[serde(alias = "accountId")]
-    pub account_id: String,
-    #[serde(alias = "profileIconId")]
+    pub account_id: AccountId,
     pub profile_icon_id: i32,
-    #[serde(alias = "revisionDate")]
     pub revision_date: i64,
     pub name: String,
-    pub id: String,
-    pub puuid: String,
-    #[serde(alias = "summonerLevel")]
+    pub id: SummonerId,
+    pub puuid: Puuid,
     pub summoner_level: i64,
 }
+
+impl fmt::Display for Summoner {
+    /// Writes the name and the level of the summoner ("RqndomHax (level 30)").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (level {})", self.name, self.summoner_level)
+    }
+}
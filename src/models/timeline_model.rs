@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{models::match_model::Metadata, team::*};
+
+/// The timeline of a match of match-v5, a frame for every minute of the match.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Timeline {
+    pub metadata: Metadata,
+    pub info: TimelineInfo,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TimelineInfo {
+    pub end_of_game_result: Option<String>,
+    /// The duration between two frames, in milliseconds.
+    pub frame_interval: i64,
+    pub frames: Vec<Frame>,
+    pub game_id: i64,
+    pub participants: Vec<TimelineParticipant>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TimelineParticipant {
+    pub participant_id: i32,
+    pub puuid: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Frame {
+    pub events: Vec<Event>,
+    /// The state of each participant, keyed by its participant id ("1" to "10").
+    pub participant_frames: HashMap<String, ParticipantFrame>,
+    /// The time of the frame since the start of the match, in milliseconds.
+    pub timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ParticipantFrame {
+    pub champion_stats: ChampionStats,
+    pub current_gold: i32,
+    pub damage_stats: DamageStats,
+    pub gold_per_second: i32,
+    pub jungle_minions_killed: i32,
+    pub level: i32,
+    pub minions_killed: i32,
+    pub participant_id: i32,
+    pub position: Position,
+    pub time_enemy_spent_controlled: i32,
+    pub total_gold: i32,
+    pub xp: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampionStats {
+    pub ability_haste: i32,
+    pub ability_power: i32,
+    pub armor: i32,
+    pub armor_pen: i32,
+    pub armor_pen_percent: i32,
+    pub attack_damage: i32,
+    pub attack_speed: i32,
+    pub bonus_armor_pen_percent: i32,
+    pub bonus_magic_pen_percent: i32,
+    pub cc_reduction: i32,
+    pub cooldown_reduction: i32,
+    pub health: i32,
+    pub health_max: i32,
+    pub health_regen: i32,
+    pub lifesteal: i32,
+    pub magic_pen: i32,
+    pub magic_pen_percent: i32,
+    pub magic_resist: i32,
+    pub movement_speed: i32,
+    pub omnivamp: i32,
+    pub physical_vamp: i32,
+    pub power: i32,
+    pub power_max: i32,
+    pub power_regen: i32,
+    pub spell_vamp: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DamageStats {
+    pub magic_damage_done: i32,
+    pub magic_damage_done_to_champions: i32,
+    pub magic_damage_taken: i32,
+    pub physical_damage_done: i32,
+    pub physical_damage_done_to_champions: i32,
+    pub physical_damage_taken: i32,
+    pub total_damage_done: i32,
+    pub total_damage_done_to_champions: i32,
+    pub total_damage_taken: i32,
+    pub true_damage_done: i32,
+    pub true_damage_done_to_champions: i32,
+    pub true_damage_taken: i32,
+}
+
+/// A position on the map, from (0, 0) at the bottom left to about (15000, 15000) at the top right.
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// An event of a [`Frame`], tagged by its `type`.
+/// The events unknown by this version of samira, or which could not be read, are kept as [`Event::Other`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{models::timeline_model::*, team::*};
+///
+/// let events: Vec<Event> = serde_json::from_str(r#"[
+///     {"type": "ITEM_PURCHASED", "timestamp": 1000, "participantId": 1, "itemId": 1055},
+///     {"type": "ELITE_MONSTER_KILL", "timestamp": 600000, "killerId": 7, "killerTeamId": 200,
+///         "monsterType": "DRAGON", "monsterSubType": "FIRE_DRAGON", "position": {"x": 9866, "y": 4414}},
+///     {"type": "NEW_EVENT", "timestamp": 700000}
+/// ]"#).unwrap();
+/// assert!(matches!(&events[0], Event::ItemPurchased(item) if item.item_id == 1055));
+/// match &events[1] {
+///     Event::EliteMonsterKill(kill) => assert_eq!(kill.killer_team_id, TeamId::RED),
+///     event => panic!("unexpected event {:?}", event),
+/// }
+/// assert!(matches!(events[2], Event::Other(_)));
+/// assert_eq!(events[2].timestamp(), 700000);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Event {
+    ChampionKill(ChampionKillEvent),
+    ChampionSpecialKill(ChampionSpecialKillEvent),
+    BuildingKill(BuildingKillEvent),
+    TurretPlateDestroyed(TurretPlateDestroyedEvent),
+    EliteMonsterKill(EliteMonsterKillEvent),
+    DragonSoulGiven(DragonSoulGivenEvent),
+    ItemPurchased(ItemEvent),
+    ItemSold(ItemEvent),
+    ItemDestroyed(ItemEvent),
+    ItemUndo(ItemUndoEvent),
+    WardPlaced(WardPlacedEvent),
+    WardKill(WardKillEvent),
+    LevelUp(LevelUpEvent),
+    SkillLevelUp(SkillLevelUpEvent),
+    PauseEnd(PauseEndEvent),
+    GameEnd(GameEndEvent),
+    /// An event unknown by this version of samira, as sent by the Riot API.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl Event {
+    /// Returns the time of the event since the start of the match, in milliseconds.
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            Event::ChampionKill(event) => event.timestamp,
+            Event::ChampionSpecialKill(event) => event.timestamp,
+            Event::BuildingKill(event) => event.timestamp,
+            Event::TurretPlateDestroyed(event) => event.timestamp,
+            Event::EliteMonsterKill(event) => event.timestamp,
+            Event::DragonSoulGiven(event) => event.timestamp,
+            Event::ItemPurchased(event) | Event::ItemSold(event) | Event::ItemDestroyed(event) => {
+                event.timestamp
+            }
+            Event::ItemUndo(event) => event.timestamp,
+            Event::WardPlaced(event) => event.timestamp,
+            Event::WardKill(event) => event.timestamp,
+            Event::LevelUp(event) => event.timestamp,
+            Event::SkillLevelUp(event) => event.timestamp,
+            Event::PauseEnd(event) => event.timestamp,
+            Event::GameEnd(event) => event.timestamp,
+            Event::Other(event) => event["timestamp"].as_i64().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampionKillEvent {
+    pub timestamp: i64,
+    /// The participant id of the killer, 0 for an execution by a minion, a turret or a monster.
+    pub killer_id: i32,
+    pub victim_id: i32,
+    pub assisting_participant_ids: Vec<i32>,
+    pub bounty: i32,
+    pub shutdown_bounty: i32,
+    pub kill_streak_length: i32,
+    pub position: Position,
+    pub victim_damage_dealt: Vec<DamageEvent>,
+    pub victim_damage_received: Vec<DamageEvent>,
+}
+
+/// A source of damage dealt or received by the victim of a [`ChampionKillEvent`].
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DamageEvent {
+    pub basic: bool,
+    pub magic_damage: i32,
+    pub physical_damage: i32,
+    pub true_damage: i32,
+    /// The name of the champion, or of the minion, turret or monster ("SRU_Baron", ...).
+    pub name: String,
+    pub participant_id: i32,
+    pub spell_name: String,
+    pub spell_slot: i32,
+    /// "OTHER", "MINION", "MONSTER", "TOWER" or "CHAMPION".
+    #[serde(rename = "type")]
+    pub damage_type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChampionSpecialKillEvent {
+    pub timestamp: i64,
+    pub killer_id: i32,
+    /// "KILL_FIRST_BLOOD", "KILL_MULTI" or "KILL_ACE".
+    pub kill_type: String,
+    pub multi_kill_length: Option<i32>,
+    pub position: Position,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BuildingKillEvent {
+    pub timestamp: i64,
+    pub killer_id: i32,
+    #[serde(default)]
+    pub assisting_participant_ids: Vec<i32>,
+    #[serde(default)]
+    pub bounty: i32,
+    /// "TOWER_BUILDING" or "INHIBITOR_BUILDING".
+    pub building_type: String,
+    /// "TOP_LANE", "MID_LANE" or "BOT_LANE".
+    pub lane_type: String,
+    /// "OUTER_TURRET", "INNER_TURRET", "BASE_TURRET" or "NEXUS_TURRET", for the towers.
+    #[serde(default)]
+    pub tower_type: Option<String>,
+    pub position: Position,
+    /// The team which lost the building.
+    pub team_id: TeamId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TurretPlateDestroyedEvent {
+    pub timestamp: i64,
+    pub killer_id: i32,
+    pub lane_type: String,
+    pub position: Position,
+    /// The team which lost the plate.
+    pub team_id: TeamId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EliteMonsterKillEvent {
+    pub timestamp: i64,
+    pub killer_id: i32,
+    pub killer_team_id: TeamId,
+    #[serde(default)]
+    pub assisting_participant_ids: Vec<i32>,
+    #[serde(default)]
+    pub bounty: i32,
+    /// "DRAGON", "BARON_NASHOR", "RIFTHERALD", "HORDE" or "ATAKHAN".
+    pub monster_type: String,
+    /// The kind of dragon ("FIRE_DRAGON", "ELDER_DRAGON", ...).
+    #[serde(default)]
+    pub monster_sub_type: Option<String>,
+    pub position: Position,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DragonSoulGivenEvent {
+    pub timestamp: i64,
+    /// The kind of soul ("Infernal", "Ocean", ...).
+    pub name: String,
+    pub team_id: TeamId,
+}
+
+/// An item purchased, sold or destroyed (consumed, or combined into another item).
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ItemEvent {
+    pub timestamp: i64,
+    pub participant_id: i32,
+    pub item_id: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ItemUndoEvent {
+    pub timestamp: i64,
+    pub participant_id: i32,
+    pub before_id: i32,
+    pub after_id: i32,
+    pub gold_gain: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WardPlacedEvent {
+    pub timestamp: i64,
+    pub creator_id: i32,
+    /// "YELLOW_TRINKET", "CONTROL_WARD", "SIGHT_WARD", "BLUE_TRINKET", "TEEMO_MUSHROOM" or "UNDEFINED".
+    pub ward_type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WardKillEvent {
+    pub timestamp: i64,
+    pub killer_id: i32,
+    pub ward_type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LevelUpEvent {
+    pub timestamp: i64,
+    pub participant_id: i32,
+    pub level: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SkillLevelUpEvent {
+    pub timestamp: i64,
+    pub participant_id: i32,
+    /// The spell leveled up, from 1 (Q) to 4 (R).
+    pub skill_slot: i32,
+    /// "NORMAL" or "EVOLVE".
+    pub level_up_type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PauseEndEvent {
+    pub timestamp: i64,
+    /// The time of the event since the epoch, in milliseconds.
+    pub real_timestamp: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameEndEvent {
+    pub timestamp: i64,
+    pub game_id: i64,
+    pub real_timestamp: i64,
+    pub winning_team: TeamId,
+}
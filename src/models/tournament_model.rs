@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SamiraError, ids::Puuid};
+
+/// The settings of the lobbies created by tournament codes, of tournament-v5.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::models::tournament_model::*;
+///
+/// let parameters = TournamentCodeParameters::new(5).pick_type(PickType::BLIND_PICK).metadata("round 1");
+/// assert_eq!(
+///     serde_json::to_string(&parameters).unwrap(),
+///     r#"{"metadata":"round 1","teamSize":5,"pickType":"BLIND_PICK","mapType":"SUMMONERS_RIFT","spectatorType":"ALL","enoughPlayers":true}"#
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeParameters {
+    /// The players allowed to join the lobby, anyone can join if None.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_participants: Option<Vec<Puuid>>,
+    /// Sent back in the game results callback, e.g. to identify the match of a bracket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// The number of players of each team, from 1 to 5.
+    pub team_size: i32,
+    pub pick_type: PickType,
+    pub map_type: MapType,
+    pub spectator_type: SpectatorType,
+    /// Whether the game can start without every allowed participant.
+    pub enough_players: bool,
+}
+
+impl TournamentCodeParameters {
+    /// Creates the parameters of tournament draft games on Summoner's Rift, open to anyone and to spectators.
+    pub fn new(team_size: i32) -> TournamentCodeParameters {
+        TournamentCodeParameters {
+            allowed_participants: None,
+            metadata: None,
+            team_size,
+            pick_type: PickType::TOURNAMENT_DRAFT,
+            map_type: MapType::SUMMONERS_RIFT,
+            spectator_type: SpectatorType::ALL,
+            enough_players: true,
+        }
+    }
+
+    /// Only lets the given players join the lobby.
+    pub fn allowed_participants(
+        mut self,
+        participants: impl IntoIterator<Item = Puuid>,
+    ) -> TournamentCodeParameters {
+        self.allowed_participants = Some(participants.into_iter().collect());
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &str) -> TournamentCodeParameters {
+        self.metadata = Some(metadata.to_string());
+        self
+    }
+
+    pub fn pick_type(mut self, pick_type: PickType) -> TournamentCodeParameters {
+        self.pick_type = pick_type;
+        self
+    }
+
+    pub fn map_type(mut self, map_type: MapType) -> TournamentCodeParameters {
+        self.map_type = map_type;
+        self
+    }
+
+    pub fn spectator_type(mut self, spectator_type: SpectatorType) -> TournamentCodeParameters {
+        self.spectator_type = spectator_type;
+        self
+    }
+
+    pub fn enough_players(mut self, enough_players: bool) -> TournamentCodeParameters {
+        self.enough_players = enough_players;
+        self
+    }
+}
+
+/// How the champions of a tournament lobby are picked.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PickType {
+    BLIND_PICK,
+    DRAFT_MODE,
+    ALL_RANDOM,
+    TOURNAMENT_DRAFT,
+}
+
+/// The map of a tournament lobby.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MapType {
+    SUMMONERS_RIFT,
+    HOWLING_ABYSS,
+}
+
+/// Who can spectate the games of a tournament lobby.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpectatorType {
+    NONE,
+    LOBBYONLY,
+    ALL,
+}
+
+/// The outcome of [`RiotApi::create_tournament_codes`](crate::riot_api::RiotApi::create_tournament_codes).
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TournamentCodesReport {
+    /// The codes created, in the order they were returned.
+    pub codes: Vec<String>,
+    /// The batches which failed, with the number of codes they requested,
+    /// or the number of codes left if the failure stopped the creation.
+    pub failed: Vec<(usize, SamiraError)>,
+}
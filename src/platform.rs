@@ -1,5 +1,10 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::region::Region;
+
 const PROTOCOL: &str = "https";
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Platform {
     BR1,
     EUN1,
@@ -12,24 +17,176 @@ pub enum Platform {
     OC1,
     TR1,
     RU,
+    PH2,
+    SG2,
+    TH2,
+    TW2,
+    VN2,
+    ME1,
+    /// A platform unknown by samira, e.g. a new shard or a test environment,
+    /// named by its id which is used in its host and to track its rate limits.
+    Custom(String),
+}
+
+impl Platform {
+    /// Returns every known platform, e.g. to query the status of each of them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::platform::*;
+    ///
+    /// assert_eq!(Platform::all().any(|platform| platform == Platform::EUW1), true);
+    /// ```
+    pub fn all() -> impl Iterator<Item = Platform> {
+        [
+            Platform::BR1,
+            Platform::EUN1,
+            Platform::EUW1,
+            Platform::JP1,
+            Platform::KR,
+            Platform::LA1,
+            Platform::LA2,
+            Platform::NA1,
+            Platform::OC1,
+            Platform::TR1,
+            Platform::RU,
+            Platform::PH2,
+            Platform::SG2,
+            Platform::TH2,
+            Platform::TW2,
+            Platform::VN2,
+            Platform::ME1,
+        ]
+        .into_iter()
+    }
+
+    /// Returns the region serving the regional endpoints (match-v5, account-v1, ...) of the platform.
+    /// A custom platform is routed to a custom region of the same id.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, region::*};
+    ///
+    /// assert_eq!(Platform::EUW1.regional_route(), Region::EUROPE);
+    /// assert_eq!(Platform::NA1.regional_route(), Region::AMERICAS);
+    /// assert_eq!(Platform::OC1.regional_route(), Region::SEA);
+    /// assert_eq!(Platform::ME1.regional_route(), Region::EUROPE);
+    /// ```
+    pub fn regional_route(&self) -> Region {
+        match self {
+            Platform::BR1 | Platform::LA1 | Platform::LA2 | Platform::NA1 => Region::AMERICAS,
+            Platform::EUN1 | Platform::EUW1 | Platform::TR1 | Platform::RU | Platform::ME1 => {
+                Region::EUROPE
+            }
+            Platform::JP1 | Platform::KR => Region::ASIA,
+            Platform::OC1
+            | Platform::PH2
+            | Platform::SG2
+            | Platform::TH2
+            | Platform::TW2
+            | Platform::VN2 => Region::SEA,
+            // Test environments usually serve both kinds of endpoints.
+            Platform::Custom(id) => Region::Custom(id.clone()),
+        }
+    }
 }
 
 pub fn get_platform_url(platform: &Platform) -> String {
     format!(
         "{protocol}://{platform}.api.riotgames.com",
         protocol = PROTOCOL,
-        platform = match platform {
-            Platform::BR1 => "br1",
-            Platform::EUN1 => "eun1",
-            Platform::EUW1 => "euw1",
-            Platform::JP1 => "jp1",
-            Platform::KR => "kr",
-            Platform::LA1 => "la1",
-            Platform::LA2 => "la2",
-            Platform::NA1 => "na1",
-            Platform::OC1 => "oc1",
-            Platform::TR1 => "tr1",
-            Platform::RU => "ru",
-        }
+        platform = get_platform_id(platform)
     )
 }
+
+/// Returns the platform as written in the Riot API hosts ("euw1", "na1", ...).
+pub(crate) fn get_platform_id(platform: &Platform) -> &str {
+    match platform {
+        Platform::BR1 => "br1",
+        Platform::EUN1 => "eun1",
+        Platform::EUW1 => "euw1",
+        Platform::JP1 => "jp1",
+        Platform::KR => "kr",
+        Platform::LA1 => "la1",
+        Platform::LA2 => "la2",
+        Platform::NA1 => "na1",
+        Platform::OC1 => "oc1",
+        Platform::TR1 => "tr1",
+        Platform::RU => "ru",
+        Platform::PH2 => "ph2",
+        Platform::SG2 => "sg2",
+        Platform::TH2 => "th2",
+        Platform::TW2 => "tw2",
+        Platform::VN2 => "vn2",
+        Platform::ME1 => "me1",
+        Platform::Custom(id) => id,
+    }
+}
+
+/// The error returned when a string is not a known platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePlatformError(String);
+
+impl fmt::Display for ParsePlatformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown platform: {}", self.0)
+    }
+}
+
+impl Error for ParsePlatformError {}
+
+impl FromStr for Platform {
+    type Err = ParsePlatformError;
+
+    /// Parses a platform from its id ("euw1") or its usual name ("EUW", "LAN", "OCE", ...), whatever its case.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::platform::*;
+    ///
+    /// assert_eq!("euw1".parse(), Ok(Platform::EUW1));
+    /// assert_eq!("EUW".parse(), Ok(Platform::EUW1));
+    /// assert_eq!("las".parse(), Ok(Platform::LA2));
+    /// assert_eq!("moon".parse::<Platform>().is_err(), true);
+    /// assert_eq!(Platform::EUN1.to_string(), "eun1");
+    /// assert_eq!(Platform::Custom("pbe1".to_string()).to_string(), "pbe1");
+    /// ```
+    fn from_str(platform: &str) -> Result<Platform, ParsePlatformError> {
+        match platform.trim().to_lowercase().as_str() {
+            "br1" | "br" => Ok(Platform::BR1),
+            "eun1" | "eun" | "eune" => Ok(Platform::EUN1),
+            "euw1" | "euw" => Ok(Platform::EUW1),
+            "jp1" | "jp" => Ok(Platform::JP1),
+            "kr" => Ok(Platform::KR),
+            "la1" | "lan" => Ok(Platform::LA1),
+            "la2" | "las" => Ok(Platform::LA2),
+            "na1" | "na" => Ok(Platform::NA1),
+            "oc1" | "oce" => Ok(Platform::OC1),
+            "tr1" | "tr" => Ok(Platform::TR1),
+            "ru" => Ok(Platform::RU),
+            "ph2" | "ph" => Ok(Platform::PH2),
+            "sg2" | "sg" => Ok(Platform::SG2),
+            "th2" | "th" => Ok(Platform::TH2),
+            "tw2" | "tw" => Ok(Platform::TW2),
+            "vn2" | "vn" => Ok(Platform::VN2),
+            "me1" | "me" => Ok(Platform::ME1),
+            _ => Err(ParsePlatformError(platform.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    /// Writes the platform id ("euw1", "na1", ...).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(get_platform_id(self))
+    }
+}
@@ -0,0 +1,160 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// The position played by a participant, as written by match-v5 `teamPosition` and `individualPosition`.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TeamPosition {
+    TOP,
+    JUNGLE,
+    MIDDLE,
+    BOTTOM,
+    UTILITY,
+}
+
+impl TeamPosition {
+    /// Every position, in the order of the draft.
+    pub const ALL: [TeamPosition; 5] = [
+        TeamPosition::TOP,
+        TeamPosition::JUNGLE,
+        TeamPosition::MIDDLE,
+        TeamPosition::BOTTOM,
+        TeamPosition::UTILITY,
+    ];
+
+    /// Returns the position as written by the Riot API ("TOP", "UTILITY", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamPosition::TOP => "TOP",
+            TeamPosition::JUNGLE => "JUNGLE",
+            TeamPosition::MIDDLE => "MIDDLE",
+            TeamPosition::BOTTOM => "BOTTOM",
+            TeamPosition::UTILITY => "UTILITY",
+        }
+    }
+
+    /// Parses a position whatever its case and its common aliases ("MID", "BOT", "ADC", "SUPPORT", ...).
+    /// Returns None for the empty and "Invalid" positions of the modes without lanes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::position::*;
+    ///
+    /// assert_eq!(TeamPosition::parse("UTILITY"), Some(TeamPosition::UTILITY));
+    /// assert_eq!(TeamPosition::parse("support"), Some(TeamPosition::UTILITY));
+    /// assert_eq!(TeamPosition::parse("Mid"), Some(TeamPosition::MIDDLE));
+    /// assert_eq!(TeamPosition::parse("Invalid"), None);
+    /// assert_eq!(TeamPosition::parse(""), None);
+    /// ```
+    pub fn parse(position: &str) -> Option<TeamPosition> {
+        match position.trim().to_uppercase().as_str() {
+            "TOP" => Some(TeamPosition::TOP),
+            "JUNGLE" | "JUNGLER" | "JG" => Some(TeamPosition::JUNGLE),
+            "MIDDLE" | "MID" => Some(TeamPosition::MIDDLE),
+            "BOTTOM" | "BOT" | "ADC" | "CARRY" => Some(TeamPosition::BOTTOM),
+            "UTILITY" | "SUPPORT" | "SUP" => Some(TeamPosition::UTILITY),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamPosition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TeamPosition, D::Error> {
+        let position = String::deserialize(deserializer)?;
+        TeamPosition::parse(&position).ok_or_else(|| {
+            de::Error::unknown_variant(&position, &["TOP", "JUNGLE", "MIDDLE", "BOTTOM", "UTILITY"])
+        })
+    }
+}
+
+/// The lane a participant was detected in by match-v5 `lane`, less reliable than [`TeamPosition`].
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lane {
+    TOP,
+    JUNGLE,
+    MIDDLE,
+    BOTTOM,
+    /// The lane could not be detected.
+    NONE,
+}
+
+impl Lane {
+    /// Returns the lane as written by the Riot API ("TOP", "NONE", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Lane::TOP => "TOP",
+            Lane::JUNGLE => "JUNGLE",
+            Lane::MIDDLE => "MIDDLE",
+            Lane::BOTTOM => "BOTTOM",
+            Lane::NONE => "NONE",
+        }
+    }
+
+    /// Parses a lane whatever its case and its older names ("MID", "BOT", ...), unknown lanes are [`Lane::NONE`].
+    pub fn parse(lane: &str) -> Lane {
+        match lane.trim().to_uppercase().as_str() {
+            "TOP" => Lane::TOP,
+            "JUNGLE" => Lane::JUNGLE,
+            "MIDDLE" | "MID" => Lane::MIDDLE,
+            "BOTTOM" | "BOT" => Lane::BOTTOM,
+            _ => Lane::NONE,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Lane {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Lane, D::Error> {
+        Ok(Lane::parse(&String::deserialize(deserializer)?))
+    }
+}
+
+/// The role a participant was detected with by match-v5 `role`, combined with its [`Lane`].
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    SOLO,
+    DUO,
+    CARRY,
+    SUPPORT,
+    /// The role could not be detected.
+    NONE,
+}
+
+impl Role {
+    /// Returns the role as written by the Riot API ("SOLO", "NONE", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::SOLO => "SOLO",
+            Role::DUO => "DUO",
+            Role::CARRY => "CARRY",
+            Role::SUPPORT => "SUPPORT",
+            Role::NONE => "NONE",
+        }
+    }
+
+    /// Parses a role whatever its case and its older names ("DUO_CARRY", "DUO_SUPPORT"),
+    /// unknown roles are [`Role::NONE`].
+    pub fn parse(role: &str) -> Role {
+        match role.trim().to_uppercase().as_str() {
+            "SOLO" => Role::SOLO,
+            "DUO" => Role::DUO,
+            "CARRY" | "DUO_CARRY" => Role::CARRY,
+            "SUPPORT" | "DUO_SUPPORT" => Role::SUPPORT,
+            _ => Role::NONE,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Role, D::Error> {
+        Ok(Role::parse(&String::deserialize(deserializer)?))
+    }
+}
+
+/// Deserializes the match-v5 positions, which are empty when they could not be determined (remakes, ARAM, ...).
+pub(crate) fn deserialize_position<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<TeamPosition>, D::Error> {
+    let position = Option::<String>::deserialize(deserializer)?;
+    Ok(position.as_deref().and_then(TeamPosition::parse))
+}
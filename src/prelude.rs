@@ -0,0 +1,44 @@
+//! Re-exports the types most programs need, so a single import is enough.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! use samira::prelude::*;
+//!
+//! let api = RiotApi::new_unchecked("TOKEN_HERE");
+//! let filter = SummonerFilter::by_riot_id("RqndomHax", "EUW").puuid_first();
+//! assert_eq!(Platform::EUW1.regional_route(), Region::EUROPE);
+//! ```
+
+pub use crate::{
+    champion_id::ChampionId,
+    error::SamiraError,
+    filters::{match_ids_filter::MatchIdsFilter, summoner_filter::*},
+    game::{GameMode, GameType, MatchType},
+    ids::*,
+    live_client::LiveClient,
+    models::{
+        account_model::Account,
+        champion_info_model::ChampionInfo,
+        champion_mastery_model::ChampionMastery,
+        champion_model::Champion,
+        item_model::Item,
+        league_model::{LeagueEntry, LeagueItem, LeagueList, MiniSeries},
+        match_model::{Match, Participant, Team},
+        rune_model::{Rune, RuneData},
+        spectator_model::{CurrentGameInfo, FeaturedGames},
+        summoner_model::Summoner,
+        timeline_model::{Event, Timeline},
+    },
+    platform::Platform,
+    position::{Lane, Role, TeamPosition},
+    queue::Queue,
+    rank::{Division, Rank, Tier},
+    region::Region,
+    riot_api::RiotApi,
+    team::TeamId,
+    utils_api::UtilsApi,
+    version::GameVersion,
+};
@@ -0,0 +1,153 @@
+//! Builds the links to the profile of a player on the usual stats websites, for bots and dashboards to link players.
+
+use crate::{
+    models::account_model::Account,
+    platform::{get_platform_id, Platform},
+    request::encode_component,
+};
+
+/// A website showing the profile of players.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileSite {
+    /// op.gg
+    OpGg,
+    /// u.gg
+    UGg,
+    /// porofessor.gg, linking to the live game of the player.
+    Porofessor,
+    /// leagueofgraphs.com
+    LeagueOfGraphs,
+}
+
+impl ProfileSite {
+    /// Every profile website.
+    pub const ALL: [ProfileSite; 4] = [
+        ProfileSite::OpGg,
+        ProfileSite::UGg,
+        ProfileSite::Porofessor,
+        ProfileSite::LeagueOfGraphs,
+    ];
+
+    /// Returns the name of the website ("op.gg", "u.gg", ...).
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProfileSite::OpGg => "op.gg",
+            ProfileSite::UGg => "u.gg",
+            ProfileSite::Porofessor => "porofessor.gg",
+            ProfileSite::LeagueOfGraphs => "leagueofgraphs.com",
+        }
+    }
+
+    /// Returns the profile URL of a player from the parts of its Riot ID, both being URL-encoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, profile_url::*};
+    ///
+    /// assert_eq!(
+    ///     ProfileSite::OpGg.url(&Platform::EUW1, "Rqndom Hax", "EUW"),
+    ///     "https://op.gg/lol/summoners/euw/Rqndom%20Hax-EUW"
+    /// );
+    /// assert_eq!(
+    ///     ProfileSite::UGg.url(&Platform::EUW1, "RqndomHax", "EUW"),
+    ///     "https://u.gg/lol/profile/euw1/RqndomHax-EUW/overview"
+    /// );
+    /// assert_eq!(
+    ///     ProfileSite::Porofessor.url(&Platform::LA2, "Ñandú", "LAS"),
+    ///     "https://porofessor.gg/live/las/%C3%91and%C3%BA-LAS"
+    /// );
+    /// assert_eq!(
+    ///     ProfileSite::LeagueOfGraphs.url(&Platform::KR, "Hide on bush", "KR1"),
+    ///     "https://www.leagueofgraphs.com/summoner/kr/Hide%20on%20bush-KR1"
+    /// );
+    /// ```
+    pub fn url(&self, platform: &Platform, game_name: &str, tag_line: &str) -> String {
+        let player = format!(
+            "{}-{}",
+            encode_component(game_name),
+            encode_component(tag_line)
+        );
+        match self {
+            ProfileSite::OpGg => format!(
+                "https://op.gg/lol/summoners/{}/{}",
+                site_region(platform),
+                player
+            ),
+            ProfileSite::UGg => format!(
+                "https://u.gg/lol/profile/{}/{}/overview",
+                get_platform_id(platform),
+                player
+            ),
+            ProfileSite::Porofessor => format!(
+                "https://porofessor.gg/live/{}/{}",
+                site_region(platform),
+                player
+            ),
+            ProfileSite::LeagueOfGraphs => format!(
+                "https://www.leagueofgraphs.com/summoner/{}/{}",
+                site_region(platform),
+                player
+            ),
+        }
+    }
+
+    /// Returns the profile URL of a player from its Riot ID ("Name#TAG"), None if it has no tag line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, profile_url::*};
+    ///
+    /// assert_eq!(
+    ///     ProfileSite::OpGg.riot_id_url(&Platform::NA1, "Doublelift#NA1"),
+    ///     Some("https://op.gg/lol/summoners/na/Doublelift-NA1".to_string())
+    /// );
+    /// assert_eq!(ProfileSite::OpGg.riot_id_url(&Platform::NA1, "Doublelift"), None);
+    /// ```
+    pub fn riot_id_url(&self, platform: &Platform, riot_id: &str) -> Option<String> {
+        let (game_name, tag_line) = riot_id.rsplit_once('#')?;
+        match game_name.is_empty() || tag_line.is_empty() {
+            true => None,
+            false => Some(self.url(platform, game_name, tag_line)),
+        }
+    }
+
+    /// Returns the profile URL of an account, None if the account has no Riot ID.
+    pub fn account_url(&self, platform: &Platform, account: &Account) -> Option<String> {
+        Some(self.url(
+            platform,
+            account.game_name.as_ref()?,
+            account.tag_line.as_ref()?,
+        ))
+    }
+}
+
+/// Returns the region of a platform as written in the URLs of the websites ("euw", "eune", "lan", ...).
+/// A custom platform keeps its id.
+fn site_region(platform: &Platform) -> &str {
+    match platform {
+        Platform::BR1 => "br",
+        Platform::EUN1 => "eune",
+        Platform::EUW1 => "euw",
+        Platform::JP1 => "jp",
+        Platform::KR => "kr",
+        Platform::LA1 => "lan",
+        Platform::LA2 => "las",
+        Platform::NA1 => "na",
+        Platform::OC1 => "oce",
+        Platform::TR1 => "tr",
+        Platform::RU => "ru",
+        Platform::PH2 => "ph",
+        Platform::SG2 => "sg",
+        Platform::TH2 => "th",
+        Platform::TW2 => "tw",
+        Platform::VN2 => "vn",
+        Platform::ME1 => "me",
+        Platform::Custom(id) => id,
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+/// A League of Legends queue, written as its league-v4 queue type ("RANKED_SOLO_5x5")
+/// or as its match-v5 queue id (420).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum Queue {
+    CUSTOM,
+    NORMAL_DRAFT,
+    RANKED_SOLO_5x5,
+    NORMAL_BLIND,
+    RANKED_FLEX_SR,
+    ARAM,
+    QUICKPLAY,
+    CLASH,
+    ARAM_CLASH,
+    COOP_VS_AI_INTRO,
+    COOP_VS_AI_BEGINNER,
+    COOP_VS_AI_INTERMEDIATE,
+    URF,
+    ONE_FOR_ALL,
+    NEXUS_BLITZ,
+    ULTIMATE_SPELLBOOK,
+    ARENA,
+}
+
+impl Queue {
+    /// Every known queue.
+    pub const ALL: [Queue; 17] = [
+        Queue::CUSTOM,
+        Queue::NORMAL_DRAFT,
+        Queue::RANKED_SOLO_5x5,
+        Queue::NORMAL_BLIND,
+        Queue::RANKED_FLEX_SR,
+        Queue::ARAM,
+        Queue::QUICKPLAY,
+        Queue::CLASH,
+        Queue::ARAM_CLASH,
+        Queue::COOP_VS_AI_INTRO,
+        Queue::COOP_VS_AI_BEGINNER,
+        Queue::COOP_VS_AI_INTERMEDIATE,
+        Queue::URF,
+        Queue::ONE_FOR_ALL,
+        Queue::NEXUS_BLITZ,
+        Queue::ULTIMATE_SPELLBOOK,
+        Queue::ARENA,
+    ];
+
+    /// Returns the queue id used by match-v5 and spectator-v4 (420, 450, ...).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::queue::*;
+    ///
+    /// assert_eq!(Queue::RANKED_SOLO_5x5.id(), 420);
+    /// assert_eq!(Queue::from_id(450), Some(Queue::ARAM));
+    /// assert_eq!(Queue::from_id(-1), None);
+    /// ```
+    pub fn id(&self) -> i32 {
+        match self {
+            Queue::CUSTOM => 0,
+            Queue::NORMAL_DRAFT => 400,
+            Queue::RANKED_SOLO_5x5 => 420,
+            Queue::NORMAL_BLIND => 430,
+            Queue::RANKED_FLEX_SR => 440,
+            Queue::ARAM => 450,
+            Queue::QUICKPLAY => 490,
+            Queue::CLASH => 700,
+            Queue::ARAM_CLASH => 720,
+            Queue::COOP_VS_AI_INTRO => 830,
+            Queue::COOP_VS_AI_BEGINNER => 840,
+            Queue::COOP_VS_AI_INTERMEDIATE => 850,
+            Queue::URF => 900,
+            Queue::ONE_FOR_ALL => 1020,
+            Queue::NEXUS_BLITZ => 1300,
+            Queue::ULTIMATE_SPELLBOOK => 1400,
+            Queue::ARENA => 1700,
+        }
+    }
+
+    /// Returns the queue matching a match-v5 queue id, None if it is unknown.
+    pub fn from_id(id: i32) -> Option<Queue> {
+        Queue::ALL.into_iter().find(|queue| queue.id() == id)
+    }
+
+    /// Returns the queue as written by the Riot API ("RANKED_SOLO_5x5", "ARAM", ...),
+    /// ranked queues being written as league-v4 expects them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::queue::*;
+    ///
+    /// assert_eq!(Queue::RANKED_FLEX_SR.as_str(), "RANKED_FLEX_SR");
+    /// assert_eq!(Queue::from_name("RANKED_SOLO_5x5"), Some(Queue::RANKED_SOLO_5x5));
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Queue::CUSTOM => "CUSTOM",
+            Queue::NORMAL_DRAFT => "NORMAL_DRAFT",
+            Queue::RANKED_SOLO_5x5 => "RANKED_SOLO_5x5",
+            Queue::NORMAL_BLIND => "NORMAL_BLIND",
+            Queue::RANKED_FLEX_SR => "RANKED_FLEX_SR",
+            Queue::ARAM => "ARAM",
+            Queue::QUICKPLAY => "QUICKPLAY",
+            Queue::CLASH => "CLASH",
+            Queue::ARAM_CLASH => "ARAM_CLASH",
+            Queue::COOP_VS_AI_INTRO => "COOP_VS_AI_INTRO",
+            Queue::COOP_VS_AI_BEGINNER => "COOP_VS_AI_BEGINNER",
+            Queue::COOP_VS_AI_INTERMEDIATE => "COOP_VS_AI_INTERMEDIATE",
+            Queue::URF => "URF",
+            Queue::ONE_FOR_ALL => "ONE_FOR_ALL",
+            Queue::NEXUS_BLITZ => "NEXUS_BLITZ",
+            Queue::ULTIMATE_SPELLBOOK => "ULTIMATE_SPELLBOOK",
+            Queue::ARENA => "ARENA",
+        }
+    }
+
+    /// Returns the queue matching its name, None if it is unknown.
+    pub fn from_name(name: &str) -> Option<Queue> {
+        Queue::ALL.into_iter().find(|queue| queue.as_str() == name)
+    }
+
+    /// Returns whether games of this queue change the league-v4 ranks.
+    pub fn is_ranked(&self) -> bool {
+        matches!(self, Queue::RANKED_SOLO_5x5 | Queue::RANKED_FLEX_SR)
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A ranked tier, ordered from IRON to CHALLENGER.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Tier {
+    IRON,
+    BRONZE,
+    SILVER,
+    GOLD,
+    PLATINUM,
+    EMERALD,
+    DIAMOND,
+    MASTER,
+    GRANDMASTER,
+    CHALLENGER,
+}
+
+impl Tier {
+    /// Every tier, from the lowest to the highest.
+    pub const ALL: [Tier; 10] = [
+        Tier::IRON,
+        Tier::BRONZE,
+        Tier::SILVER,
+        Tier::GOLD,
+        Tier::PLATINUM,
+        Tier::EMERALD,
+        Tier::DIAMOND,
+        Tier::MASTER,
+        Tier::GRANDMASTER,
+        Tier::CHALLENGER,
+    ];
+
+    /// Returns the tier as written by the Riot API ("IRON", "MASTER", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tier::IRON => "IRON",
+            Tier::BRONZE => "BRONZE",
+            Tier::SILVER => "SILVER",
+            Tier::GOLD => "GOLD",
+            Tier::PLATINUM => "PLATINUM",
+            Tier::EMERALD => "EMERALD",
+            Tier::DIAMOND => "DIAMOND",
+            Tier::MASTER => "MASTER",
+            Tier::GRANDMASTER => "GRANDMASTER",
+            Tier::CHALLENGER => "CHALLENGER",
+        }
+    }
+
+    /// Returns the tier matching its name, None if it is unknown.
+    pub fn from_name(name: &str) -> Option<Tier> {
+        Tier::ALL.into_iter().find(|tier| tier.as_str() == name)
+    }
+
+    /// Returns whether the tier has a single division (MASTER and above).
+    pub fn is_apex(&self) -> bool {
+        *self >= Tier::MASTER
+    }
+}
+
+/// A division of a tier, ordered from IV to I.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Division {
+    IV,
+    III,
+    II,
+    I,
+}
+
+impl Division {
+    /// Every division, from the lowest to the highest.
+    pub const ALL: [Division; 4] = [Division::IV, Division::III, Division::II, Division::I];
+
+    /// Returns the division as written by the Riot API ("IV", "I", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Division::IV => "IV",
+            Division::III => "III",
+            Division::II => "II",
+            Division::I => "I",
+        }
+    }
+
+    /// Returns the division matching its name, None if it is unknown.
+    pub fn from_name(name: &str) -> Option<Division> {
+        Division::ALL
+            .into_iter()
+            .find(|division| division.as_str() == name)
+    }
+}
+
+/// The rank of a player in a queue, ordered by tier, then division, then league points.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::rank::*;
+///
+/// let gold = Rank::new(Tier::GOLD, Division::I, 75);
+/// let platinum = Rank::new(Tier::PLATINUM, Division::IV, 0);
+/// assert!(platinum > gold);
+/// assert!(Rank::new(Tier::GOLD, Division::I, 80) > gold);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rank {
+    pub tier: Tier,
+    pub division: Division,
+    pub league_points: i32,
+}
+
+impl Rank {
+    pub fn new(tier: Tier, division: Division, league_points: i32) -> Rank {
+        Rank {
+            tier,
+            division,
+            league_points,
+        }
+    }
+}
+
+impl fmt::Display for Tier {
+    /// Writes the tier as displayed in the client ("Gold", "Grandmaster", ...).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.as_str();
+        write!(f, "{}{}", &name[..1], name[1..].to_lowercase())
+    }
+}
+
+impl fmt::Display for Division {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Rank {
+    /// Writes the rank as displayed in the client, without the division of the apex tiers.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::rank::*;
+    ///
+    /// assert_eq!(Rank::new(Tier::GOLD, Division::II, 56).to_string(), "Gold II, 56 LP");
+    /// assert_eq!(Rank::new(Tier::MASTER, Division::I, 230).to_string(), "Master, 230 LP");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.tier.is_apex() {
+            true => write!(f, "{}, {} LP", self.tier, self.league_points),
+            false => write!(
+                f,
+                "{} {}, {} LP",
+                self.tier, self.division, self.league_points
+            ),
+        }
+    }
+}
@@ -0,0 +1,447 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::Duration,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+// std's Instant panics in browsers.
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::{cancellation::CancellationToken, error::SamiraError, metrics::RateLimitUsage};
+
+/// How requests are throttled to stay under the app and method rate limits.
+/// The default is [`RateLimitStrategy::Throttle`], except on the wasm32 target where it is [`RateLimitStrategy::FailFast`]
+/// since a browser cannot block its thread to wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitStrategy {
+    /// Waits until a request can be sent without exceeding the rate limits, unsupported on the wasm32 target.
+    #[cfg_attr(not(target_arch = "wasm32"), default)]
+    Throttle,
+    /// Fails with [`SamiraError::RateLimited`] instead of waiting.
+    #[cfg_attr(target_arch = "wasm32", default)]
+    FailFast,
+    /// Sends every request without throttling, e.g. behind a proxy already enforcing the rate limits.
+    Disabled,
+}
+
+/// How the keys of a pool are picked to send each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyRotation {
+    /// Keeps sending requests with the same key until it reaches its rate limits.
+    #[default]
+    WhenLimited,
+    /// Sends each request with the next key, skipping the keys which reached their rate limits.
+    PerRequest,
+}
+
+/// How urgently a request is sent once the rate limits are reached.
+/// While a request waits for the limits of a route, the requests of lower priority to this route wait behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Requests which can be delayed, e.g. crawling matches.
+    Background,
+    #[default]
+    Normal,
+    /// Requests a user is waiting on, e.g. looking up a summoner.
+    Interactive,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+const CANCELLATION_POLL: Duration = Duration::from_millis(100);
+
+/// The route and priority of waiting requests.
+type WaitingKey = (String, Priority);
+/// The number of waiting requests of each route and priority.
+type Waiting = HashMap<WaitingKey, usize>;
+
+/// Counts the requests waiting for the rate limits of each route and priority,
+/// so lower priority requests let the higher priority ones go first.
+#[derive(Debug, Default)]
+pub(crate) struct PriorityQueue {
+    waiting: Mutex<Waiting>,
+    changed: Condvar,
+}
+
+impl PriorityQueue {
+    /// Blocks while a request of higher priority waits for the rate limits of the route.
+    fn wait_for_higher(
+        &self,
+        route: &str,
+        priority: Priority,
+        cancellation: &CancellationToken,
+    ) -> Result<(), SamiraError> {
+        let mut waiting = self.waiting.lock().unwrap();
+        while waiting.keys().any(|(waiting_route, waiting_priority)| {
+            waiting_route == route && *waiting_priority > priority
+        }) {
+            cancellation.check()?;
+            waiting = self.wait_changed(waiting)?;
+        }
+        Ok(())
+    }
+
+    /// Blocks until a request leaves the queue, which notifies the waiting ones.
+    /// Cancelling a token does not notify the queue, so the wait also times out regularly to notice it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn wait_changed<'a>(
+        &self,
+        waiting: MutexGuard<'a, Waiting>,
+    ) -> Result<MutexGuard<'a, Waiting>, SamiraError> {
+        Ok(self
+            .changed
+            .wait_timeout(waiting, CANCELLATION_POLL)
+            .unwrap()
+            .0)
+    }
+
+    /// A browser cannot block its thread until the higher priority requests are sent, so the request fails instead.
+    #[cfg(target_arch = "wasm32")]
+    fn wait_changed<'a>(
+        &self,
+        _waiting: MutexGuard<'a, Waiting>,
+    ) -> Result<MutexGuard<'a, Waiting>, SamiraError> {
+        Err(SamiraError::RateLimited { retry_after: None })
+    }
+
+    fn enter<'a>(&'a self, route: &str, priority: Priority) -> Queued<'a> {
+        let key = (route.to_string(), priority);
+        *self.waiting.lock().unwrap().entry(key.clone()).or_default() += 1;
+        Queued { queue: self, key }
+    }
+}
+
+/// A request waiting in a [`PriorityQueue`], leaving it once dropped.
+struct Queued<'a> {
+    queue: &'a PriorityQueue,
+    key: WaitingKey,
+}
+
+impl Drop for Queued<'_> {
+    fn drop(&mut self) {
+        let mut waiting = self.queue.waiting.lock().unwrap();
+        if let Some(count) = waiting.get_mut(&self.key) {
+            *count -= 1;
+            if *count == 0 {
+                waiting.remove(&self.key);
+            }
+        }
+        self.queue.changed.notify_all();
+    }
+}
+
+/// The state of a rate limit window, as tracked from the latest response headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The index of the key in the pool, 0 with a single key.
+    pub key: usize,
+    /// The method of a method limit, None for an app limit.
+    pub method: Option<String>,
+    /// The number of requests sent in the current window.
+    pub count: u32,
+    /// The number of requests allowed in a window.
+    pub limit: u32,
+    /// The number of requests which can still be sent in the current window.
+    pub remaining: u32,
+    /// The duration of a window.
+    pub window: Duration,
+    /// The time left before the current window resets.
+    pub resets_in: Duration,
+    /// The time left before the limit can be used again after a 429 response, if it was reached.
+    pub retry_after: Option<Duration>,
+}
+
+/// A single rate limit window, e.g. "20 requests every 1 second".
+#[derive(Debug)]
+struct Window {
+    limit: u32,
+    duration: Duration,
+    count: u32,
+    start: Instant,
+}
+
+impl Window {
+    fn new(limit: u32, duration: Duration, count: u32, now: Instant) -> Window {
+        Window {
+            limit,
+            duration,
+            count,
+            start: now,
+        }
+    }
+
+    fn reset_if_elapsed(&mut self, now: Instant) {
+        if now >= self.start + self.duration {
+            self.start = now;
+            self.count = 0;
+        }
+    }
+
+    fn wait_time(&self, now: Instant) -> Option<Duration> {
+        if self.count < self.limit {
+            return None;
+        }
+        (self.start + self.duration).checked_duration_since(now)
+    }
+}
+
+/// The rate limit windows of one route (app limits) or one route and method (method limits).
+#[derive(Debug, Default)]
+struct Bucket {
+    windows: Vec<Window>,
+    blocked_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        let blocked = self
+            .blocked_until
+            .and_then(|blocked_until| blocked_until.checked_duration_since(now));
+        self.windows
+            .iter_mut()
+            .filter_map(|window| {
+                window.reset_if_elapsed(now);
+                window.wait_time(now)
+            })
+            .chain(blocked)
+            .max()
+    }
+
+    fn record(&mut self) {
+        for window in self.windows.iter_mut() {
+            window.count += 1;
+        }
+    }
+
+    /// Updates the windows from a limits header ("20:1,100:120") and its count header ("1:1,1:120").
+    fn update(&mut self, limits: &str, counts: Option<&str>, now: Instant) {
+        let counts = counts.map(parse_header).unwrap_or_default();
+        let mut windows = Vec::new();
+        for (limit, seconds) in parse_header(limits) {
+            let duration = Duration::from_secs(seconds as u64);
+            let count = counts
+                .iter()
+                .find(|(_, count_seconds)| *count_seconds == seconds)
+                .map(|(count, _)| *count)
+                // Without a count, at least the request of this response was made.
+                .unwrap_or(1);
+            let window = match self
+                .windows
+                .iter()
+                .position(|window| window.duration == duration)
+            {
+                Some(index) => {
+                    let mut window = self.windows.swap_remove(index);
+                    window.reset_if_elapsed(now);
+                    window.limit = limit;
+                    window.count = window.count.max(count);
+                    window
+                }
+                None => Window::new(limit, duration, count, now),
+            };
+            windows.push(window);
+        }
+        self.windows = windows;
+    }
+}
+
+fn parse_header(header: &str) -> Vec<(u32, u32)> {
+    header
+        .split(',')
+        .filter_map(|pair| {
+            let (value, seconds) = pair.trim().split_once(':')?;
+            Some((value.parse().ok()?, seconds.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Blocks until a request to the given route and method can be sent with one of the limiters,
+/// tried in order, then counts it and returns the index of the limiter used.
+/// Waiting requests of higher priority are served first, and waiting stops once cancelled.
+/// With the [`RateLimitStrategy::FailFast`] strategy it fails instead of blocking.
+pub(crate) fn acquire_any(
+    limiters: &[&RateLimiter],
+    route: &str,
+    method: &str,
+    queue: &PriorityQueue,
+    priority: Priority,
+    cancellation: &CancellationToken,
+) -> Result<usize, SamiraError> {
+    let mut queued = None;
+    loop {
+        cancellation.check()?;
+        queue.wait_for_higher(route, priority, cancellation)?;
+        let mut shortest_wait: Option<Duration> = None;
+        for (index, limiter) in limiters.iter().enumerate() {
+            match limiter.try_acquire(route, method) {
+                Some(wait) => {
+                    shortest_wait = Some(shortest_wait.map_or(wait, |shortest| shortest.min(wait)))
+                }
+                None => return Ok(index),
+            }
+        }
+        let wait = match shortest_wait {
+            Some(wait) => wait,
+            None => return Err(SamiraError::InvalidToken),
+        };
+        if limiters[0].strategy == RateLimitStrategy::FailFast {
+            return Err(SamiraError::RateLimited {
+                retry_after: Some(wait.as_secs_f64().ceil() as u64),
+            });
+        }
+        if queued.is_none() {
+            queued = Some(queue.enter(route, priority));
+        }
+        cancellation.sleep(wait)?;
+    }
+}
+
+/// A route and, for method buckets, the method name.
+type BucketKey = (String, Option<String>);
+type SharedBucket = Arc<Mutex<Bucket>>;
+
+/// Throttles requests so they stay under the app and method rate limits
+/// advertised by the Riot API response headers.
+/// Each route (platform or region) has its own app bucket, and each method of a route its own method bucket,
+/// so a burst on one route or method never delays the others.
+/// Cloning a RateLimiter shares its buckets, so it can be used from several threads.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct RateLimiter {
+    strategy: RateLimitStrategy,
+    buckets: Arc<Mutex<HashMap<BucketKey, SharedBucket>>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(strategy: RateLimitStrategy) -> RateLimiter {
+        RateLimiter {
+            strategy,
+            ..Default::default()
+        }
+    }
+
+    fn bucket(&self, route: &str, method: Option<&str>) -> SharedBucket {
+        self.buckets
+            .lock()
+            .unwrap()
+            .entry((route.to_string(), method.map(str::to_string)))
+            .or_default()
+            .clone()
+    }
+
+    /// Counts a request to the given route and method if it can be sent now,
+    /// else returns how long to wait before it can be.
+    fn try_acquire(&self, route: &str, method: &str) -> Option<Duration> {
+        if self.strategy == RateLimitStrategy::Disabled {
+            return None;
+        }
+        let app_bucket = self.bucket(route, None);
+        let method_bucket = self.bucket(route, Some(method));
+        let now = Instant::now();
+        let mut app = app_bucket.lock().unwrap();
+        let mut method = method_bucket.lock().unwrap();
+        let wait = app
+            .wait_time(now)
+            .into_iter()
+            .chain(method.wait_time(now))
+            .max();
+        if wait.is_none() {
+            app.record();
+            method.record();
+        }
+        wait
+    }
+
+    /// Returns the usage of the app and method windows of a route and method.
+    pub(crate) fn usage<'a>(&self, route: &'a str, method: &'a str) -> Vec<RateLimitUsage<'a>> {
+        if self.strategy == RateLimitStrategy::Disabled {
+            return Vec::new();
+        }
+        let now = Instant::now();
+        let mut usage = Vec::new();
+        for method in [None, Some(method)] {
+            let bucket = self.bucket(route, method);
+            let mut bucket = bucket.lock().unwrap();
+            for window in bucket.windows.iter_mut() {
+                window.reset_if_elapsed(now);
+                usage.push(RateLimitUsage {
+                    route,
+                    method,
+                    count: window.count,
+                    limit: window.limit,
+                    window: window.duration,
+                });
+            }
+        }
+        usage
+    }
+
+    /// Returns the state of the app and method windows of a route, the app windows first.
+    pub(crate) fn status(&self, route: &str, key: usize) -> Vec<RateLimitStatus> {
+        if self.strategy == RateLimitStrategy::Disabled {
+            return Vec::new();
+        }
+        let mut buckets: Vec<(Option<String>, SharedBucket)> = self
+            .buckets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((bucket_route, _), _)| bucket_route == route)
+            .map(|((_, method), bucket)| (method.clone(), bucket.clone()))
+            .collect();
+        buckets.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let now = Instant::now();
+        let mut status = Vec::new();
+        for (method, bucket) in buckets {
+            let mut bucket = bucket.lock().unwrap();
+            let retry_after = bucket
+                .blocked_until
+                .and_then(|blocked_until| blocked_until.checked_duration_since(now));
+            for window in bucket.windows.iter_mut() {
+                window.reset_if_elapsed(now);
+                status.push(RateLimitStatus {
+                    key,
+                    method: method.clone(),
+                    count: window.count,
+                    limit: window.limit,
+                    remaining: window.limit.saturating_sub(window.count),
+                    window: window.duration,
+                    resets_in: (window.start + window.duration).saturating_duration_since(now),
+                    retry_after,
+                });
+            }
+        }
+        status
+    }
+
+    /// Updates the limits of a route and method from the headers of its latest response.
+    pub(crate) fn update<'a>(
+        &self,
+        route: &str,
+        method: &str,
+        header: impl Fn(&str) -> Option<&'a str>,
+    ) {
+        if self.strategy == RateLimitStrategy::Disabled {
+            return;
+        }
+        let now = Instant::now();
+        let retry_after = header("Retry-After")
+            .and_then(|retry_after| retry_after.parse().ok())
+            .map(|retry_after| now + Duration::from_secs(retry_after));
+        let limits = [
+            (None, "X-App-Rate-Limit", "application"),
+            (Some(method), "X-Method-Rate-Limit", "method"),
+        ];
+        for (method, limit_header, limit_type) in limits {
+            if let Some(limit) = header(limit_header) {
+                let bucket = self.bucket(route, method);
+                let mut bucket = bucket.lock().unwrap();
+                bucket.update(limit, header(&format!("{}-Count", limit_header)), now);
+                if header("X-Rate-Limit-Type") == Some(limit_type) {
+                    bucket.blocked_until = retry_after;
+                }
+            }
+        }
+    }
+}
@@ -1,21 +1,97 @@
+use std::{error::Error, fmt, str::FromStr};
+
 const PROTOCOL: &str = "https";
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Region {
     AMERICAS,
     ASIA,
     EUROPE,
     SEA,
+    /// A region unknown by samira, e.g. a test environment,
+    /// named by its id which is used in its host and to track its rate limits.
+    Custom(String),
+}
+
+impl Region {
+    /// Returns every known region, e.g. to query each of them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::region::*;
+    ///
+    /// let regions: Vec<String> = Region::all().map(|region| region.to_string()).collect();
+    /// assert_eq!(regions, vec!["americas", "asia", "europe", "sea"]);
+    /// ```
+    pub fn all() -> impl Iterator<Item = Region> {
+        [Region::AMERICAS, Region::ASIA, Region::EUROPE, Region::SEA].into_iter()
+    }
 }
 
 pub fn get_region_url(region: &Region) -> String {
     format!(
         "{protocol}://{region}.api.riotgames.com",
         protocol = PROTOCOL,
-        region = match region {
-            Region::AMERICAS => "americas",
-            Region::ASIA => "asia",
-            Region::EUROPE => "europe",
-            Region::SEA => "sea",
-        }
+        region = get_region_id(region)
     )
 }
+
+/// Returns the region as written in the Riot API hosts ("americas", "europe", ...).
+pub(crate) fn get_region_id(region: &Region) -> &str {
+    match region {
+        Region::AMERICAS => "americas",
+        Region::ASIA => "asia",
+        Region::EUROPE => "europe",
+        Region::SEA => "sea",
+        Region::Custom(id) => id,
+    }
+}
+
+/// The error returned when a string is not a known region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRegionError(String);
+
+impl fmt::Display for ParseRegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown region: {}", self.0)
+    }
+}
+
+impl Error for ParseRegionError {}
+
+impl FromStr for Region {
+    type Err = ParseRegionError;
+
+    /// Parses a region from its id ("europe", "americas", ...), whatever its case.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::region::*;
+    ///
+    /// assert_eq!("EUROPE".parse(), Ok(Region::EUROPE));
+    /// assert_eq!("sea".parse(), Ok(Region::SEA));
+    /// assert_eq!("mars".parse::<Region>().is_err(), true);
+    /// ```
+    fn from_str(region: &str) -> Result<Region, ParseRegionError> {
+        match region.trim().to_lowercase().as_str() {
+            "americas" => Ok(Region::AMERICAS),
+            "asia" => Ok(Region::ASIA),
+            "europe" => Ok(Region::EUROPE),
+            "sea" => Ok(Region::SEA),
+            _ => Err(ParseRegionError(region.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    /// Writes the region id ("americas", "europe", ...).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(get_region_id(self))
+    }
+}
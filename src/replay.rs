@@ -0,0 +1,189 @@
+//! The Replay API, served by the game on this machine while a replay is played.
+//! It must be enabled with `EnableReplayApi=1` in the `[General]` section of the `game.cfg` file of the game.
+
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    error::SamiraError,
+    models::replay_model::*,
+    request::read_json,
+    transport::{local_transport, HttpTransport},
+};
+
+/// The address of the Replay API, served on the port of the Live Client Data API.
+pub const REPLAY_URL: &str = "https://127.0.0.1:2999/replay";
+
+/// A client of the Replay API, driving the playback, the camera and the render of a replay.
+/// The game answers over HTTPS with a self-signed certificate, which the default transport accepts for loopback addresses only.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{replay::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new().with_json(
+///     "https://127.0.0.1:2999/replay/playback",
+///     r#"{"length": 1834.2, "paused": false, "seeking": false, "speed": 1.0, "time": 300.0}"#,
+/// );
+/// let client = ReplayClient::new().with_transport(transport);
+/// assert_eq!(client.try_seek(300.0).unwrap().time, 300.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayClient {
+    url: String,
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl Default for ReplayClient {
+    fn default() -> ReplayClient {
+        ReplayClient {
+            url: REPLAY_URL.to_string(),
+            transport: local_transport(),
+        }
+    }
+}
+
+impl ReplayClient {
+    /// Creates a client of the replay played on this machine.
+    pub fn new() -> ReplayClient {
+        ReplayClient::default()
+    }
+
+    /// Sets the url of the Replay API, [`REPLAY_URL`] by default.
+    pub fn with_url(mut self, url: &str) -> ReplayClient {
+        self.url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests,
+    /// a [`UreqTransport::local`](crate::transport::ureq_transport::UreqTransport::local) by default.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> ReplayClient {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Retrieve the game process playing the replay.
+    pub fn get_game(&self) -> Option<ReplayGame> {
+        self.try_get_game().ok()
+    }
+
+    /// Retrieve the game process playing the replay.
+    /// Unlike [`ReplayClient::get_game`] it returns the reason of the failure.
+    pub fn try_get_game(&self) -> Result<ReplayGame, SamiraError> {
+        self.get("game")
+    }
+
+    /// Retrieve the playback state of the replay.
+    pub fn get_playback(&self) -> Option<Playback> {
+        self.try_get_playback().ok()
+    }
+
+    /// Retrieve the playback state of the replay.
+    /// Unlike [`ReplayClient::get_playback`] it returns the reason of the failure.
+    pub fn try_get_playback(&self) -> Result<Playback, SamiraError> {
+        self.get("playback")
+    }
+
+    /// Changes the playback state of the replay and returns the new state.
+    pub fn try_set_playback(&self, update: &PlaybackUpdate) -> Result<Playback, SamiraError> {
+        self.post("playback", update)
+    }
+
+    /// Moves the playback to a time of the game, in seconds.
+    pub fn try_seek(&self, time: f64) -> Result<Playback, SamiraError> {
+        self.try_set_playback(&PlaybackUpdate {
+            time: Some(time),
+            ..Default::default()
+        })
+    }
+
+    /// Sets the playback speed, 1.0 being real time.
+    pub fn try_set_speed(&self, speed: f64) -> Result<Playback, SamiraError> {
+        self.try_set_playback(&PlaybackUpdate {
+            speed: Some(speed),
+            ..Default::default()
+        })
+    }
+
+    /// Pauses or resumes the playback.
+    pub fn try_set_paused(&self, paused: bool) -> Result<Playback, SamiraError> {
+        self.try_set_playback(&PlaybackUpdate {
+            paused: Some(paused),
+            ..Default::default()
+        })
+    }
+
+    /// Retrieve the render settings of the replay.
+    pub fn get_render(&self) -> Option<Render> {
+        self.try_get_render().ok()
+    }
+
+    /// Retrieve the render settings of the replay.
+    /// Unlike [`ReplayClient::get_render`] it returns the reason of the failure.
+    pub fn try_get_render(&self) -> Result<Render, SamiraError> {
+        self.get("render")
+    }
+
+    /// Changes the render settings set in `render` and returns the new settings.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::replay_model::*, replay::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json("*/replay/render", r#"{"interfaceAll": false, "cameraMode": "focus"}"#);
+    /// let client = ReplayClient::new().with_transport(transport);
+    /// let render = Render {
+    ///     interface_all: Some(false),
+    ///     camera_mode: Some(CameraMode::FOCUS),
+    ///     selection_name: Some("Samira".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(client.try_set_render(&render).unwrap().camera_mode, Some(CameraMode::FOCUS));
+    /// ```
+    pub fn try_set_render(&self, render: &Render) -> Result<Render, SamiraError> {
+        self.post("render", render)
+    }
+
+    /// Retrieve the keyframes animating the replay.
+    pub fn get_sequence(&self) -> Option<Sequence> {
+        self.try_get_sequence().ok()
+    }
+
+    /// Retrieve the keyframes animating the replay.
+    /// Unlike [`ReplayClient::get_sequence`] it returns the reason of the failure.
+    pub fn try_get_sequence(&self) -> Result<Sequence, SamiraError> {
+        self.get("sequence")
+    }
+
+    /// Replaces the keyframes animating the replay and returns the new sequence,
+    /// an empty [`Sequence`] stops the animation.
+    pub fn try_set_sequence(&self, sequence: &Sequence) -> Result<Sequence, SamiraError> {
+        self.post("sequence", sequence)
+    }
+
+    fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, SamiraError> {
+        let request = format!("{}/{}", self.url, endpoint);
+        let response =
+            read_json(self.transport.get(&request, &[])?)?.ok_or(SamiraError::NotFound)?;
+        Ok(T::deserialize(&response)?)
+    }
+
+    fn post<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &impl Serialize,
+    ) -> Result<T, SamiraError> {
+        let request = format!("{}/{}", self.url, endpoint);
+        let body = serde_json::to_string(body)?;
+        let response =
+            read_json(self.transport.post(&request, &[], &body)?)?.ok_or(SamiraError::NotFound)?;
+        Ok(T::deserialize(&response)?)
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    io::{BufReader, Read},
+    sync::{Arc, Condvar, Mutex, OnceLock},
+};
+
+use serde_json::{self, Value};
+
+use crate::{
+    cache::{Revalidation, Validators},
+    error::SamiraError,
+    transport::{HttpResponse, HttpTransport},
+};
+
+type JsonResult = Result<Option<Value>, SamiraError>;
+
+/// Reads the JSON body of a response.
+/// A 404 response is not an error: it means the resource does not exist and returns None.
+pub(crate) fn read_json(response: HttpResponse) -> JsonResult {
+    match response.status {
+        200..=299 => Ok(Some(serde_json::from_reader(response.body)?)),
+        404 => Ok(None),
+        status => Err(SamiraError::from_status(
+            status,
+            response.header("Retry-After"),
+        )),
+    }
+}
+
+/// Reads the body of a response as text, without parsing it, for documents deserialized straight into typed values.
+/// A 404 response returns None like [`read_json`].
+pub(crate) fn read_text(mut response: HttpResponse) -> JsonResult {
+    match response.status {
+        200..=299 => {
+            let mut body = String::new();
+            response.body.read_to_string(&mut body)?;
+            Ok(Some(Value::String(body)))
+        }
+        404 => Ok(None),
+        status => Err(SamiraError::from_status(
+            status,
+            response.header("Retry-After"),
+        )),
+    }
+}
+
+/// Sends a GET request and reads its JSON body.
+pub(crate) fn get_json(transport: &dyn HttpTransport, url: &str) -> JsonResult {
+    coalesce(transport, url, || read_json(transport.get(url, &[])?))
+}
+
+/// Sends a GET request and returns a reader over its body, for documents too large to be buffered.
+/// A 404 response returns None like [`read_json`].
+pub(crate) fn get_reader(
+    transport: &dyn HttpTransport,
+    url: &str,
+    compression: bool,
+) -> Result<Option<impl Read>, SamiraError> {
+    let headers: Vec<(&str, &str)> = encoding_header(compression).into_iter().collect();
+    let response = transport.get(url, &headers)?;
+    match response.status {
+        200..=299 => Ok(Some(BufReader::new(response.body))),
+        404 => Ok(None),
+        status => Err(SamiraError::from_status(
+            status,
+            response.header("Retry-After"),
+        )),
+    }
+}
+
+/// Builds a query string ("?count=20&type=ranked") from its parameters, percent-encoding their names and values.
+pub(crate) fn encode_query(query: &[(&str, &str)]) -> String {
+    if query.is_empty() {
+        return String::new();
+    }
+    let parameters: Vec<String> = query
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode_component(name), encode_component(value)))
+        .collect();
+    format!("?{}", parameters.join("&"))
+}
+
+/// Percent-encodes a query parameter or a path segment.
+pub(crate) fn encode_component(component: &str) -> String {
+    component
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// The header disabling compression when it is not wanted.
+/// Compressed responses are otherwise requested and decompressed transparently.
+pub(crate) fn encoding_header(compression: bool) -> Option<(&'static str, &'static str)> {
+    match compression {
+        true => None,
+        false => Some(("Accept-Encoding", "identity")),
+    }
+}
+
+/// Sends a GET request which only returns the body if it changed since the validators were received.
+pub(crate) fn get_json_if_modified(
+    transport: &dyn HttpTransport,
+    url: &str,
+    validators: &Validators,
+    compression: bool,
+) -> Result<Revalidation, SamiraError> {
+    get_if_modified(transport, url, validators, compression, read_json)
+}
+
+/// Like [`get_json_if_modified`], but the body is returned as text by [`read_text`].
+pub(crate) fn get_text_if_modified(
+    transport: &dyn HttpTransport,
+    url: &str,
+    validators: &Validators,
+    compression: bool,
+) -> Result<Revalidation, SamiraError> {
+    get_if_modified(transport, url, validators, compression, read_text)
+}
+
+fn get_if_modified(
+    transport: &dyn HttpTransport,
+    url: &str,
+    validators: &Validators,
+    compression: bool,
+    read: fn(HttpResponse) -> JsonResult,
+) -> Result<Revalidation, SamiraError> {
+    let mut headers: Vec<(&str, &str)> = encoding_header(compression).into_iter().collect();
+    if let Some(etag) = &validators.etag {
+        headers.push(("If-None-Match", etag.as_str()));
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        headers.push(("If-Modified-Since", last_modified.as_str()));
+    }
+    let response = transport.get(url, &headers)?;
+    if response.status == 304 {
+        return Ok(Revalidation::NotModified);
+    }
+    let validators = Validators {
+        etag: response.header("ETag").map(str::to_string),
+        last_modified: response.header("Last-Modified").map(str::to_string),
+    };
+    Ok(Revalidation::Modified(read(response)?, validators))
+}
+
+/// A request being sent, whose result is shared with every caller waiting for it.
+#[derive(Default)]
+struct InFlight {
+    result: Mutex<Option<JsonResult>>,
+    done: Condvar,
+}
+
+fn in_flight() -> &'static Mutex<HashMap<String, Arc<InFlight>>> {
+    static IN_FLIGHT: OnceLock<Mutex<HashMap<String, Arc<InFlight>>>> = OnceLock::new();
+    IN_FLIGHT.get_or_init(Default::default)
+}
+
+/// Publishes the result of a request to its waiting callers, even if the request panicked.
+struct Publish<'a> {
+    key: &'a str,
+    request: Arc<InFlight>,
+    result: Option<JsonResult>,
+}
+
+impl Drop for Publish<'_> {
+    fn drop(&mut self) {
+        in_flight().lock().unwrap().remove(self.key);
+        let result = self
+            .result
+            .take()
+            .unwrap_or_else(|| Err(SamiraError::Transport("request aborted".to_string())));
+        *self.request.result.lock().unwrap() = Some(result);
+        self.request.done.notify_all();
+    }
+}
+
+/// Runs `fetch` unless an identical request, identified by `key`, is already being sent through the same transport,
+/// in which case it waits for that request and returns its result instead.
+/// The requests of clients with different transports are never shared, e.g. a mock and a real client.
+pub(crate) fn coalesce(
+    transport: &dyn HttpTransport,
+    key: &str,
+    fetch: impl FnOnce() -> JsonResult,
+) -> JsonResult {
+    let key = &format!("{:p}\n{}", transport as *const _ as *const (), key);
+    let (request, is_leader) = {
+        let mut requests = in_flight().lock().unwrap();
+        match requests.get(key) {
+            Some(request) => (request.clone(), false),
+            None => {
+                let request = Arc::new(InFlight::default());
+                requests.insert(key.to_string(), request.clone());
+                (request, true)
+            }
+        }
+    };
+    if is_leader {
+        let mut publish = Publish {
+            key,
+            request,
+            result: None,
+        };
+        let result = fetch();
+        publish.result = Some(result.clone());
+        return result;
+    }
+    let mut result = request.result.lock().unwrap();
+    while result.is_none() {
+        result = request.done.wait(result).unwrap();
+    }
+    result.clone().unwrap()
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+/// Controls how failed requests are retried.
+/// Rate limited (429) and transient server errors (500, 502, 503, 504) are retried,
+/// waiting for the `Retry-After` duration when the server provides it, or with an exponential backoff otherwise.
+/// On the wasm32 target the default policy never retries, since a browser cannot block its thread to wait.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled on each following retry.
+    pub base_delay: Duration,
+    /// The maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: match cfg!(target_arch = "wasm32") {
+                true => 1,
+                false => 3,
+            },
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a RetryPolicy which never retries.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::retry_policy::*;
+    ///
+    /// let policy = RetryPolicy::none();
+    /// assert_eq!(policy.max_attempts, 1);
+    /// ```
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay to wait before retrying a failed attempt, or None if it should not be retried.
+    /// `attempt` starts at 0 for the first attempt.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::retry_policy::*;
+    ///
+    /// let policy = RetryPolicy::default();
+    /// assert_eq!(policy.retry_delay(0, 503, None), Some(Duration::from_millis(500)));
+    /// assert_eq!(policy.retry_delay(1, 503, None), Some(Duration::from_secs(1)));
+    /// assert_eq!(policy.retry_delay(0, 429, Some("10")), Some(Duration::from_secs(10)));
+    /// assert_eq!(policy.retry_delay(2, 429, Some("10")), None);
+    /// assert_eq!(policy.retry_delay(0, 404, None), None);
+    /// ```
+    pub fn retry_delay(
+        &self,
+        attempt: u32,
+        status: u16,
+        retry_after: Option<&str>,
+    ) -> Option<Duration> {
+        if attempt + 1 >= self.max_attempts || !matches!(status, 429 | 500 | 502 | 503 | 504) {
+            return None;
+        }
+        let delay = match retry_after.and_then(|retry_after| retry_after.parse().ok()) {
+            Some(retry_after) => Duration::from_secs(retry_after),
+            None => self.base_delay.saturating_mul(2u32.saturating_pow(attempt)),
+        };
+        Some(delay.min(self.max_delay))
+    }
+}
@@ -1,18 +1,163 @@
+#[cfg(feature = "ureq")]
+use crate::transport::ureq_transport::UreqTransport;
 use crate::{
-    filters::summoner_filter::*,
-    models::{champion_info_model::*, summoner_model::*},
+    bulk::run_bounded,
+    cache::{memory_cache::MemoryCache, Cache, CacheCategory, CacheTtls},
+    cancellation::CancellationToken,
+    endpoint::{endpoint, Endpoint},
+    error::SamiraError,
+    filters::{match_ids_filter::*, summoner_filter::*},
+    ids::{MatchId, Puuid},
+    metrics::{MetricsSink, NoMetrics, RequestMetrics},
+    models::{
+        account_model::*, champion_info_model::*, league_model::*, match_model::Match,
+        summoner_model::*, tournament_model::*,
+    },
     platform::*,
+    queue::Queue,
+    rank::Tier,
+    rate_limiter::{
+        acquire_any, KeyRotation, Priority, PriorityQueue, RateLimitStatus, RateLimitStrategy,
+        RateLimiter,
+    },
+    region::Region,
+    request::{coalesce, encode_component, encode_query, encoding_header, read_json},
+    retry_policy::RetryPolicy,
+    route::Route,
+    transport::{default_transport, HttpResponse, HttpTransport},
 };
-use ureq::serde_json;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+use std::{
+    collections::hash_map::DefaultHasher,
+    env, fmt,
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use zeroize::Zeroize;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+// std's Instant panics in browsers.
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// The environment variables read for a token, in order.
+const TOKEN_VARIABLES: [&str; 2] = ["RIOT_API_KEY", "RIOT_API"];
+
+/// The most tournament codes created by a single request of tournament-v5.
+const MAX_TOURNAMENT_CODES: usize = 1000;
 
-#[derive(Debug, PartialEq)]
+/// A client of the Riot API.
+/// Cloning a RiotApi is cheap and the clones share their rate limits, cache and connections,
+/// so it can be stored in an application state and used from several threads.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::thread;
+/// use samira::{platform::*, riot_api::*, transport::mock_transport::*};
+///
+/// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(MockTransport::new());
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let api = api.clone();
+///         thread::spawn(move || api.get_champion_rotations(&Platform::EUW1))
+///     })
+///     .collect();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone)]
 pub struct RiotApi {
-    token: String,
+    /// The pool of keys sending the requests, it is never empty.
+    keys: Arc<[ApiKey]>,
+    key_rotation: KeyRotation,
+    /// The key tried first for the next request.
+    next_key: Arc<AtomicUsize>,
+    transport: Arc<dyn HttpTransport>,
+    retry_policy: RetryPolicy,
+    cache: Option<Arc<dyn Cache>>,
+    cache_ttls: CacheTtls,
+    compression: bool,
+    /// The url of the platform hosts, where `{platform}` is replaced by the platform id.
+    base_url: Option<String>,
+    metrics: Arc<dyn MetricsSink>,
+    priority: Priority,
+    /// The requests waiting for the rate limits, shared by the clones whatever their priority.
+    queue: Arc<PriorityQueue>,
+    cancellation: CancellationToken,
+}
+
+impl PartialEq for RiotApi {
+    fn eq(&self, other: &RiotApi) -> bool {
+        self.keys
+            .iter()
+            .map(|key| &key.token)
+            .eq(other.keys.iter().map(|key| &key.token))
+    }
+}
+
+/// An API token, which is never printed and is erased from memory when dropped.
+#[derive(Clone, PartialEq, Eq)]
+struct Token(String);
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Token(<redacted>)")
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Token {
+    fn new(token: &str) -> Token {
+        Token(token.to_string())
+    }
+
+    fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Identifies the token without revealing it.
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// A token and the rate limits of its application.
+#[derive(Debug, Clone)]
+struct ApiKey {
+    token: Token,
+    rate_limiter: RateLimiter,
+}
+
+impl ApiKey {
+    fn new(token: Token, rate_limit_strategy: RateLimitStrategy) -> ApiKey {
+        ApiKey {
+            token,
+            rate_limiter: RateLimiter::new(rate_limit_strategy),
+        }
+    }
 }
 
 impl RiotApi {
     /// Creates a new RiotApi with a token.
     /// It checks if the token is valid by retrieving the League of Legends NA1 region status.
+    /// Requests are automatically throttled to stay under the rate limits advertised by the Riot API.
     ///
     /// # Examples
     ///
@@ -35,18 +180,67 @@ impl RiotApi {
     /// // We can now use the api methods.
     /// ```
     pub fn new(token: &str) -> Option<RiotApi> {
-        let result = check_token(token);
-        if result.is_ok() && result.unwrap() == true {
-            return Some(RiotApi {
-                token: token.to_string(),
-            });
-        } else {
-            None
-        }
+        RiotApi::try_new(token).ok()
+    }
+
+    /// Creates a new RiotApi with a token.
+    /// Unlike [`RiotApi::new`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, riot_api::*};
+    ///
+    /// let api = RiotApi::try_new("INVALID_TOKEN");
+    /// assert_eq!(api, Err(SamiraError::InvalidToken));
+    /// ```
+    pub fn try_new(token: &str) -> Result<RiotApi, SamiraError> {
+        let api = RiotApi::new_unchecked(token);
+        check_token(&api)?;
+        Ok(api)
+    }
+
+    /// Creates a new RiotApi with the token of the `RIOT_API_KEY` or `RIOT_API` environment variable.
+    /// It checks if the token is valid like [`RiotApi::new`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::riot_api::*;
+    ///
+    /// let api = RiotApi::from_env().expect("RIOT_API_KEY should be a valid token");
+    /// ```
+    pub fn from_env() -> Option<RiotApi> {
+        RiotApi::try_from_env().ok()
+    }
+
+    /// Creates a new RiotApi with the token of the `RIOT_API_KEY` or `RIOT_API` environment variable.
+    /// Unlike [`RiotApi::from_env`] it returns the reason of the failure,
+    /// a missing variable being a [`SamiraError::InvalidToken`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::riot_api::*;
+    ///
+    /// match RiotApi::try_from_env() {
+    ///     Ok(api) => println!("{:?}", api.get_champion_rotations(&samira::platform::Platform::EUW1)),
+    ///     Err(error) => println!("could not create the api: {}", error),
+    /// }
+    /// ```
+    pub fn try_from_env() -> Result<RiotApi, SamiraError> {
+        RiotApi::try_new(env_token().ok_or(SamiraError::InvalidToken)?.expose())
     }
 
     /// Creates a new RiotApi with a token.
     /// It doesn't check if the token is valid.
+    /// The token is redacted when debug printed and erased from memory when the RiotApi is dropped.
     ///
     /// # Examples
     ///
@@ -56,11 +250,197 @@ impl RiotApi {
     /// use samira::riot_api::*;
     ///
     /// let api = RiotApi::new_unchecked("TOKEN_HERE");
+    /// // The token is never printed.
+    /// assert!(!format!("{:?}", api).contains("TOKEN_HERE"));
     /// ```
     pub fn new_unchecked(token: &str) -> RiotApi {
-        return RiotApi {
-            token: token.to_string(),
-        };
+        RiotApi {
+            keys: Arc::new([ApiKey::new(Token::new(token), RateLimitStrategy::default())]),
+            key_rotation: KeyRotation::default(),
+            next_key: Arc::new(AtomicUsize::new(0)),
+            transport: default_transport(),
+            retry_policy: RetryPolicy::default(),
+            cache: Some(Arc::new(MemoryCache::default())),
+            cache_ttls: CacheTtls::default(),
+            compression: true,
+            base_url: None,
+            metrics: Arc::new(NoMetrics),
+            priority: Priority::default(),
+            queue: Arc::default(),
+            cancellation: CancellationToken::default(),
+        }
+    }
+
+    /// Creates a [`RiotApiBuilder`] to configure a RiotApi before creating it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::{riot_api::*, rate_limiter::*};
+    ///
+    /// let api = RiotApi::builder()
+    ///     .token("TOKEN_HERE")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .rate_limit_strategy(RateLimitStrategy::FailFast)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> RiotApiBuilder {
+        RiotApiBuilder::default()
+    }
+
+    /// Sets the policy used to retry rate limited and transient server errors.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::{riot_api::*, retry_policy::*};
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_retry_policy(RetryPolicy {
+    ///     max_attempts: 5,
+    ///     base_delay: Duration::from_secs(1),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> RiotApi {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the cache used to store responses, replacing the default in-memory cache.
+    /// A cache can be shared by several clients, each API key keeping its own responses
+    /// since the encrypted ids differ from one key to another.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use samira::{cache::memory_cache::*, riot_api::*};
+    ///
+    /// let cache = Arc::new(MemoryCache::default());
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_cache(cache.clone());
+    /// let other_api = RiotApi::new_unchecked("OTHER_TOKEN_HERE").with_cache(cache);
+    /// ```
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> RiotApi {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Disables the response cache.
+    pub fn without_cache(mut self) -> RiotApi {
+        self.cache = None;
+        self
+    }
+
+    /// Sets the time to live of the cached responses of each category.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::{cache::*, riot_api::*};
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE")
+    ///     .with_cache_ttls(CacheTtls::default().with(CacheCategory::Summoner, Duration::from_secs(10)));
+    /// ```
+    pub fn with_cache_ttls(mut self, cache_ttls: CacheTtls) -> RiotApi {
+        self.cache_ttls = cache_ttls;
+        self
+    }
+
+    /// Enables or disables gzip compressed responses, which are enabled by default with the `gzip` feature.
+    /// Disabling it is useful to debug raw payloads.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::riot_api::*;
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_compression(false);
+    /// ```
+    pub fn with_compression(mut self, compression: bool) -> RiotApi {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the url of the platform hosts, e.g. to send the requests through a rate limiting proxy.
+    /// `{platform}` is replaced by the platform or region id ("euw1", "europe", ...), the url defaults to `https://{platform}.api.riotgames.com`.
+    /// Rate limits are still tracked per platform and region when every route goes through the same host.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::riot_api::*;
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_base_url("http://localhost:8080/{platform}");
+    /// ```
+    pub fn with_base_url(mut self, base_url: &str) -> RiotApi {
+        self.base_url = Some(base_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sets the sink receiving the metrics of the requests, which are ignored by default.
+    /// See [`MetricsSink`] for an example.
+    pub fn with_metrics(mut self, metrics: Arc<dyn MetricsSink>) -> RiotApi {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Sets the priority of the requests sent by this client once the rate limits are reached.
+    /// Clones share their rate limits, so a clone with a lower priority can run background work
+    /// without delaying the requests of the original client.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{rate_limiter::*, riot_api::*};
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_priority(Priority::Interactive);
+    /// let crawler = api.clone().with_priority(Priority::Background);
+    /// ```
+    pub fn with_priority(mut self, priority: Priority) -> RiotApi {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the token cancelling the requests of this client, see [`CancellationToken`] for an example.
+    /// Set it on a clone to only cancel the requests of a task, e.g. when its caller gave up.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> RiotApi {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests, a [`UreqTransport`](crate::transport::ureq_transport::UreqTransport) by default.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{riot_api::*, transport::ureq_transport::*};
+    ///
+    /// let agent = ureq::AgentBuilder::new().user_agent("my-app").build();
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(UreqTransport::new(agent));
+    /// ```
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> RiotApi {
+        self.transport = Arc::new(transport);
+        self
     }
 
     /// Retrieve champion rotation.
@@ -71,27 +451,73 @@ impl RiotApi {
     /// Basic usage:
     ///
     /// ```
-    /// use std::env;
-    /// use std::process::exit;
-    ///
-    /// let token = env::var("RIOT_API");
-    /// if token.is_err() {
-    ///     // We exit the program because we couldn't find the token
-    ///     exit(1);
-    /// }
-    /// let token = token.unwrap().to_string();
-    /// use samira::{riot_api::*, platform::*};
+    /// use samira::{riot_api::*, platform::*, transport::mock_transport::*};
     ///
-    /// let api = RiotApi::new(&token).unwrap();
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://euw1.api.riotgames.com/lol/platform/v3/champion-rotations",
+    ///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [18, 81], "freeChampionIds": [360, 777]}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
     /// let champion_rotations = api.get_champion_rotations(&Platform::EUW1);
     /// assert_eq!(champion_rotations.unwrap().max_new_player_level, 10);
     /// ```
     pub fn get_champion_rotations(&self, platform: &Platform) -> Option<ChampionInfo> {
-        let champion_rotations_result = get_champion_rotations(&self.token, platform);
-        if champion_rotations_result.is_ok() {
-            return Some(champion_rotations_result.unwrap());
-        }
-        None
+        self.try_get_champion_rotations(platform).ok()
+    }
+
+    /// Retrieve champion rotation.
+    /// Unlike [`RiotApi::get_champion_rotations`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{riot_api::*, platform::*};
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE");
+    /// match api.try_get_champion_rotations(&Platform::EUW1) {
+    ///     Ok(champion_rotations) => println!("{:?}", champion_rotations.free_champion_ids),
+    ///     Err(error) => println!("could not retrieve champion rotations: {}", error),
+    /// }
+    /// ```
+    pub fn try_get_champion_rotations(
+        &self,
+        platform: &Platform,
+    ) -> Result<ChampionInfo, SamiraError> {
+        Ok(serde_json::from_value(
+            self.try_get_champion_rotations_raw(platform)?,
+        )?)
+    }
+
+    /// Retrieve champion rotation as untyped JSON, giving access to the fields [`ChampionInfo`] does not cover yet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{riot_api::*, platform::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://euw1.api.riotgames.com/lol/platform/v3/champion-rotations",
+    ///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": [], "newField": true}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let champion_rotations = api.get_champion_rotations_raw(&Platform::EUW1).unwrap();
+    /// assert_eq!(champion_rotations["newField"], true);
+    /// ```
+    pub fn get_champion_rotations_raw(&self, platform: &Platform) -> Option<Value> {
+        self.try_get_champion_rotations_raw(platform).ok()
+    }
+
+    /// Retrieve champion rotation as untyped JSON.
+    /// Unlike [`RiotApi::get_champion_rotations_raw`] it returns the reason of the failure.
+    pub fn try_get_champion_rotations_raw(
+        &self,
+        platform: &Platform,
+    ) -> Result<Value, SamiraError> {
+        get_champion_rotations(self, platform)?.ok_or(SamiraError::NotFound)
     }
 
     /// Retrieve a summoner by a given filter.
@@ -102,180 +528,1175 @@ impl RiotApi {
     /// Basic usage:
     ///
     /// ```
-    /// use std::env;
-    /// use std::process::exit;
+    /// use samira::{riot_api::*, platform::*, filters::summoner_filter::*, transport::mock_transport::*};
     ///
-    /// let token = env::var("RIOT_API");
-    /// if token.is_err() {
-    ///     // We exit the program because we couldn't find the token
-    ///     exit(1);
-    /// }
-    /// let token = token.unwrap().to_string();
-    /// use samira::{riot_api::*, platform::*, filters::summoner_filter::*};
-    ///
-    /// let api = RiotApi::new(&token).unwrap();
     /// let name = "RqndomHax";
+    /// let puuid = "Y22N0dvmtG6NsF5GTpPJ4yhxI2t3zMvP5solMwWSqj1Ld-YAijBqMG5bDP9xYZ9EgVkyxiyifsMC_Q";
+    /// let summoner = format!(
+    ///     r#"{{"accountId": "", "profileIconId": 0, "revisionDate": 0, "name": "{}", "id": "", "puuid": "{}", "summonerLevel": 30}}"#,
+    ///     name, puuid,
+    /// );
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/lol/summoner/v4/summoners/by-name/RqndomHax", &summoner)
+    ///     .with_json(&format!("*/lol/summoner/v4/summoners/by-puuid/{}", puuid), &summoner);
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
     /// let summoner = api.get_summoner(&Platform::EUW1, SummonerFilter {name: Some(name.to_string()), ..Default::default()});
     /// assert_eq!(summoner.unwrap().name, name);
     /// // We can add multiple filters so we can still find a profile with incorect infos.
-    /// let puuid = "Y22N0dvmtG6NsF5GTpPJ4yhxI2t3zMvP5solMwWSqj1Ld-YAijBqMG5bDP9xYZ9EgVkyxiyifsMC_Q";
-    /// let summoner = api.get_summoner(&Platform::EUW1, SummonerFilter {name: Some("_RandomHaxx_".to_string()), puuid: Some(puuid.to_string()), ..Default::default()});
+    /// let summoner = api.get_summoner(&Platform::EUW1, SummonerFilter::by_name("_RandomHaxx_").puuid(puuid));
     /// let summoner = summoner.unwrap();
     /// assert_eq!(summoner.name, name); // We are still finding RqndomHax, thanks to the puuid
     /// assert_eq!(summoner.puuid, puuid); // The puuid is the correct filter
+    /// // The fields are percent-encoded in the path.
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/lol/summoner/v4/summoners/by-name/Rqndom%20Hax%2F%3F",
+    ///     r#"{"accountId": "", "profileIconId": 0, "revisionDate": 0, "name": "Rqndom Hax/?", "id": "", "puuid": "", "summonerLevel": 30}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// assert!(api.get_summoner(&Platform::EUW1, SummonerFilter::by_name("Rqndom Hax/?")).is_some());
+    /// ```
+    pub fn get_summoner(&self, platform: &Platform, summoner: SummonerFilter) -> Option<Summoner> {
+        self.try_get_summoner(platform, summoner).ok()
+    }
+
+    /// Retrieve a summoner by a given filter.
+    /// Unlike [`RiotApi::get_summoner`] it returns the reason of the failure.
+    /// The fields of the filter are tried in its [`SummonerFilter::lookup_order`],
+    /// only a missing summoner falls through to the next filter, any other error is returned immediately.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, riot_api::*, platform::*, filters::summoner_filter::*};
+    ///
+    /// let api = RiotApi::new_unchecked("INVALID_TOKEN");
+    /// let summoner = api.try_get_summoner(&Platform::EUW1, SummonerFilter {name: Some("RqndomHax".to_string()), ..Default::default()});
+    /// assert_eq!(summoner, Err(SamiraError::InvalidToken));
     /// ```
-    pub fn get_summoner(
+    pub fn try_get_summoner(
         &self,
         platform: &Platform,
-        mut summoner: SummonerFilter,
-    ) -> Option<Summoner> {
-        if summoner.account_id.is_some() {
-            return match get_summoner_by_account(
-                &self.token,
-                platform,
-                summoner.account_id.as_ref().unwrap().as_str(),
-            ) {
-                Ok(result) => Some(result),
-                Err(_) => {
-                    summoner.account_id = None;
-                    self.get_summoner(platform, summoner)
-                }
-            };
-        }
-        if summoner.name.is_some() {
-            return match get_summoner_by_name(
-                &self.token,
-                platform,
-                summoner.name.as_ref().unwrap().as_str(),
-            ) {
-                Ok(result) => Some(result),
-                Err(_) => {
-                    summoner.name = None;
-                    self.get_summoner(platform, summoner)
-                }
-            };
-        }
-        if summoner.id.is_some() {
-            return match get_summoner(
-                &self.token,
-                platform,
-                summoner.id.as_ref().unwrap().as_str(),
-            ) {
-                Ok(result) => Some(result),
-                Err(_) => {
-                    summoner.id = None;
-                    self.get_summoner(platform, summoner)
-                }
-            };
-        }
-        if summoner.puuid.is_some() {
-            return match get_summoner_by_puuid(
-                &self.token,
-                platform,
-                summoner.puuid.as_ref().unwrap().as_str(),
-            ) {
-                Ok(result) => Some(result),
-                Err(_) => {
-                    summoner.puuid = None;
-                    self.get_summoner(platform, summoner)
-                }
-            };
-        }
-        None
+        summoner: SummonerFilter,
+    ) -> Result<Summoner, SamiraError> {
+        Ok(serde_json::from_value(
+            self.try_get_summoner_raw(platform, summoner)?,
+        )?)
     }
-}
 
-fn get_champion_rotations(token: &str, platform: &Platform) -> Result<ChampionInfo, ureq::Error> {
-    let request = format!(
-        "{server}/lol/platform/v3/champion-rotations",
-        server = get_platform_url(platform)
-    );
-    let response: serde_json::Value = ureq::get(&request)
-        .set("X-Riot-Token", token)
-        .call()?
-        .into_json()?;
-
-    Ok(serde_json::from_value(response).unwrap())
-}
+    /// Retrieve a summoner by a given filter as untyped JSON, giving access to the fields [`Summoner`] does not cover yet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{riot_api::*, platform::*, filters::summoner_filter::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/lol/summoner/v4/summoners/by-name/RqndomHax",
+    ///     r#"{"name": "RqndomHax", "summonerLevel": 30}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let filter = SummonerFilter {name: Some("RqndomHax".to_string()), ..Default::default()};
+    /// let summoner = api.get_summoner_raw(&Platform::EUW1, filter).unwrap();
+    /// assert_eq!(summoner["summonerLevel"], 30);
+    /// ```
+    pub fn get_summoner_raw(&self, platform: &Platform, summoner: SummonerFilter) -> Option<Value> {
+        self.try_get_summoner_raw(platform, summoner).ok()
+    }
 
-fn get_summoner(
-    token: &str,
-    platform: &Platform,
-    encrypted_summoner_id: &str,
-) -> Result<Summoner, ureq::Error> {
-    let request = format!(
-        "{server}/lol/summoner/v4/summoners/{encrypted_summoner_id}",
-        server = get_platform_url(platform),
-        encrypted_summoner_id = encrypted_summoner_id
-    );
-    let response: serde_json::Value = ureq::get(&request)
-        .set("X-Riot-Token", token)
-        .call()?
-        .into_json()?;
+    /// Retrieve a summoner by a given filter as untyped JSON.
+    /// Unlike [`RiotApi::get_summoner_raw`] it returns the reason of the failure.
+    /// It fails with [`SamiraError::InvalidFilter`] if the filter has no field set.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{error::*, riot_api::*, platform::*, filters::summoner_filter::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json(
+    ///         "https://europe.api.riotgames.com/riot/account/v1/accounts/by-riot-id/Rqndom%20Hax/EUW",
+    ///         r#"{"puuid": "PUUID", "gameName": "Rqndom Hax", "tagLine": "EUW"}"#,
+    ///     )
+    ///     .with_json("*/lol/summoner/v4/summoners/by-puuid/PUUID", r#"{"puuid": "PUUID", "summonerLevel": 30}"#);
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let summoner = api.try_get_summoner_raw(&Platform::EUW1, SummonerFilter::by_riot_id("Rqndom Hax", "EUW"));
+    /// assert_eq!(summoner.unwrap()["summonerLevel"], 30);
+    /// let summoner = api.try_get_summoner_raw(&Platform::EUW1, SummonerFilter::default());
+    /// assert!(matches!(summoner, Err(SamiraError::InvalidFilter(_))));
+    /// ```
+    pub fn try_get_summoner_raw(
+        &self,
+        platform: &Platform,
+        summoner: SummonerFilter,
+    ) -> Result<Value, SamiraError> {
+        resolve_summoner(self, platform, &summoner).map(|(summoner, _, _)| summoner)
+    }
 
-    Ok(serde_json::from_value(response).unwrap())
-}
+    /// Retrieve a summoner by a given filter along with the identifier which found it
+    /// and the identifiers tried before which found none, so stale identifiers can be detected and replaced.
+    /// If the summoner does not exist it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{riot_api::*, platform::*, filters::summoner_filter::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/lol/summoner/v4/summoners/by-puuid/PUUID",
+    ///     r#"{"accountId": "", "profileIconId": 0, "revisionDate": 0, "name": "", "id": "", "puuid": "PUUID", "summonerLevel": 30}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let resolved = api.resolve_summoner(&Platform::EUW1, SummonerFilter::by_name("OldName").puuid("PUUID")).unwrap();
+    /// assert_eq!(resolved.summoner.summoner_level, 30);
+    /// assert_eq!(resolved.matched, SummonerField::Puuid);
+    /// // The name did not find the summoner anymore.
+    /// assert_eq!(resolved.missed, [SummonerField::Name]);
+    /// ```
+    pub fn resolve_summoner(
+        &self,
+        platform: &Platform,
+        summoner: SummonerFilter,
+    ) -> Option<ResolvedSummoner> {
+        self.try_resolve_summoner(platform, summoner).ok()
+    }
 
-fn get_summoner_by_account(
-    token: &str,
-    platform: &Platform,
-    encrypted_account_id: &str,
-) -> Result<Summoner, ureq::Error> {
-    let request = format!(
-        "{server}/lol/summoner/v4/summoners/by-account/{encrypted_account_id}",
-        server = get_platform_url(platform),
-        encrypted_account_id = encrypted_account_id
-    );
-    let response: serde_json::Value = ureq::get(&request)
-        .set("X-Riot-Token", token)
-        .call()?
-        .into_json()?;
+    /// Retrieve a summoner by a given filter along with the identifiers tried to find it.
+    /// Unlike [`RiotApi::resolve_summoner`] it returns the reason of the failure.
+    pub fn try_resolve_summoner(
+        &self,
+        platform: &Platform,
+        summoner: SummonerFilter,
+    ) -> Result<ResolvedSummoner, SamiraError> {
+        let (summoner, matched, missed) = resolve_summoner(self, platform, &summoner)?;
+        Ok(ResolvedSummoner {
+            summoner: serde_json::from_value(summoner)?,
+            matched,
+            missed,
+        })
+    }
+
+    /// Retrieve a Riot account by its Riot ID, e.g. `("RqndomHax", "EUW")` for RqndomHax#EUW.
+    /// Accounts are shared by every region, the closest one should be used but SEA, which does not serve them.
+    /// If the account does not exist it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{region::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://europe.api.riotgames.com/riot/account/v1/accounts/by-riot-id/RqndomHax/EUW",
+    ///     r#"{"puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW"}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let account = api.get_account_by_riot_id(&Region::EUROPE, "RqndomHax", "EUW").unwrap();
+    /// assert_eq!(account.puuid, "PUUID");
+    /// ```
+    pub fn get_account_by_riot_id(
+        &self,
+        region: &Region,
+        game_name: &str,
+        tag_line: &str,
+    ) -> Option<Account> {
+        self.try_get_account_by_riot_id(region, game_name, tag_line)
+            .ok()
+    }
+
+    /// Retrieve a Riot account by its Riot ID.
+    /// Unlike [`RiotApi::get_account_by_riot_id`] it returns the reason of the failure.
+    pub fn try_get_account_by_riot_id(
+        &self,
+        region: &Region,
+        game_name: &str,
+        tag_line: &str,
+    ) -> Result<Account, SamiraError> {
+        let account = get_account_by_riot_id(self, region, game_name, tag_line)?;
+        Ok(serde_json::from_value(
+            account.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Retrieve a Riot account by its PUUID, e.g. to find the Riot ID of a player.
+    /// If the account does not exist it returns None.
+    pub fn get_account_by_puuid(&self, region: &Region, puuid: &Puuid) -> Option<Account> {
+        self.try_get_account_by_puuid(region, puuid).ok()
+    }
+
+    /// Retrieve a Riot account by its PUUID.
+    /// Unlike [`RiotApi::get_account_by_puuid`] it returns the reason of the failure.
+    pub fn try_get_account_by_puuid(
+        &self,
+        region: &Region,
+        puuid: &Puuid,
+    ) -> Result<Account, SamiraError> {
+        let account = get_account_by_puuid(self, region, puuid.as_str())?;
+        Ok(serde_json::from_value(
+            account.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Retrieve the Riot account of the player who signed in with Riot (RSO),
+    /// from the access token obtained at the end of the OAuth flow.
+    /// The request is authorized by the access token instead of the API keys and its response is never cached.
+    /// If the token is invalid or expired it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{region::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://europe.api.riotgames.com/riot/account/v1/accounts/me",
+    ///     r#"{"puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW"}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let account = api.get_account_me(&Region::EUROPE, "ACCESS_TOKEN").unwrap();
+    /// assert_eq!(account.riot_id().as_deref(), Some("RqndomHax#EUW"));
+    /// ```
+    pub fn get_account_me(&self, region: &Region, access_token: &str) -> Option<Account> {
+        self.try_get_account_me(region, access_token).ok()
+    }
+
+    /// Retrieve the Riot account of the player who signed in with Riot (RSO).
+    /// Unlike [`RiotApi::get_account_me`] it returns the reason of the failure.
+    pub fn try_get_account_me(
+        &self,
+        region: &Region,
+        access_token: &str,
+    ) -> Result<Account, SamiraError> {
+        let account = get_account_me(self, region, access_token)?;
+        Ok(serde_json::from_value(
+            account.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Retrieve the match ids of a player, the latest first.
+    /// If the player does not exist it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{filters::match_ids_filter::*, queue::*, region::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://europe.api.riotgames.com/lol/match/v5/matches/by-puuid/PUUID/ids?queue=420&count=2",
+    ///     r#"["EUW1_6000000001", "EUW1_6000000000"]"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let filter = MatchIdsFilter::new().queue(Queue::RANKED_SOLO_5x5).count(2);
+    /// let match_ids = api.get_match_ids(&Region::EUROPE, &"PUUID".into(), &filter).unwrap();
+    /// assert_eq!(match_ids[0], "EUW1_6000000001");
+    /// ```
+    pub fn get_match_ids(
+        &self,
+        region: &Region,
+        puuid: &Puuid,
+        filter: &MatchIdsFilter,
+    ) -> Option<Vec<MatchId>> {
+        self.try_get_match_ids(region, puuid, filter).ok()
+    }
+
+    /// Retrieve the match ids of a player, the latest first.
+    /// Unlike [`RiotApi::get_match_ids`] it returns the reason of the failure.
+    pub fn try_get_match_ids(
+        &self,
+        region: &Region,
+        puuid: &Puuid,
+        filter: &MatchIdsFilter,
+    ) -> Result<Vec<MatchId>, SamiraError> {
+        let match_ids = get_match_ids(self, region, puuid.as_str(), filter)?;
+        Ok(serde_json::from_value(
+            match_ids.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Retrieve a match by its id, e.g. "EUW1_6000000000".
+    /// If the match does not exist it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{region::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://europe.api.riotgames.com/lol/match/v5/matches/EUW1_6000000000",
+    ///     r#"{
+    ///         "metadata": {"dataVersion": "2", "matchId": "EUW1_6000000000", "participants": []},
+    ///         "info": {
+    ///             "gameCreation": 1700000000000, "gameDuration": 1800, "gameId": 6000000000, "gameMode": "CLASSIC",
+    ///             "gameName": "", "gameStartTimestamp": 1700000000000, "gameType": "MATCHED_GAME",
+    ///             "gameVersion": "13.22.541.5047", "mapId": 11, "participants": [], "platformId": "EUW1", "queueId": 420,
+    ///             "teams": []
+    ///         }
+    ///     }"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let game = api.get_match(&Region::EUROPE, &"EUW1_6000000000".into()).unwrap();
+    /// assert_eq!(game.info.game_duration, 1800);
+    /// ```
+    pub fn get_match(&self, region: &Region, match_id: &MatchId) -> Option<Match> {
+        self.try_get_match(region, match_id).ok()
+    }
+
+    /// Retrieve a match by its id.
+    /// Unlike [`RiotApi::get_match`] it returns the reason of the failure.
+    pub fn try_get_match(&self, region: &Region, match_id: &MatchId) -> Result<Match, SamiraError> {
+        let game = get_match(self, region, match_id.as_str())?;
+        Ok(serde_json::from_value(game.ok_or(SamiraError::NotFound)?)?)
+    }
+
+    /// Retrieve the ranked entries of a player, one per queue the player is ranked in.
+    /// If the player does not exist it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, queue::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://euw1.api.riotgames.com/lol/league/v4/entries/by-puuid/PUUID",
+    ///     r#"[{"leagueId": "", "summonerId": "", "queueType": "RANKED_SOLO_5x5", "tier": "GOLD", "rank": "II",
+    ///         "leaguePoints": 56, "wins": 20, "losses": 18}]"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let entries = api.get_league_entries(&Platform::EUW1, &"PUUID".into()).unwrap();
+    /// assert_eq!(entries[0].queue(), Some(Queue::RANKED_SOLO_5x5));
+    /// ```
+    pub fn get_league_entries(
+        &self,
+        platform: &Platform,
+        puuid: &Puuid,
+    ) -> Option<Vec<LeagueEntry>> {
+        self.try_get_league_entries(platform, puuid).ok()
+    }
+
+    /// Retrieve the ranked entries of a player.
+    /// Unlike [`RiotApi::get_league_entries`] it returns the reason of the failure.
+    pub fn try_get_league_entries(
+        &self,
+        platform: &Platform,
+        puuid: &Puuid,
+    ) -> Result<Vec<LeagueEntry>, SamiraError> {
+        let entries = get_league_entries(self, platform, puuid.as_str())?;
+        Ok(serde_json::from_value(
+            entries.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Retrieve the league of an apex tier (MASTER, GRANDMASTER or CHALLENGER) in a ranked queue.
+    /// It fails with [`SamiraError::InvalidFilter`] for the other tiers, which are split in many leagues.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, queue::*, rank::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://euw1.api.riotgames.com/lol/league/v4/challengerleagues/by-queue/RANKED_SOLO_5x5",
+    ///     r#"{"tier": "CHALLENGER", "leagueId": "ID", "queue": "RANKED_SOLO_5x5", "name": "", "entries": []}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let league = api.get_apex_league(&Platform::EUW1, Tier::CHALLENGER, Queue::RANKED_SOLO_5x5);
+    /// assert_eq!(league.unwrap().tier(), Some(Tier::CHALLENGER));
+    /// assert_eq!(api.get_apex_league(&Platform::EUW1, Tier::GOLD, Queue::RANKED_SOLO_5x5), None);
+    /// ```
+    pub fn get_apex_league(
+        &self,
+        platform: &Platform,
+        tier: Tier,
+        queue: Queue,
+    ) -> Option<LeagueList> {
+        self.try_get_apex_league(platform, tier, queue).ok()
+    }
+
+    /// Retrieve the league of an apex tier in a ranked queue.
+    /// Unlike [`RiotApi::get_apex_league`] it returns the reason of the failure.
+    pub fn try_get_apex_league(
+        &self,
+        platform: &Platform,
+        tier: Tier,
+        queue: Queue,
+    ) -> Result<LeagueList, SamiraError> {
+        let league = get_apex_league(self, platform, tier, queue)?;
+        Ok(serde_json::from_value(
+            league.ok_or(SamiraError::NotFound)?,
+        )?)
+    }
+
+    /// Creates tournament codes for a tournament, sending as many requests as needed
+    /// since tournament-v5 creates at most 1000 codes per request. Every code shares the same parameters.
+    /// Tournament-v5 is only served by the AMERICAS region, whatever the region of the tournament.
+    /// A failed batch does not stop the next ones, the number of codes it requested is reported instead,
+    /// unless it failed with an invalid token, a rate limit or a cancellation: the codes left are then reported as failed
+    /// without being requested.
+    /// A batch failing with a server error is never retried since its codes may have been created anyway,
+    /// only the rate limited batches are.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::tournament_model::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://americas.api.riotgames.com/lol/tournament/v5/codes?count=2&tournamentId=4242",
+    ///     r#"["EUW04242-CODE-1", "EUW04242-CODE-2"]"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let parameters = TournamentCodeParameters::new(5).metadata("round 1");
+    /// let report = api.create_tournament_codes(4242, 2, &parameters);
+    /// assert_eq!(report.codes, ["EUW04242-CODE-1", "EUW04242-CODE-2"]);
+    /// assert!(report.failed.is_empty());
+    /// ```
+    ///
+    /// A server error is not retried:
+    ///
+    /// ```
+    /// use samira::{error::*, models::tournament_model::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_response("*/lol/tournament/v5/codes*", 503, &[], "");
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport.clone());
+    /// let report = api.create_tournament_codes(4242, 2, &TournamentCodeParameters::new(5));
+    /// assert_eq!(report.failed, [(2, SamiraError::Http { status: 503 })]);
+    /// assert_eq!(transport.requests().iter().filter(|(method, _)| method == "POST").count(), 1);
+    /// ```
+    pub fn create_tournament_codes(
+        &self,
+        tournament_id: i64,
+        count: usize,
+        parameters: &TournamentCodeParameters,
+    ) -> TournamentCodesReport {
+        let mut report = TournamentCodesReport::default();
+        let mut remaining = count;
+        while remaining > 0 {
+            let batch = remaining.min(MAX_TOURNAMENT_CODES);
+            match create_tournament_codes(self, tournament_id, batch, parameters) {
+                Ok(codes) => report.codes.extend(codes),
+                // The next batches would fail the same way.
+                Err(
+                    error @ (SamiraError::InvalidToken
+                    | SamiraError::RateLimited { .. }
+                    | SamiraError::Cancelled),
+                ) => {
+                    report.failed.push((remaining, error));
+                    break;
+                }
+                Err(error) => report.failed.push((batch, error)),
+            }
+            remaining -= batch;
+        }
+        report
+    }
+
+    /// Sends a request to any endpoint of the Riot API and deserializes its response,
+    /// e.g. to call an endpoint samira does not support yet.
+    /// The request is signed, rate limited and retried like the other endpoints but never cached,
+    /// and its method rate limits are tracked per path.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{region::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://europe.api.riotgames.com/lol/match/v5/matches/by-puuid/PUUID/ids?start=0&count=2",
+    ///     r#"["EUW1_6000000001", "EUW1_6000000000"]"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let match_ids: Vec<String> = api
+    ///     .execute(&Region::EUROPE, "/lol/match/v5/matches/by-puuid/PUUID/ids", &[("start", "0"), ("count", "2")])
+    ///     .unwrap();
+    /// assert_eq!(match_ids.len(), 2);
+    /// ```
+    pub fn execute<T: DeserializeOwned>(
+        &self,
+        route: &impl Route,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T, SamiraError> {
+        let path = format!("/{}", path.trim_start_matches('/'));
+        let request = format!(
+            "{server}{path}{query}",
+            server = self.route_url(route),
+            path = path,
+            query = encode_query(query),
+        );
+        let response = self
+            .fetch_json(route.id(), &path, &request)?
+            .ok_or(SamiraError::NotFound)?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    /// Returns the usage, remaining capacity and reset time of the rate limit windows of a platform or region,
+    /// tracked from the headers of the latest responses, so work can be planned instead of waiting on the limits.
+    /// Windows are only known once a response advertised them, and each key of the pool has its own windows.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_response(
+    ///     "*/lol/platform/v3/champion-rotations",
+    ///     200,
+    ///     &[("X-App-Rate-Limit", "20:1,100:120"), ("X-App-Rate-Limit-Count", "1:1,5:120")],
+    ///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": []}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// api.get_champion_rotations(&Platform::EUW1);
+    /// let status = api.rate_limit_status(&Platform::EUW1);
+    /// let long_window = status.iter().find(|window| window.limit == 100).unwrap();
+    /// assert_eq!(long_window.method, None);
+    /// assert_eq!(long_window.remaining, 95);
+    /// ```
+    pub fn rate_limit_status(&self, route: &impl Route) -> Vec<RateLimitStatus> {
+        self.keys
+            .iter()
+            .enumerate()
+            .flat_map(|(index, key)| key.rate_limiter.status(route.id(), index))
+            .collect()
+    }
 
-    Ok(serde_json::from_value(response).unwrap())
+    /// Runs several endpoint calls with at most `max_concurrency` threads, returning the results in order.
+    /// Every call still goes through the rate limiter, so the concurrency only bounds the requests in flight.
+    /// On the wasm32 target the calls run one after the other on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, riot_api::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "https://*.api.riotgames.com/lol/platform/v3/champion-rotations",
+    ///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": [360]}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+    /// let platforms = [Platform::EUW1, Platform::NA1, Platform::KR];
+    /// let rotations = api.get_many(&platforms, 2, |api, platform| api.try_get_champion_rotations(platform));
+    /// assert_eq!(rotations.len(), 3);
+    /// assert!(rotations.iter().all(|rotation| rotation.is_ok()));
+    /// ```
+    pub fn get_many<I: Send, T: Send>(
+        &self,
+        requests: impl IntoIterator<Item = I>,
+        max_concurrency: usize,
+        fetch: impl Fn(&RiotApi, I) -> T + Sync,
+    ) -> Vec<T> {
+        run_bounded(requests, max_concurrency, |request| fetch(self, request))
+    }
 }
 
-fn get_summoner_by_name(
-    token: &str,
-    platform: &Platform,
-    summoner_name: &str,
-) -> Result<Summoner, ureq::Error> {
-    let request = format!(
-        "{server}/lol/summoner/v4/summoners/by-name/{summoner_name}",
-        server = get_platform_url(platform),
-        summoner_name = summoner_name
-    );
-    let response: serde_json::Value = ureq::get(&request)
-        .set("X-Riot-Token", token)
-        .call()?
-        .into_json()?;
+impl RiotApi {
+    fn route_url(&self, route: &impl Route) -> String {
+        match &self.base_url {
+            Some(base_url) => base_url.replace("{platform}", route.id()),
+            None => format!("https://{}.api.riotgames.com", route.id()),
+        }
+    }
+
+    /// Sends the request to an endpoint, through the cache.
+    fn send(&self, endpoint: &Endpoint) -> Result<Option<Value>, SamiraError> {
+        self.get_json(
+            endpoint.route,
+            endpoint.method,
+            endpoint.category,
+            &endpoint.url,
+        )
+    }
+
+    fn get_json(
+        &self,
+        route: &str,
+        method: &str,
+        category: CacheCategory,
+        request: &str,
+    ) -> Result<Option<Value>, SamiraError> {
+        let cache = self.cache.as_deref().zip(self.cache_ttls.get(category));
+        if let Some((cache, _)) = cache {
+            if let Some(value) = cache.get(&self.request_key(self.next_key(), request)) {
+                return Ok(Some(value));
+            }
+        }
+        coalesce(
+            self.transport.as_ref(),
+            &self.request_key(self.next_key(), request),
+            || {
+                let (value, api_key) = self.send_json(route, method, true, |headers| {
+                    self.transport.get(request, headers)
+                })?;
+                // The key sending the request may not be the one expected when it was limited.
+                if let (Some((cache, ttl)), Some(value)) = (cache, &value) {
+                    cache.set(&self.request_key(api_key, request), value.clone(), ttl);
+                }
+                Ok(value)
+            },
+        )
+    }
+
+    /// Identifies a request sent with a key in the cache and among the in-flight requests.
+    /// The encrypted ids differ per application, so a response is only shared with the requests sent with the same key.
+    fn request_key(&self, api_key: &ApiKey, request: &str) -> String {
+        format!("{:016x}\n{}", api_key.token.fingerprint(), request)
+    }
+
+    /// Returns the key the rotation picks for the next request, unless it is limited.
+    fn next_key(&self) -> &ApiKey {
+        &self.keys[self.next_key.load(Ordering::Relaxed) % self.keys.len()]
+    }
+
+    /// Picks the key sending the next request following the key rotation,
+    /// waiting until one of the keys is under its rate limits.
+    fn acquire_key(&self, route: &str, method: &str) -> Result<&ApiKey, SamiraError> {
+        let start = match self.key_rotation {
+            KeyRotation::WhenLimited => self.next_key.load(Ordering::Relaxed),
+            KeyRotation::PerRequest => self.next_key.fetch_add(1, Ordering::Relaxed),
+        };
+        let keys: Vec<&ApiKey> = (0..self.keys.len())
+            .map(|offset| &self.keys[(start + offset) % self.keys.len()])
+            .collect();
+        let limiters: Vec<&RateLimiter> = keys.iter().map(|key| &key.rate_limiter).collect();
+        let index = acquire_any(
+            &limiters,
+            route,
+            method,
+            &self.queue,
+            self.priority,
+            &self.cancellation,
+        )?;
+        if self.key_rotation == KeyRotation::WhenLimited {
+            self.next_key
+                .store((start + index) % self.keys.len(), Ordering::Relaxed);
+        }
+        Ok(keys[index])
+    }
+
+    fn fetch_json(
+        &self,
+        route: &str,
+        method: &str,
+        request: &str,
+    ) -> Result<Option<Value>, SamiraError> {
+        coalesce(
+            self.transport.as_ref(),
+            &self.request_key(self.next_key(), request),
+            || {
+                let (value, _) = self.send_json(route, method, true, |headers| {
+                    self.transport.get(request, headers)
+                })?;
+                Ok(value)
+            },
+        )
+    }
+
+    /// Sends a request through the rate limiter with a key of the pool, retrying it following the retry policy,
+    /// and returns the response with the key which sent it.
+    /// A request which is not idempotent is only retried when rate limited, as the server rejected it without running it,
+    /// whereas it may have run it before failing with a server error.
+    fn send_json(
+        &self,
+        route: &str,
+        method: &str,
+        idempotent: bool,
+        send: impl Fn(&[(&str, &str)]) -> Result<HttpResponse, SamiraError>,
+    ) -> Result<(Option<Value>, &ApiKey), SamiraError> {
+        let mut attempt = 0;
+        loop {
+            let api_key = self.acquire_key(route, method)?;
+            let mut headers = vec![("X-Riot-Token", api_key.token.expose())];
+            headers.extend(encoding_header(self.compression));
+            let started = Instant::now();
+            let response = send(&headers);
+            self.metrics.on_request(&RequestMetrics {
+                route,
+                method,
+                status: response.as_ref().ok().map(|response| response.status),
+                latency: started.elapsed(),
+                attempt: attempt + 1,
+            });
+            let response = response?;
+            api_key
+                .rate_limiter
+                .update(route, method, |name| response.header(name));
+            for usage in api_key.rate_limiter.usage(route, method) {
+                self.metrics.on_rate_limit(&usage);
+            }
+            let delay = match idempotent || response.status == 429 {
+                true => self.retry_policy.retry_delay(
+                    attempt,
+                    response.status,
+                    response.header("Retry-After"),
+                ),
+                false => None,
+            };
+            match delay {
+                Some(delay) => self.cancellation.sleep(delay)?,
+                None => return Ok((read_json(response)?, api_key)),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Sends a request authorized by the access token of a player instead of the API keys.
+    /// Its response is neither cached nor shared with the other requests, being specific to the player.
+    fn fetch_authorized(
+        &self,
+        route: &str,
+        method: &str,
+        request: &str,
+        access_token: &str,
+    ) -> Result<Option<Value>, SamiraError> {
+        let authorization = format!("Bearer {}", access_token);
+        let mut headers = vec![("Authorization", authorization.as_str())];
+        headers.extend(encoding_header(self.compression));
+        let mut attempt = 0;
+        loop {
+            let started = Instant::now();
+            let response = self.transport.get(request, &headers);
+            self.metrics.on_request(&RequestMetrics {
+                route,
+                method,
+                status: response.as_ref().ok().map(|response| response.status),
+                latency: started.elapsed(),
+                attempt: attempt + 1,
+            });
+            let response = response?;
+            match self.retry_policy.retry_delay(
+                attempt,
+                response.status,
+                response.header("Retry-After"),
+            ) {
+                Some(delay) => self.cancellation.sleep(delay)?,
+                None => return read_json(response),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// Configures a [`RiotApi`], created with [`RiotApi::builder`].
+/// Unlike [`RiotApi::new`], the token is only checked when asked to.
+#[derive(Debug, Default)]
+pub struct RiotApiBuilder {
+    tokens: Vec<Token>,
+    key_rotation: KeyRotation,
+    check_token: bool,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    retry_policy: RetryPolicy,
+    rate_limit_strategy: RateLimitStrategy,
+    base_url: Option<String>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    metrics: Option<Arc<dyn MetricsSink>>,
+}
+
+impl RiotApiBuilder {
+    /// Adds a token sending the requests, at least one is required.
+    /// Several tokens form a pool whose rate limits add up, see [`RiotApiBuilder::tokens`].
+    pub fn token(mut self, token: &str) -> RiotApiBuilder {
+        self.tokens.push(Token::new(token));
+        self
+    }
+
+    /// Adds the token of the `RIOT_API_KEY` or `RIOT_API` environment variable, if any.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::riot_api::*;
+    ///
+    /// // Without checking the token, no request is sent.
+    /// let api = RiotApi::builder().token_from_env().check_token(false).build();
+    /// ```
+    pub fn token_from_env(mut self) -> RiotApiBuilder {
+        self.tokens.extend(env_token());
+        self
+    }
+
+    /// Adds tokens to the pool of tokens sending the requests, each with its own rate limits.
+    /// Encrypted ids (summoner ids, account ids and puuids) are specific to the application of a token,
+    /// so pooled tokens should only be used for requests which don't depend on them.
+    /// Cached responses are kept per token, and only served to the requests sent with the token which fetched them.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{rate_limiter::*, riot_api::*};
+    ///
+    /// let api = RiotApi::builder()
+    ///     .tokens(&["FIRST_TOKEN", "SECOND_TOKEN"])
+    ///     .key_rotation(KeyRotation::PerRequest)
+    ///     .build();
+    /// assert!(api.is_ok());
+    /// ```
+    pub fn tokens(mut self, tokens: &[&str]) -> RiotApiBuilder {
+        self.tokens
+            .extend(tokens.iter().map(|token| Token::new(token)));
+        self
+    }
 
-    Ok(serde_json::from_value(response).unwrap())
+    /// Sets how the tokens of the pool are picked to send each request.
+    pub fn key_rotation(mut self, key_rotation: KeyRotation) -> RiotApiBuilder {
+        self.key_rotation = key_rotation;
+        self
+    }
+
+    /// Checks if the tokens are valid when building, by retrieving the League of Legends NA1 region status.
+    pub fn check_token(mut self, check_token: bool) -> RiotApiBuilder {
+        self.check_token = check_token;
+        self
+    }
+
+    /// Sets the timeout of each request, requests don't time out by default.
+    /// It is ignored with a custom transport.
+    pub fn timeout(mut self, timeout: Duration) -> RiotApiBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the user agent sent with every request, `samira/<version>` by default.
+    /// It is ignored with a custom transport.
+    pub fn user_agent(mut self, user_agent: &str) -> RiotApiBuilder {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Sets the policy used to retry rate limited and transient server errors.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> RiotApiBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets how requests are throttled to stay under the rate limits.
+    pub fn rate_limit_strategy(mut self, rate_limit_strategy: RateLimitStrategy) -> RiotApiBuilder {
+        self.rate_limit_strategy = rate_limit_strategy;
+        self
+    }
+
+    /// Sets the url of the platform hosts, where `{platform}` is replaced by the platform id ("euw1", "na1", ...).
+    /// It defaults to `https://{platform}.api.riotgames.com`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::riot_api::*;
+    ///
+    /// let api = RiotApi::builder()
+    ///     .token("TOKEN_HERE")
+    ///     .base_url("http://localhost:8080/{platform}")
+    ///     .build();
+    /// assert!(api.is_ok());
+    /// ```
+    pub fn base_url(mut self, base_url: &str) -> RiotApiBuilder {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Sets the sink receiving the metrics of the requests.
+    pub fn metrics(mut self, metrics: Arc<dyn MetricsSink>) -> RiotApiBuilder {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> RiotApiBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Creates the RiotApi.
+    /// It fails with [`SamiraError::InvalidToken`] without a token, or if a token is checked and invalid.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{error::*, riot_api::*};
+    ///
+    /// let api = RiotApi::builder().build();
+    /// assert_eq!(api, Err(SamiraError::InvalidToken));
+    /// ```
+    pub fn build(self) -> Result<RiotApi, SamiraError> {
+        if self.tokens.is_empty() {
+            return Err(SamiraError::InvalidToken);
+        }
+        #[cfg(feature = "ureq")]
+        let transport = self.transport.unwrap_or_else(|| {
+            Arc::new(UreqTransport::configured(
+                self.timeout,
+                self.user_agent.as_deref(),
+            ))
+        });
+        #[cfg(not(feature = "ureq"))]
+        let transport = self.transport.unwrap_or_else(default_transport);
+        let mut api = RiotApi {
+            keys: self
+                .tokens
+                .into_iter()
+                .map(|token| ApiKey::new(token, self.rate_limit_strategy))
+                .collect(),
+            key_rotation: self.key_rotation,
+            transport,
+            retry_policy: self.retry_policy,
+            ..RiotApi::new_unchecked("")
+        };
+        if let Some(base_url) = self.base_url {
+            api = api.with_base_url(&base_url);
+        }
+        if let Some(metrics) = self.metrics {
+            api = api.with_metrics(metrics);
+        }
+        if self.check_token {
+            for key in api.keys.iter() {
+                check_token(&RiotApi {
+                    keys: Arc::new([key.clone()]),
+                    transport: api.transport.clone(),
+                    base_url: api.base_url.clone(),
+                    ..RiotApi::new_unchecked("")
+                })?;
+            }
+        }
+        Ok(api)
+    }
 }
 
-fn get_summoner_by_puuid(
-    token: &str,
+fn env_token() -> Option<Token> {
+    TOKEN_VARIABLES
+        .iter()
+        .filter_map(|variable| env::var(variable).ok().map(Token))
+        .find(|token| !token.0.is_empty())
+}
+
+type SummonerLookup = fn(&RiotApi, &Platform, &str) -> Result<Option<Value>, SamiraError>;
+
+/// Tries the identifiers of the filter in its lookup order, returning the summoner with the identifier which found it
+/// and the identifiers set before which found none.
+/// Only a missing summoner falls through to the next identifier, any other error is returned immediately.
+fn resolve_summoner(
+    api: &RiotApi,
     platform: &Platform,
+    summoner: &SummonerFilter,
+) -> Result<(Value, SummonerField, Vec<SummonerField>), SamiraError> {
+    summoner.validate()?;
+    let mut missed = Vec::new();
+    for &field in summoner.lookup_order() {
+        let (value, lookup): (Option<String>, SummonerLookup) = match field {
+            SummonerField::AccountId => (
+                summoner.account_id.as_ref().map(ToString::to_string),
+                get_summoner_by_account,
+            ),
+            SummonerField::Name => (summoner.name.clone(), get_summoner_by_name),
+            SummonerField::Id => (summoner.id.as_ref().map(ToString::to_string), get_summoner),
+            SummonerField::Puuid => (
+                summoner.puuid.as_ref().map(ToString::to_string),
+                get_summoner_by_puuid,
+            ),
+            SummonerField::RiotId => (
+                summoner
+                    .riot_id
+                    .as_ref()
+                    .map(|(game_name, tag_line)| format!("{}#{}", game_name, tag_line)),
+                get_summoner_by_riot_id,
+            ),
+        };
+        if let Some(value) = value {
+            match lookup(api, platform, &value)? {
+                Some(result) => return Ok((result, field, missed)),
+                None => missed.push(field),
+            }
+        }
+    }
+    Err(SamiraError::NotFound)
+}
+
+endpoint! {
+    fn get_champion_rotations(platform: &Platform)
+        => "champion-v3.getChampionInfo", ChampionRotation, "/lol/platform/v3/champion-rotations";
+
+    fn get_summoner(platform: &Platform, encrypted_summoner_id: &str)
+        => "summoner-v4.getBySummonerId", Summoner, "/lol/summoner/v4/summoners/{encrypted_summoner_id}";
+
+    fn get_summoner_by_account(platform: &Platform, encrypted_account_id: &str)
+        => "summoner-v4.getByAccountId", Summoner, "/lol/summoner/v4/summoners/by-account/{encrypted_account_id}";
+
+    fn get_summoner_by_name(platform: &Platform, summoner_name: &str)
+        => "summoner-v4.getBySummonerName", Summoner, "/lol/summoner/v4/summoners/by-name/{summoner_name}";
+
+    fn get_summoner_by_puuid(platform: &Platform, puuid: &str)
+        => "summoner-v4.getByPUUID", Summoner, "/lol/summoner/v4/summoners/by-puuid/{puuid}";
+
+    fn get_match(region: &Region, match_id: &str)
+        => "match-v5.getMatch", Match, "/lol/match/v5/matches/{match_id}";
+
+    fn get_league_entries(platform: &Platform, puuid: &str)
+        => "league-v4.getLeagueEntriesByPUUID", League, "/lol/league/v4/entries/by-puuid/{puuid}";
+
+    fn get_account_by_riot_id(region: &Region, game_name: &str, tag_line: &str)
+        => "account-v1.getByRiotId", Summoner, "/riot/account/v1/accounts/by-riot-id/{game_name}/{tag_line}";
+
+    fn get_account_by_puuid(region: &Region, puuid: &str)
+        => "account-v1.getByPuuid", Summoner, "/riot/account/v1/accounts/by-puuid/{puuid}";
+
+    fn get_platform_data(platform: &Platform)
+        => "lol-status-v4.getPlatformData", Status, "/lol/status/v4/platform-data";
+}
+
+fn get_match_ids(
+    api: &RiotApi,
+    region: &Region,
     puuid: &str,
-) -> Result<Summoner, ureq::Error> {
+    filter: &MatchIdsFilter,
+) -> Result<Option<Value>, SamiraError> {
+    let query = filter.to_query();
+    let query: Vec<(&str, &str)> = query
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect();
+    // The match history grows with every game, so it expires like the summoners.
+    api.send(&Endpoint {
+        route: region.id(),
+        method: "match-v5.getMatchIdsByPUUID",
+        category: CacheCategory::Summoner,
+        url: format!(
+            "{server}/lol/match/v5/matches/by-puuid/{puuid}/ids{query}",
+            server = api.route_url(region),
+            puuid = encode_component(puuid),
+            query = encode_query(&query)
+        ),
+    })
+}
+
+fn get_apex_league(
+    api: &RiotApi,
+    platform: &Platform,
+    tier: Tier,
+    queue: Queue,
+) -> Result<Option<Value>, SamiraError> {
+    let (league, method) = match tier {
+        Tier::CHALLENGER => ("challengerleagues", "league-v4.getChallengerLeague"),
+        Tier::GRANDMASTER => ("grandmasterleagues", "league-v4.getGrandmasterLeague"),
+        Tier::MASTER => ("masterleagues", "league-v4.getMasterLeague"),
+        tier => {
+            return Err(SamiraError::InvalidFilter(format!(
+                "{} is not an apex tier",
+                tier.as_str()
+            )))
+        }
+    };
+    api.send(&Endpoint {
+        route: platform.id(),
+        method,
+        category: CacheCategory::League,
+        url: format!(
+            "{server}/lol/league/v4/{league}/by-queue/{queue}",
+            server = api.route_url(platform),
+            league = league,
+            queue = queue.as_str()
+        ),
+    })
+}
+
+/// Resolves the Riot ID ("game name#tag line") to a PUUID through account-v1, then looks up its summoner.
+fn get_summoner_by_riot_id(
+    api: &RiotApi,
+    platform: &Platform,
+    riot_id: &str,
+) -> Result<Option<Value>, SamiraError> {
+    let (game_name, tag_line) = riot_id.rsplit_once('#').unwrap_or((riot_id, ""));
+    // Accounts are served by every regional route but SEA.
+    let region = match platform.regional_route() {
+        Region::SEA => Region::ASIA,
+        region => region,
+    };
+    let account = get_account_by_riot_id(api, &region, game_name, tag_line)?;
+    match account
+        .as_ref()
+        .and_then(|account| account["puuid"].as_str())
+    {
+        Some(puuid) => get_summoner_by_puuid(api, platform, puuid),
+        None => Ok(None),
+    }
+}
+
+fn get_account_me(
+    api: &RiotApi,
+    region: &Region,
+    access_token: &str,
+) -> Result<Option<Value>, SamiraError> {
     let request = format!(
-        "{server}/lol/summoner/v4/summoners/by-puuid/{puuid}",
-        server = get_platform_url(platform),
-        puuid = puuid
+        "{server}/riot/account/v1/accounts/me",
+        server = api.route_url(region)
     );
-    let response: serde_json::Value = ureq::get(&request)
-        .set("X-Riot-Token", token)
-        .call()?
-        .into_json()?;
-
-    Ok(serde_json::from_value(response).unwrap())
+    api.fetch_authorized(
+        region.id(),
+        "account-v1.getByAccessToken",
+        &request,
+        access_token,
+    )
 }
 
-fn check_token(token: &str) -> Result<bool, ureq::Error> {
+fn create_tournament_codes(
+    api: &RiotApi,
+    tournament_id: i64,
+    count: usize,
+    parameters: &TournamentCodeParameters,
+) -> Result<Vec<String>, SamiraError> {
+    let region = Region::AMERICAS;
     let request = format!(
-        "{server}/lol/status/v4/platform-data",
-        server = get_platform_url(&Platform::NA1),
+        "{server}/lol/tournament/v5/codes{query}",
+        server = api.route_url(&region),
+        query = encode_query(&[
+            ("count", &count.to_string()),
+            ("tournamentId", &tournament_id.to_string())
+        ])
     );
-    ureq::get(&request).set("X-Riot-Token", token).call()?;
+    let body = serde_json::to_string(parameters)?;
+    // Each request creates new codes, so it is neither cached nor coalesced,
+    // and it is not retried after a server error which may have happened once the codes were created.
+    let (codes, _) = api.send_json(
+        region.id(),
+        "tournament-v5.createTournamentCode",
+        false,
+        |headers| api.transport.post(&request, headers, &body),
+    )?;
+    Ok(serde_json::from_value(codes.ok_or(SamiraError::NotFound)?)?)
+}
+
+fn check_token(api: &RiotApi) -> Result<(), SamiraError> {
+    get_platform_data(api, &Platform::NA1)?;
 
-    Ok(true)
+    Ok(())
 }
@@ -0,0 +1,20 @@
+use crate::{platform::*, region::*};
+
+/// A routing value of the Riot API hosts, either a [`Platform`] or a [`Region`].
+/// Platform endpoints (summoner, league, ...) and regional endpoints (match, account, ...) are served by different hosts.
+pub trait Route {
+    /// Returns the route as written in the Riot API hosts ("euw1", "europe", ...).
+    fn id(&self) -> &str;
+}
+
+impl Route for Platform {
+    fn id(&self) -> &str {
+        get_platform_id(self)
+    }
+}
+
+impl Route for Region {
+    fn id(&self) -> &str {
+        get_region_id(self)
+    }
+}
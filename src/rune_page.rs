@@ -0,0 +1,449 @@
+//! Builds rune pages checked against the rune trees of DDragon, ready to be imported in the League client.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SamiraError, models::rune_model::*};
+
+/// The stat shards of each row (offense, flex, defense), which DDragon does not list.
+pub const STAT_SHARDS: [[i32; 3]; 3] = [
+    // Adaptive force, attack speed, ability haste.
+    [5008, 5005, 5007],
+    // Adaptive force, move speed, scaling health.
+    [5008, 5010, 5001],
+    // Health, tenacity and slow resist, scaling health.
+    [5011, 5013, 5001],
+];
+
+/// A rune page, serialized in the format of the `/lol-perks/v1/pages` endpoint of the League client.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::rune_page::*;
+///
+/// let page = RunePage {
+///     name: "Samira".to_string(),
+///     primary_style_id: 8000,
+///     sub_style_id: 8100,
+///     selected_perk_ids: vec![8010, 9111, 9104, 8014, 8139, 8135, 5005, 5008, 5011],
+///     current: true,
+/// };
+/// let json = serde_json::to_value(&page).unwrap();
+/// assert_eq!(json["primaryStyleId"], 8000);
+/// assert_eq!(json["selectedPerkIds"][0], 8010);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RunePage {
+    pub name: String,
+    /// The id of the primary rune tree.
+    pub primary_style_id: i32,
+    /// The id of the secondary rune tree.
+    pub sub_style_id: i32,
+    /// The keystone, the three primary runes, the two secondary runes, then the three stat shards.
+    pub selected_perk_ids: Vec<i32>,
+    /// True to select the page once imported.
+    pub current: bool,
+}
+
+impl RunePage {
+    /// Returns every rule of the rune trees the page breaks, none if it is valid, e.g. to check an imported page.
+    /// The perks are expected in the order of the League client: the 4 primary runes, the 2 secondary runes,
+    /// then the offense, flex and defense shards.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::rune_model::*, rune_page::*};
+    ///
+    /// let trees: Vec<Rune> = serde_json::from_str(r#"[
+    ///     {"id": 8000, "key": "Precision", "icon": "", "name": "Precision", "slots": [
+    ///         {"runes": [{"id": 8010, "key": "Conqueror", "icon": "", "name": "Conqueror", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 9111, "key": "Triumph", "icon": "", "name": "Triumph", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 9104, "key": "LegendAlacrity", "icon": "", "name": "Legend: Alacrity", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 8014, "key": "CoupDeGrace", "icon": "", "name": "Coup de Grace", "shortDesc": "", "longDesc": ""}]}
+    ///     ]},
+    ///     {"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": [
+    ///         {"runes": [{"id": 8112, "key": "Electrocute", "icon": "", "name": "Electrocute", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 8139, "key": "TasteOfBlood", "icon": "", "name": "Taste of Blood", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 8136, "key": "ZombieWard", "icon": "", "name": "Zombie Ward", "shortDesc": "", "longDesc": ""}]},
+    ///         {"runes": [{"id": 8135, "key": "TreasureHunter", "icon": "", "name": "Treasure Hunter", "shortDesc": "", "longDesc": ""}]}
+    ///     ]}
+    /// ]"#).unwrap();
+    ///
+    /// let mut page = RunePage {
+    ///     primary_style_id: 8000,
+    ///     sub_style_id: 8100,
+    ///     selected_perk_ids: vec![8010, 9111, 9104, 8014, 8139, 8135, 5005, 5008, 5011],
+    ///     ..Default::default()
+    /// };
+    /// assert!(page.violations(&trees).is_empty());
+    ///
+    /// page.selected_perk_ids = vec![8010, 9111, 9111, 8014, 8112, 8135, 5005, 5008, 5007];
+    /// assert_eq!(page.violations(&trees), [
+    ///     RunePageViolation::DuplicateSlot {tree_id: 8000, slot: 1},
+    ///     RunePageViolation::MissingSlot {tree_id: 8000, slot: 2},
+    ///     RunePageViolation::SecondaryKeystone {rune_id: 8112},
+    ///     RunePageViolation::InvalidShard {row: 2, shard_id: 5007},
+    /// ]);
+    /// ```
+    pub fn violations(&self, trees: &[Rune]) -> Vec<RunePageViolation> {
+        let perks = &self.selected_perk_ids;
+        if perks.len() != 9 {
+            return vec![RunePageViolation::PerkCount { found: perks.len() }];
+        }
+        check(
+            trees,
+            (self.primary_style_id, &perks[..4]),
+            (self.sub_style_id, &perks[4..6]),
+            &perks[6..],
+        )
+    }
+
+    /// Checks the page against the rune trees, see [`RunePage::violations`].
+    /// It fails with [`SamiraError::InvalidRunePage`] listing every rule the page breaks.
+    pub fn validate(&self, trees: &[Rune]) -> Result<(), SamiraError> {
+        let violations = self.violations(trees);
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(SamiraError::InvalidRunePage(join(&violations))),
+        }
+    }
+}
+
+/// Builds a [`RunePage`], checking it follows the rules of the rune trees:
+/// - the primary tree has a keystone and one rune of each of its other slots,
+/// - the secondary tree is another tree and has two runes of two different slots, keystones excluded,
+/// - each stat shard belongs to its row of [`STAT_SHARDS`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{error::*, models::rune_model::*, rune_page::*};
+///
+/// let trees: Vec<Rune> = serde_json::from_str(r#"[
+///     {"id": 8000, "key": "Precision", "icon": "", "name": "Precision", "slots": [
+///         {"runes": [{"id": 8010, "key": "Conqueror", "icon": "", "name": "Conqueror", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 9111, "key": "Triumph", "icon": "", "name": "Triumph", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 9104, "key": "LegendAlacrity", "icon": "", "name": "Legend: Alacrity", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 8014, "key": "CoupDeGrace", "icon": "", "name": "Coup de Grace", "shortDesc": "", "longDesc": ""}]}
+///     ]},
+///     {"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": [
+///         {"runes": [{"id": 8112, "key": "Electrocute", "icon": "", "name": "Electrocute", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 8139, "key": "TasteOfBlood", "icon": "", "name": "Taste of Blood", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 8136, "key": "ZombieWard", "icon": "", "name": "Zombie Ward", "shortDesc": "", "longDesc": ""}]},
+///         {"runes": [{"id": 8135, "key": "TreasureHunter", "icon": "", "name": "Treasure Hunter", "shortDesc": "", "longDesc": ""}]}
+///     ]}
+/// ]"#).unwrap();
+///
+/// let page = RunePageBuilder::new(&trees)
+///     .name("Samira")
+///     .primary(8000, &[8010, 9111, 9104, 8014])
+///     .secondary(8100, &[8139, 8135])
+///     .shards([5005, 5008, 5011])
+///     .build()
+///     .unwrap();
+/// assert_eq!(page.selected_perk_ids, vec![8010, 9111, 9104, 8014, 8139, 8135, 5005, 5008, 5011]);
+///
+/// let page = RunePageBuilder::new(&trees)
+///     .primary(8000, &[8010, 9111, 9104, 8014])
+///     .secondary(8100, &[8112, 8135])
+///     .build();
+/// assert!(matches!(page, Err(SamiraError::InvalidRunePage(_))));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RunePageBuilder<'a> {
+    trees: &'a [Rune],
+    name: String,
+    primary: Option<(i32, Vec<i32>)>,
+    secondary: Option<(i32, Vec<i32>)>,
+    shards: [i32; 3],
+    current: bool,
+}
+
+impl<'a> RunePageBuilder<'a> {
+    /// Creates a builder checking the pages against the given rune trees, e.g. those of [`UtilsApi::get_all_runes`](crate::utils_api::UtilsApi::get_all_runes).
+    /// The page is named "samira", selected once imported and has the first shard of each row until they are set.
+    pub fn new(trees: &'a [Rune]) -> RunePageBuilder<'a> {
+        RunePageBuilder {
+            trees,
+            name: "samira".to_string(),
+            primary: None,
+            secondary: None,
+            shards: [STAT_SHARDS[0][0], STAT_SHARDS[1][0], STAT_SHARDS[2][0]],
+            current: true,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> RunePageBuilder<'a> {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Sets the primary tree and its runes: a keystone and one rune of each other slot, in any order.
+    pub fn primary(mut self, tree_id: i32, rune_ids: &[i32]) -> RunePageBuilder<'a> {
+        self.primary = Some((tree_id, rune_ids.to_vec()));
+        self
+    }
+
+    /// Sets the secondary tree and its runes: two runes of two different slots, in any order.
+    pub fn secondary(mut self, tree_id: i32, rune_ids: &[i32]) -> RunePageBuilder<'a> {
+        self.secondary = Some((tree_id, rune_ids.to_vec()));
+        self
+    }
+
+    /// Sets the stat shards of the offense, flex and defense rows.
+    pub fn shards(mut self, shard_ids: [i32; 3]) -> RunePageBuilder<'a> {
+        self.shards = shard_ids;
+        self
+    }
+
+    /// Sets whether the page is selected once imported, true by default.
+    pub fn current(mut self, current: bool) -> RunePageBuilder<'a> {
+        self.current = current;
+        self
+    }
+
+    /// Checks the page and builds it, with its runes sorted by slot.
+    /// It fails with [`SamiraError::InvalidRunePage`] listing every rule of the rune trees the page breaks.
+    pub fn build(self) -> Result<RunePage, SamiraError> {
+        let invalid = |message: String| Err(SamiraError::InvalidRunePage(message));
+        let (primary_id, primary_runes) = match &self.primary {
+            Some(primary) => primary,
+            None => return invalid("no primary tree".to_string()),
+        };
+        let (secondary_id, secondary_runes) = match &self.secondary {
+            Some(secondary) => secondary,
+            None => return invalid("no secondary tree".to_string()),
+        };
+        let violations = check(
+            self.trees,
+            (*primary_id, primary_runes),
+            (*secondary_id, secondary_runes),
+            &self.shards,
+        );
+        if !violations.is_empty() {
+            return invalid(join(&violations));
+        }
+
+        let primary = self.tree(*primary_id)?;
+        let secondary = self.tree(*secondary_id)?;
+        let selected_perk_ids = sorted_by_slot(primary, primary_runes)
+            .chain(sorted_by_slot(secondary, secondary_runes))
+            .chain(self.shards)
+            .collect();
+        Ok(RunePage {
+            name: self.name,
+            primary_style_id: *primary_id,
+            sub_style_id: *secondary_id,
+            selected_perk_ids,
+            current: self.current,
+        })
+    }
+
+    fn tree(&self, tree_id: i32) -> Result<&'a Rune, SamiraError> {
+        self.trees
+            .iter()
+            .find(|tree| tree.id == tree_id)
+            .ok_or_else(|| SamiraError::InvalidRunePage(format!("unknown tree {}", tree_id)))
+    }
+}
+
+/// A rule of the rune trees a rune page breaks, see [`RunePage::violations`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RunePageViolation {
+    /// The page does not select the 9 perks: 4 primary runes, 2 secondary runes and 3 stat shards.
+    PerkCount { found: usize },
+    /// The tree is not one of the rune trees.
+    UnknownTree { tree_id: i32 },
+    /// The same tree is both the primary and the secondary tree.
+    SameTree { tree_id: i32 },
+    /// The rune does not belong to the tree it was selected in.
+    NotInTree { rune_id: i32, tree_id: i32 },
+    /// The tree has the wrong number of runes selected.
+    RuneCount {
+        tree_id: i32,
+        expected: usize,
+        found: usize,
+    },
+    /// No rune of this slot of the primary tree is selected, the slot 0 being the keystones.
+    MissingSlot { tree_id: i32, slot: usize },
+    /// Several runes of the same slot of the tree are selected.
+    DuplicateSlot { tree_id: i32, slot: usize },
+    /// The keystones of the secondary tree cannot be selected.
+    SecondaryKeystone { rune_id: i32 },
+    /// The stat shard does not belong to its row of [`STAT_SHARDS`], the offense row being 0.
+    InvalidShard { row: usize, shard_id: i32 },
+}
+
+impl fmt::Display for RunePageViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunePageViolation::PerkCount { found } => {
+                write!(f, "{} perks are selected instead of 9", found)
+            }
+            RunePageViolation::UnknownTree { tree_id } => write!(f, "unknown tree {}", tree_id),
+            RunePageViolation::SameTree { tree_id } => {
+                write!(f, "tree {} is both primary and secondary", tree_id)
+            }
+            RunePageViolation::NotInTree { rune_id, tree_id } => {
+                write!(f, "rune {} is not in tree {}", rune_id, tree_id)
+            }
+            RunePageViolation::RuneCount {
+                tree_id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "tree {} needs {} runes but has {}",
+                tree_id, expected, found
+            ),
+            RunePageViolation::MissingSlot { tree_id, slot: 0 } => {
+                write!(f, "the primary tree {} has no keystone", tree_id)
+            }
+            RunePageViolation::MissingSlot { tree_id, slot } => {
+                write!(
+                    f,
+                    "the primary tree {} has no rune of slot {}",
+                    tree_id, slot
+                )
+            }
+            RunePageViolation::DuplicateSlot { tree_id, slot } => {
+                write!(f, "tree {} has several runes of slot {}", tree_id, slot)
+            }
+            RunePageViolation::SecondaryKeystone { rune_id } => {
+                write!(f, "keystone {} cannot be a secondary rune", rune_id)
+            }
+            RunePageViolation::InvalidShard { row, shard_id } => {
+                write!(f, "shard {} does not belong to row {}", shard_id, row)
+            }
+        }
+    }
+}
+
+/// Checks a selection of runes against the rune trees, returning every rule it breaks.
+fn check(
+    trees: &[Rune],
+    (primary_id, primary_runes): (i32, &[i32]),
+    (secondary_id, secondary_runes): (i32, &[i32]),
+    shards: &[i32],
+) -> Vec<RunePageViolation> {
+    let mut violations = Vec::new();
+    let tree = |tree_id: i32| trees.iter().find(|tree| tree.id == tree_id);
+    if primary_id == secondary_id {
+        violations.push(RunePageViolation::SameTree {
+            tree_id: primary_id,
+        });
+    }
+
+    match tree(primary_id) {
+        Some(primary) => {
+            let slots = check_slots(primary, primary_runes, &mut violations);
+            if primary_runes.len() != primary.slots.len() {
+                violations.push(RunePageViolation::RuneCount {
+                    tree_id: primary_id,
+                    expected: primary.slots.len(),
+                    found: primary_runes.len(),
+                });
+            }
+            for slot in (0..primary.slots.len()).filter(|slot| !slots.contains(slot)) {
+                violations.push(RunePageViolation::MissingSlot {
+                    tree_id: primary_id,
+                    slot,
+                });
+            }
+        }
+        None => violations.push(RunePageViolation::UnknownTree {
+            tree_id: primary_id,
+        }),
+    }
+
+    match tree(secondary_id) {
+        Some(secondary) => {
+            check_slots(secondary, secondary_runes, &mut violations);
+            if secondary_runes.len() != 2 {
+                violations.push(RunePageViolation::RuneCount {
+                    tree_id: secondary_id,
+                    expected: 2,
+                    found: secondary_runes.len(),
+                });
+            }
+            for rune_id in secondary_runes {
+                if slot_of(secondary, *rune_id) == Some(0) {
+                    violations.push(RunePageViolation::SecondaryKeystone { rune_id: *rune_id });
+                }
+            }
+        }
+        None => violations.push(RunePageViolation::UnknownTree {
+            tree_id: secondary_id,
+        }),
+    }
+
+    for (row, (shard, choices)) in shards.iter().zip(STAT_SHARDS).enumerate() {
+        if !choices.contains(shard) {
+            violations.push(RunePageViolation::InvalidShard {
+                row,
+                shard_id: *shard,
+            });
+        }
+    }
+    violations
+}
+
+/// Returns the slots of the given runes in a tree, reporting the runes not in the tree and the slots selected twice.
+fn check_slots(
+    tree: &Rune,
+    rune_ids: &[i32],
+    violations: &mut Vec<RunePageViolation>,
+) -> Vec<usize> {
+    let mut slots = Vec::new();
+    for rune_id in rune_ids {
+        match slot_of(tree, *rune_id) {
+            Some(slot) if slots.contains(&slot) => {
+                let duplicate = RunePageViolation::DuplicateSlot {
+                    tree_id: tree.id,
+                    slot,
+                };
+                if !violations.contains(&duplicate) {
+                    violations.push(duplicate);
+                }
+            }
+            Some(slot) => slots.push(slot),
+            None => violations.push(RunePageViolation::NotInTree {
+                rune_id: *rune_id,
+                tree_id: tree.id,
+            }),
+        }
+    }
+    slots
+}
+
+/// Joins violations into the message of a [`SamiraError::InvalidRunePage`].
+fn join(violations: &[RunePageViolation]) -> String {
+    violations
+        .iter()
+        .map(RunePageViolation::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Returns the slot of a rune in a tree, None if the rune does not belong to it.
+fn slot_of(tree: &Rune, rune_id: i32) -> Option<usize> {
+    tree.slots
+        .iter()
+        .position(|slot| slot.runes.iter().any(|rune| rune.id == rune_id))
+}
+
+fn sorted_by_slot(tree: &Rune, rune_ids: &[i32]) -> impl Iterator<Item = i32> {
+    let mut rune_ids = rune_ids.to_vec();
+    rune_ids.sort_by_key(|rune_id| slot_of(tree, *rune_id));
+    rune_ids.into_iter()
+}
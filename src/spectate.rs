@@ -0,0 +1,204 @@
+//! Spectates a game in progress with the game client installed on this machine, e.g. behind a "Watch game" button.
+
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::process::Command;
+
+use crate::{
+    models::spectator_model::CurrentGameInfo,
+    platform::{get_platform_id, Platform},
+};
+
+/// The server streaming the games of a platform to the spectators.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SpectatorServer {
+    pub host: String,
+    pub port: u16,
+}
+
+impl SpectatorServer {
+    /// Returns the spectator server of a platform, as used by the game client.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{platform::*, spectate::*};
+    ///
+    /// let server = SpectatorServer::of_platform(&Platform::EUW1);
+    /// assert_eq!(server.to_string(), "spectator.euw1.lol.pvp.net:8080");
+    /// ```
+    pub fn of_platform(platform: &Platform) -> SpectatorServer {
+        let (host, port) = match platform {
+            Platform::EUN1 => ("spectator.eu.lol.riotgames.com", 8088),
+            Platform::BR1 => ("spectator.br.lol.riotgames.com", 80),
+            Platform::JP1 => ("spectator.jp1.lol.riotgames.com", 80),
+            Platform::KR => ("spectator.kr.lol.riotgames.com", 80),
+            Platform::LA1 => ("spectator.la1.lol.riotgames.com", 80),
+            Platform::LA2 => ("spectator.la2.lol.riotgames.com", 80),
+            Platform::OC1 => ("spectator.oc1.lol.riotgames.com", 80),
+            Platform::RU => ("spectator.ru.lol.riotgames.com", 80),
+            Platform::TR1 => ("spectator.tr.lol.riotgames.com", 80),
+            // The other platforms follow the naming of the latest servers.
+            platform => {
+                return SpectatorServer {
+                    host: format!("spectator.{}.lol.pvp.net", get_platform_id(platform)),
+                    port: 8080,
+                }
+            }
+        };
+        SpectatorServer {
+            host: host.to_string(),
+            port,
+        }
+    }
+}
+
+impl fmt::Display for SpectatorServer {
+    /// Writes the address of the server ("host:port").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.host, self.port)
+    }
+}
+
+/// The operating systems the game client runs on, which each launch it their own way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClientOs {
+    Windows,
+    MacOs,
+}
+
+impl ClientOs {
+    /// Returns the operating system samira was compiled for, Windows on the systems the client does not run on.
+    pub fn current() -> ClientOs {
+        match cfg!(target_os = "macos") {
+            true => ClientOs::MacOs,
+            false => ClientOs::Windows,
+        }
+    }
+
+    /// Returns the directory of the game in the default install directory of the client.
+    pub fn default_game_directory(&self) -> &'static str {
+        match self {
+            ClientOs::Windows => r"C:\Riot Games\League of Legends\Game",
+            ClientOs::MacOs => "/Applications/League of Legends.app/Contents/LoL/Game",
+        }
+    }
+
+    /// Returns the executable of the game, relative to the game directory.
+    fn executable(&self) -> &'static str {
+        match self {
+            ClientOs::Windows => "League of Legends.exe",
+            ClientOs::MacOs => "./LeagueofLegends.app/Contents/MacOS/LeagueofLegends",
+        }
+    }
+}
+
+/// Everything needed to spectate a game in progress.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{models::spectator_model::*, spectate::*};
+///
+/// let game: CurrentGameInfo = serde_json::from_str(r#"{
+///     "gameId": 6000000000, "gameType": "MATCHED_GAME", "gameStartTime": 1700000000000, "mapId": 11,
+///     "gameLength": 300, "platformId": "EUW1", "gameMode": "CLASSIC", "observers": {"encryptionKey": "KEY"},
+///     "participants": []
+/// }"#).unwrap();
+/// let spectate = Spectate::from_game(&game);
+/// assert_eq!(spectate.argument(), "spectator spectator.euw1.lol.pvp.net:8080 KEY 6000000000 EUW1");
+/// assert_eq!(
+///     spectate.command_line_for(ClientOs::Windows, r"D:\League of Legends\Game"),
+///     r#"cd /d "D:\League of Legends\Game" && "League of Legends.exe" "spectator spectator.euw1.lol.pvp.net:8080 KEY 6000000000 EUW1" "-UseRads" "-GameBaseDir=..""#,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Spectate {
+    pub server: SpectatorServer,
+    /// The key decrypting the game.
+    pub encryption_key: String,
+    pub game_id: i64,
+    /// The platform of the game, as written by spectator-v5 ("EUW1", "NA1", ...).
+    pub platform_id: String,
+}
+
+impl Spectate {
+    /// Gathers what is needed to spectate a game, from its spectator-v5 info.
+    /// The platforms unknown by samira are streamed from a server named after their id.
+    pub fn from_game(game: &CurrentGameInfo) -> Spectate {
+        let platform = game
+            .platform_id
+            .parse()
+            .unwrap_or_else(|_| Platform::Custom(game.platform_id.to_lowercase()));
+        Spectate {
+            server: SpectatorServer::of_platform(&platform),
+            encryption_key: game.observers.encryption_key.clone(),
+            game_id: game.game_id,
+            platform_id: game.platform_id.clone(),
+        }
+    }
+
+    /// Returns the argument telling the game to spectate ("spectator <host:port> <key> <game id> <platform id>").
+    pub fn argument(&self) -> String {
+        format!(
+            "spectator {} {} {} {}",
+            self.server, self.encryption_key, self.game_id, self.platform_id
+        )
+    }
+
+    /// Returns the arguments of the game executable.
+    fn arguments(&self) -> [String; 3] {
+        [
+            self.argument(),
+            "-UseRads".to_string(),
+            "-GameBaseDir=..".to_string(),
+        ]
+    }
+
+    /// Returns the shell command spectating the game with the client installed in its default directory,
+    /// for the operating system samira was compiled for.
+    pub fn command_line(&self) -> String {
+        let os = ClientOs::current();
+        self.command_line_for(os, os.default_game_directory())
+    }
+
+    /// Returns the shell command spectating the game with the game installed in `game_directory`,
+    /// a command prompt command on Windows and a shell command on macOS.
+    pub fn command_line_for(&self, os: ClientOs, game_directory: &str) -> String {
+        let arguments: Vec<String> = self
+            .arguments()
+            .iter()
+            .map(|argument| format!("\"{}\"", argument))
+            .collect();
+        match os {
+            ClientOs::Windows => format!(
+                "cd /d \"{}\" && \"{}\" {}",
+                game_directory,
+                os.executable(),
+                arguments.join(" ")
+            ),
+            ClientOs::MacOs => format!(
+                "cd \"{}\" && riot_launched=true \"{}\" {}",
+                game_directory,
+                os.executable(),
+                arguments.join(" ")
+            ),
+        }
+    }
+
+    /// Returns the process spectating the game with the game installed in `game_directory`, ready to be spawned.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn command(&self, game_directory: &str) -> Command {
+        let os = ClientOs::current();
+        let mut command = Command::new(std::path::Path::new(game_directory).join(os.executable()));
+        command.current_dir(game_directory).args(self.arguments());
+        if os == ClientOs::MacOs {
+            command.env("riot_launched", "true");
+        }
+        command
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{self, Value};
+
+use super::{storage_error, MatchStore};
+use crate::{error::SamiraError, ids::MatchId};
+
+/// A store keeping each match in its own file, named after its id ("EUW1_6000000000.json").
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    directory: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a FileStore storing its files in the given directory, creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::env;
+    /// use samira::{ids::*, storage::{file_store::*, *}};
+    ///
+    /// let directory = env::temp_dir().join("samira-doc-store");
+    /// # let _ = std::fs::remove_dir_all(&directory);
+    /// let store = FileStore::new(&directory).unwrap();
+    /// let match_id = MatchId::from("EUW1_6000000000");
+    /// assert_eq!(store.insert_raw(&match_id, &serde_json::json!({"info": {}})).unwrap(), true);
+    /// assert_eq!(store.insert_raw(&match_id, &serde_json::json!({"info": {}})).unwrap(), false);
+    ///
+    /// let match_ids = [match_id, MatchId::from("EUW1_6000000001")];
+    /// assert_eq!(store.missing(&match_ids).unwrap(), vec![MatchId::from("EUW1_6000000001")]);
+    /// ```
+    pub fn new(directory: impl AsRef<Path>) -> Result<FileStore, SamiraError> {
+        fs::create_dir_all(directory.as_ref()).map_err(storage_error)?;
+        Ok(FileStore {
+            directory: directory.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Returns the file of a match, failing on the ids which could escape the directory.
+    fn path(&self, match_id: &MatchId) -> Result<PathBuf, SamiraError> {
+        let valid = !match_id.as_str().is_empty()
+            && match_id
+                .as_str()
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        match valid {
+            true => Ok(self.directory.join(format!("{}.json", match_id))),
+            false => Err(SamiraError::Storage(format!(
+                "invalid match id {:?}",
+                match_id.as_str()
+            ))),
+        }
+    }
+}
+
+impl MatchStore for FileStore {
+    fn contains(&self, match_id: &MatchId) -> Result<bool, SamiraError> {
+        Ok(self.path(match_id)?.is_file())
+    }
+
+    fn get_raw(&self, match_id: &MatchId) -> Result<Option<Value>, SamiraError> {
+        match fs::read(self.path(match_id)?) {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(storage_error(error)),
+        }
+    }
+
+    fn insert_raw(&self, match_id: &MatchId, game: &Value) -> Result<bool, SamiraError> {
+        let path = self.path(match_id)?;
+        if path.is_file() {
+            return Ok(false);
+        }
+        let temporary = path.with_extension("tmp");
+        // Written then renamed so a concurrent reader never sees a partial match.
+        let written = serde_json::to_vec(game)
+            .map_err(io::Error::from)
+            .and_then(|content| fs::write(&temporary, content))
+            .and_then(|_| fs::rename(&temporary, &path));
+        if let Err(error) = written {
+            let _ = fs::remove_file(&temporary);
+            return Err(storage_error(error));
+        }
+        Ok(true)
+    }
+
+    fn remove(&self, match_id: &MatchId) -> Result<bool, SamiraError> {
+        match fs::remove_file(self.path(match_id)?) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(storage_error(error)),
+        }
+    }
+
+    fn match_ids(&self) -> Result<Vec<MatchId>, SamiraError> {
+        let mut match_ids = Vec::new();
+        for entry in fs::read_dir(&self.directory).map_err(storage_error)? {
+            let path = entry.map_err(storage_error)?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    match_ids.push(MatchId::from(stem));
+                }
+            }
+        }
+        Ok(match_ids)
+    }
+}
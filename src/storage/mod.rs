@@ -0,0 +1,57 @@
+//! Persists matches keyed by their id, so crawlers only download the matches they do not have yet.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_store;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_store;
+
+use std::fmt::{self, Debug};
+
+use serde_json::Value;
+
+use crate::{error::SamiraError, ids::MatchId, models::match_model::Match};
+
+/// A store of matches, keeping the match-v5 JSON so the fields samira does not model are not lost.
+/// Implementations must be thread safe as a store can be shared by several crawlers.
+pub trait MatchStore: Debug + Send + Sync {
+    /// Returns true if the match is stored.
+    fn contains(&self, match_id: &MatchId) -> Result<bool, SamiraError>;
+    /// Returns the JSON of a stored match, or None if it is missing.
+    fn get_raw(&self, match_id: &MatchId) -> Result<Option<Value>, SamiraError>;
+    /// Stores the JSON of a match, unless it is already stored.
+    /// Returns true if the match was added.
+    fn insert_raw(&self, match_id: &MatchId, game: &Value) -> Result<bool, SamiraError>;
+    /// Removes a match, returning true if it was stored.
+    fn remove(&self, match_id: &MatchId) -> Result<bool, SamiraError>;
+    /// Returns the ids of the stored matches, in no particular order.
+    fn match_ids(&self) -> Result<Vec<MatchId>, SamiraError>;
+
+    /// Returns a stored match.
+    fn get(&self, match_id: &MatchId) -> Result<Option<Match>, SamiraError> {
+        match self.get_raw(match_id)? {
+            Some(game) => Ok(Some(serde_json::from_value(game)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores a match under the id of its metadata, unless it is already stored.
+    /// Returns true if the match was added.
+    fn insert(&self, game: &Match) -> Result<bool, SamiraError> {
+        self.insert_raw(&game.metadata.match_id, &serde_json::to_value(game)?)
+    }
+
+    /// Returns the ids which are not stored yet, in their order, e.g. to only download the new matches of a player.
+    fn missing(&self, match_ids: &[MatchId]) -> Result<Vec<MatchId>, SamiraError> {
+        let mut missing = Vec::new();
+        for match_id in match_ids {
+            if !self.contains(match_id)? {
+                missing.push(match_id.clone());
+            }
+        }
+        Ok(missing)
+    }
+}
+
+pub(crate) fn storage_error(error: impl fmt::Display) -> SamiraError {
+    SamiraError::Storage(error.to_string())
+}
@@ -0,0 +1,123 @@
+use std::{path::Path, sync::Mutex};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::{self, Value};
+
+use super::{storage_error, MatchStore};
+use crate::{error::SamiraError, ids::MatchId};
+
+/// A store keeping the matches in a table of a SQLite database, suited to large crawls.
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    /// Opens or creates a SqliteStore in the given database file.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::env;
+    /// use samira::{ids::*, storage::{sqlite_store::*, *}};
+    ///
+    /// let store = SqliteStore::open(env::temp_dir().join("samira-doc-store.sqlite")).unwrap();
+    /// let match_id = MatchId::from("EUW1_6000000000");
+    /// store.insert_raw(&match_id, &serde_json::json!({"info": {"gameDuration": 1800}})).unwrap();
+    /// assert_eq!(store.contains(&match_id).unwrap(), true);
+    /// assert_eq!(store.get_raw(&match_id).unwrap().unwrap()["info"]["gameDuration"], 1800);
+    /// ```
+    pub fn open(path: impl AsRef<Path>) -> Result<SqliteStore, SamiraError> {
+        SqliteStore::from_connection(Connection::open(path).map_err(storage_error)?)
+    }
+
+    /// Creates a SqliteStore in memory, lost when it is dropped.
+    pub fn in_memory() -> Result<SqliteStore, SamiraError> {
+        SqliteStore::from_connection(Connection::open_in_memory().map_err(storage_error)?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<SqliteStore, SamiraError> {
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS matches (match_id TEXT PRIMARY KEY, game TEXT NOT NULL)",
+                [],
+            )
+            .map_err(storage_error)?;
+        Ok(SqliteStore {
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+impl MatchStore for SqliteStore {
+    fn contains(&self, match_id: &MatchId) -> Result<bool, SamiraError> {
+        self.connection
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM matches WHERE match_id = ?1)",
+                params![match_id.as_str()],
+                |row| row.get(0),
+            )
+            .map_err(storage_error)
+    }
+
+    fn get_raw(&self, match_id: &MatchId) -> Result<Option<Value>, SamiraError> {
+        let game: Option<String> = self
+            .connection
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT game FROM matches WHERE match_id = ?1",
+                params![match_id.as_str()],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(storage_error)?;
+        match game {
+            Some(game) => Ok(Some(serde_json::from_str(&game)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn insert_raw(&self, match_id: &MatchId, game: &Value) -> Result<bool, SamiraError> {
+        let inserted = self
+            .connection
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT OR IGNORE INTO matches (match_id, game) VALUES (?1, ?2)",
+                params![match_id.as_str(), serde_json::to_string(game)?],
+            )
+            .map_err(storage_error)?;
+        Ok(inserted == 1)
+    }
+
+    fn remove(&self, match_id: &MatchId) -> Result<bool, SamiraError> {
+        let removed = self
+            .connection
+            .lock()
+            .unwrap()
+            .execute(
+                "DELETE FROM matches WHERE match_id = ?1",
+                params![match_id.as_str()],
+            )
+            .map_err(storage_error)?;
+        Ok(removed == 1)
+    }
+
+    fn match_ids(&self) -> Result<Vec<MatchId>, SamiraError> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection
+            .prepare("SELECT match_id FROM matches")
+            .map_err(storage_error)?;
+        let match_ids = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(storage_error)?
+            .map(|match_id| match_id.map(MatchId::from).map_err(storage_error))
+            .collect();
+        match_ids
+    }
+}
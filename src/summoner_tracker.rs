@@ -0,0 +1,278 @@
+//! Follows a set of players, fetching them again on a schedule to report what changed since the previous fetch.
+
+use std::{collections::HashMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cancellation::CancellationToken,
+    error::SamiraError,
+    filters::summoner_filter::SummonerFilter,
+    ids::Puuid,
+    models::{league_model::LeagueEntry, summoner_model::Summoner},
+    platform::Platform,
+    rank::Rank,
+    region::Region,
+    riot_api::RiotApi,
+};
+
+/// The state of a tracked player at its latest fetch.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedSummoner {
+    pub summoner: Summoner,
+    /// The Riot ID of the player ("Name#TAG"), None if the account has none.
+    pub riot_id: Option<String>,
+    /// The ranked entries of the player, one per queue the player is ranked in.
+    pub entries: Vec<LeagueEntry>,
+}
+
+impl TrackedSummoner {
+    /// Returns the rank of the player in a queue type ("RANKED_SOLO_5x5", ...), None if unranked.
+    pub fn rank(&self, queue_type: &str) -> Option<Rank> {
+        self.entries
+            .iter()
+            .find(|entry| entry.queue_type == queue_type)
+            .and_then(LeagueEntry::rank)
+    }
+}
+
+/// A change of a tracked player between two fetches.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SummonerChange {
+    /// The player changed its Riot ID.
+    RiotIdChanged {
+        puuid: Puuid,
+        previous: Option<String>,
+        current: Option<String>,
+    },
+    /// The player gained one or more levels.
+    LevelUp {
+        puuid: Puuid,
+        previous: i64,
+        current: i64,
+    },
+    /// The player changed its profile icon.
+    IconChanged {
+        puuid: Puuid,
+        previous: i32,
+        current: i32,
+    },
+    /// The rank of the player changed in a queue, LP included, None when unranked.
+    RankChanged {
+        puuid: Puuid,
+        /// The queue type of the rank ("RANKED_SOLO_5x5", ...).
+        queue_type: String,
+        previous: Option<Rank>,
+        current: Option<Rank>,
+    },
+}
+
+impl SummonerChange {
+    /// Returns the player who changed.
+    pub fn puuid(&self) -> &Puuid {
+        match self {
+            SummonerChange::RiotIdChanged { puuid, .. }
+            | SummonerChange::LevelUp { puuid, .. }
+            | SummonerChange::IconChanged { puuid, .. }
+            | SummonerChange::RankChanged { puuid, .. } => puuid,
+        }
+    }
+}
+
+/// The outcome of a [`SummonerTracker::poll`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct PollReport {
+    /// The changes of the players, grouped by player.
+    pub changes: Vec<SummonerChange>,
+    /// The players who could not be fetched, their previous state is kept.
+    pub failed: Vec<(Puuid, SamiraError)>,
+}
+
+/// Fetches the tracked players of a platform again at each poll and reports their changes:
+/// Riot ID, level, profile icon and ranks.
+/// The first fetch of a player only records its state.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{platform::*, riot_api::*, summoner_tracker::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new()
+///     .with_json(
+///         "*/lol/summoner/v4/summoners/by-puuid/PUUID",
+///         r#"{"accountId": "", "profileIconId": 1, "revisionDate": 0, "name": "", "id": "", "puuid": "PUUID", "summonerLevel": 30}"#,
+///     )
+///     .with_json("*/riot/account/v1/accounts/by-puuid/PUUID", r#"{"puuid": "PUUID", "gameName": "RqndomHax", "tagLine": "EUW"}"#)
+///     .with_json("*/lol/league/v4/entries/by-puuid/PUUID", "[]");
+/// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+/// let mut tracker = SummonerTracker::new(api, Platform::EUW1);
+/// tracker.track("PUUID".into());
+///
+/// let report = tracker.poll();
+/// assert_eq!(report.changes, vec![]);
+/// assert_eq!(tracker.state(&"PUUID".into()).unwrap().riot_id.as_deref(), Some("RqndomHax#EUW"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SummonerTracker {
+    api: RiotApi,
+    platform: Platform,
+    max_concurrency: usize,
+    summoners: HashMap<Puuid, Option<TrackedSummoner>>,
+}
+
+impl SummonerTracker {
+    /// Creates a tracker of the players of a platform, fetching up to 4 players at once.
+    /// The players are fetched without the response cache of the client, so each poll sees their latest state
+    /// whatever the interval between the polls.
+    pub fn new(api: RiotApi, platform: Platform) -> SummonerTracker {
+        SummonerTracker {
+            api: api.without_cache(),
+            platform,
+            max_concurrency: 4,
+            summoners: HashMap::new(),
+        }
+    }
+
+    /// Sets how many players are fetched at once, the requests still go through the rate limiter.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> SummonerTracker {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Starts tracking a player, its state is recorded at the next poll.
+    pub fn track(&mut self, puuid: Puuid) {
+        self.summoners.entry(puuid).or_insert(None);
+    }
+
+    /// Stops tracking a player, returning its latest state.
+    pub fn untrack(&mut self, puuid: &Puuid) -> Option<TrackedSummoner> {
+        self.summoners.remove(puuid).flatten()
+    }
+
+    /// Returns the tracked players.
+    pub fn tracked(&self) -> impl Iterator<Item = &Puuid> {
+        self.summoners.keys()
+    }
+
+    /// Returns the state of a player at its latest fetch, None if it was never fetched.
+    pub fn state(&self, puuid: &Puuid) -> Option<&TrackedSummoner> {
+        self.summoners.get(puuid)?.as_ref()
+    }
+
+    /// Fetches every tracked player and returns their changes since their previous fetch.
+    pub fn poll(&mut self) -> PollReport {
+        let puuids: Vec<Puuid> = self.summoners.keys().cloned().collect();
+        let platform = &self.platform;
+        let fetched = self
+            .api
+            .get_many(&puuids, self.max_concurrency, |api, puuid| {
+                fetch(api, platform, puuid)
+            });
+        let mut report = PollReport::default();
+        for (puuid, fetched) in puuids.into_iter().zip(fetched) {
+            let current = match fetched {
+                Ok(current) => current,
+                Err(error) => {
+                    report.failed.push((puuid, error));
+                    continue;
+                }
+            };
+            if let Some(Some(previous)) = self.summoners.get(&puuid) {
+                report.changes.extend(changes(&puuid, previous, &current));
+            }
+            self.summoners.insert(puuid, Some(current));
+        }
+        report
+    }
+
+    /// Polls every `interval` until the token is cancelled, calling `on_report` after each poll with changes or failures.
+    pub fn run(
+        &mut self,
+        interval: Duration,
+        cancellation: &CancellationToken,
+        mut on_report: impl FnMut(PollReport),
+    ) {
+        while !cancellation.is_cancelled() {
+            let report = self.poll();
+            if !report.changes.is_empty() || !report.failed.is_empty() {
+                on_report(report);
+            }
+            if cancellation.sleep(interval).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+fn fetch(
+    api: &RiotApi,
+    platform: &Platform,
+    puuid: &Puuid,
+) -> Result<TrackedSummoner, SamiraError> {
+    let summoner = api.try_get_summoner(platform, SummonerFilter::by_puuid(puuid.clone()))?;
+    // Accounts are served by every regional route but SEA.
+    let region = match platform.regional_route() {
+        Region::SEA => Region::ASIA,
+        region => region,
+    };
+    let account = api.try_get_account_by_puuid(&region, puuid)?;
+    let entries = api.try_get_league_entries(platform, puuid)?;
+    Ok(TrackedSummoner {
+        summoner,
+        riot_id: account.riot_id(),
+        entries,
+    })
+}
+
+/// Returns the changes of a player between two fetches.
+fn changes(
+    puuid: &Puuid,
+    previous: &TrackedSummoner,
+    current: &TrackedSummoner,
+) -> Vec<SummonerChange> {
+    let mut changes = Vec::new();
+    if previous.riot_id != current.riot_id {
+        changes.push(SummonerChange::RiotIdChanged {
+            puuid: puuid.clone(),
+            previous: previous.riot_id.clone(),
+            current: current.riot_id.clone(),
+        });
+    }
+    if current.summoner.summoner_level > previous.summoner.summoner_level {
+        changes.push(SummonerChange::LevelUp {
+            puuid: puuid.clone(),
+            previous: previous.summoner.summoner_level,
+            current: current.summoner.summoner_level,
+        });
+    }
+    if previous.summoner.profile_icon_id != current.summoner.profile_icon_id {
+        changes.push(SummonerChange::IconChanged {
+            puuid: puuid.clone(),
+            previous: previous.summoner.profile_icon_id,
+            current: current.summoner.profile_icon_id,
+        });
+    }
+    let mut queue_types: Vec<&str> = previous
+        .entries
+        .iter()
+        .chain(&current.entries)
+        .map(|entry| entry.queue_type.as_str())
+        .collect();
+    queue_types.sort_unstable();
+    queue_types.dedup();
+    for queue_type in queue_types {
+        let (previous, current) = (previous.rank(queue_type), current.rank(queue_type));
+        if previous != current {
+            changes.push(SummonerChange::RankChanged {
+                puuid: puuid.clone(),
+                queue_type: queue_type.to_string(),
+                previous,
+                current,
+            });
+        }
+    }
+    changes
+}
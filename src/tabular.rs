@@ -0,0 +1,146 @@
+//! Flattens the models into typed columns, shared by the DataFrame exports.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::models::match_model::Match;
+
+/// The type of the values of a column, inferred from its non null values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColumnKind {
+    Boolean,
+    Integer,
+    Float,
+    Text,
+}
+
+/// A column of flattened values, null where a row has no value.
+#[derive(Debug, Clone)]
+pub(crate) struct Column {
+    pub name: String,
+    pub kind: ColumnKind,
+    pub values: Vec<Value>,
+}
+
+impl Column {
+    pub fn booleans(&self) -> Vec<Option<bool>> {
+        self.values.iter().map(Value::as_bool).collect()
+    }
+
+    pub fn integers(&self) -> Vec<Option<i64>> {
+        self.values.iter().map(Value::as_i64).collect()
+    }
+
+    pub fn floats(&self) -> Vec<Option<f64>> {
+        self.values.iter().map(Value::as_f64).collect()
+    }
+
+    /// Returns the values as strings, the strings being written without their quotes.
+    pub fn texts(&self) -> Vec<Option<String>> {
+        self.values
+            .iter()
+            .map(|value| match value {
+                Value::Null => None,
+                Value::String(value) => Some(value.clone()),
+                value => Some(value.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// Serializes a model into a row, the models which are not written as JSON objects being a single `value` column.
+pub(crate) fn to_row(model: &impl Serialize) -> Result<Map<String, Value>, serde_json::Error> {
+    match serde_json::to_value(model)? {
+        Value::Object(row) => Ok(row),
+        value => Ok(Map::from_iter([("value".to_string(), value)])),
+    }
+}
+
+/// Returns one row per participant, with the match id and the match info fields ("gameDuration", "queueId", ...)
+/// on every row, the teams excluded.
+pub(crate) fn participant_rows(
+    matches: &[Match],
+) -> Result<Vec<Map<String, Value>>, serde_json::Error> {
+    let mut rows = Vec::new();
+    for game in matches {
+        let mut info = to_row(&game.info)?;
+        info.remove("participants");
+        info.remove("teams");
+        for participant in &game.info.participants {
+            let mut row = info.clone();
+            row.insert(
+                "matchId".to_string(),
+                game.metadata.match_id.as_str().into(),
+            );
+            row.extend(to_row(participant)?);
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+/// Builds the columns of rows, named after their JSON fields.
+/// Nested objects are flattened with dotted names ("stats.hp") and arrays are written as JSON strings.
+pub(crate) fn columns(rows: impl IntoIterator<Item = Map<String, Value>>) -> Vec<Column> {
+    let mut columns: Vec<Column> = Vec::new();
+    let mut indexes: HashMap<String, usize> = HashMap::new();
+    let mut count = 0;
+    for row in rows {
+        let mut fields = Vec::new();
+        flatten("", Value::Object(row), &mut fields);
+        for (name, value) in fields {
+            let index = *indexes.entry(name.clone()).or_insert_with(|| {
+                columns.push(Column {
+                    name,
+                    kind: ColumnKind::Text,
+                    values: vec![Value::Null; count],
+                });
+                columns.len() - 1
+            });
+            columns[index].values.push(value);
+        }
+        count += 1;
+        for column in &mut columns {
+            column.values.resize(count, Value::Null);
+        }
+    }
+    for column in &mut columns {
+        column.kind = infer_kind(&column.values);
+    }
+    columns
+}
+
+fn flatten(prefix: &str, value: Value, fields: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(object) => {
+            for (name, value) in object {
+                let name = match prefix {
+                    "" => name,
+                    prefix => format!("{}.{}", prefix, name),
+                };
+                flatten(&name, value, fields);
+            }
+        }
+        Value::Array(array) => {
+            fields.push((prefix.to_string(), Value::Array(array).to_string().into()))
+        }
+        value => fields.push((prefix.to_string(), value)),
+    }
+}
+
+fn infer_kind(values: &[Value]) -> ColumnKind {
+    let values: Vec<&Value> = values.iter().filter(|value| !value.is_null()).collect();
+    if values.is_empty() {
+        ColumnKind::Text
+    } else if values.iter().all(|value| value.is_boolean()) {
+        ColumnKind::Boolean
+    } else if values.iter().all(|value| value.is_i64()) {
+        ColumnKind::Integer
+    } else if values.iter().all(|value| value.is_number()) {
+        ColumnKind::Float
+    } else {
+        ColumnKind::Text
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The side of a team on the map, written by the Riot API as its id (100 or 200).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TeamId {
+    /// The bottom left side, id 100.
+    BLUE,
+    /// The top right side, id 200.
+    RED,
+}
+
+impl TeamId {
+    /// Returns the id of the team as written by the Riot API.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::team::*;
+    ///
+    /// assert_eq!(TeamId::RED.id(), 200);
+    /// assert_eq!(TeamId::from_id(100), Some(TeamId::BLUE));
+    /// let team: TeamId = serde_json::from_str("200").unwrap();
+    /// assert_eq!(team.opponent(), TeamId::BLUE);
+    /// ```
+    pub fn id(&self) -> i32 {
+        match self {
+            TeamId::BLUE => 100,
+            TeamId::RED => 200,
+        }
+    }
+
+    /// Returns the team matching its id, None if it is unknown.
+    pub fn from_id(id: i32) -> Option<TeamId> {
+        match id {
+            100 => Some(TeamId::BLUE),
+            200 => Some(TeamId::RED),
+            _ => None,
+        }
+    }
+
+    /// Returns the other team.
+    pub fn opponent(&self) -> TeamId {
+        match self {
+            TeamId::BLUE => TeamId::RED,
+            TeamId::RED => TeamId::BLUE,
+        }
+    }
+}
+
+impl Serialize for TeamId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TeamId, D::Error> {
+        let id = i32::deserialize(deserializer)?;
+        TeamId::from_id(id).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Signed(id as i64), &"a team id, 100 or 200")
+        })
+    }
+}
+
+impl fmt::Display for TeamId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TeamId::BLUE => write!(f, "blue"),
+            TeamId::RED => write!(f, "red"),
+        }
+    }
+}
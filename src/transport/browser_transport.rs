@@ -0,0 +1,69 @@
+use web_sys::XmlHttpRequest;
+
+use crate::error::SamiraError;
+
+use super::{HttpResponse, HttpTransport};
+
+/// A transport for the wasm32-unknown-unknown target, sending requests with the browser.
+/// As the fetch API is asynchronous only, requests are sent with a synchronous XMLHttpRequest,
+/// which is best done from a web worker so the page is not blocked.
+/// Browsers decompress responses themselves and forbid setting the Accept-Encoding header,
+/// so disabling the compression has no effect.
+#[derive(Debug, Default, Clone)]
+pub struct BrowserTransport;
+
+impl HttpTransport for BrowserTransport {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError> {
+        send("GET", url, headers, None)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Result<HttpResponse, SamiraError> {
+        send("POST", url, headers, Some(body))
+    }
+}
+
+/// Sends a request with a synchronous XMLHttpRequest, with a JSON body if any.
+fn send(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<HttpResponse, SamiraError> {
+    let error = |error| SamiraError::Transport(format!("{:?}", error));
+    let request = XmlHttpRequest::new().map_err(error)?;
+    request.open_with_async(method, url, false).map_err(error)?;
+    for (name, value) in headers {
+        if !name.eq_ignore_ascii_case("Accept-Encoding") {
+            request.set_request_header(name, value).map_err(error)?;
+        }
+    }
+    match body {
+        Some(body) => {
+            request
+                .set_request_header("Content-Type", "application/json")
+                .map_err(error)?;
+            request.send_with_opt_str(Some(body)).map_err(error)?;
+        }
+        None => request.send().map_err(error)?,
+    }
+    let headers = request
+        .get_all_response_headers()
+        .map_err(error)?
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect();
+    let body = request.response_text().map_err(error)?.unwrap_or_default();
+    Ok(HttpResponse {
+        status: request.status().map_err(error)?,
+        headers,
+        body: Box::new(std::io::Cursor::new(body.into_bytes())),
+    })
+}
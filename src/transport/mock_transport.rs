@@ -0,0 +1,196 @@
+use std::sync::{Arc, Mutex};
+
+use crate::error::SamiraError;
+
+use super::{HttpResponse, HttpTransport};
+
+/// A canned response and the url pattern it answers.
+#[derive(Debug, Clone)]
+struct MockRoute {
+    pattern: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+/// A transport serving canned responses instead of sending requests,
+/// so tests can run without network access or a Riot API token.
+/// Requests are answered by the first route whose url pattern matches, where `*` matches any characters.
+/// Requests without a matching route are answered with a 404.
+/// The requests answered are recorded, and clones share them so they can be checked once a client sent them.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::{riot_api::*, platform::*, transport::mock_transport::*};
+///
+/// let transport = MockTransport::new().with_json(
+///     "https://*.api.riotgames.com/lol/platform/v3/champion-rotations",
+///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": [360]}"#,
+/// );
+/// let api = RiotApi::new_unchecked("TOKEN_HERE").with_transport(transport);
+/// let rotations = api.get_champion_rotations(&Platform::EUW1).unwrap();
+/// assert_eq!(rotations.free_champion_ids, vec![360]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MockTransport {
+    routes: Vec<MockRoute>,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockTransport {
+    /// Creates a MockTransport without any route, answering every request with a 404.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::transport::{mock_transport::*, *};
+    ///
+    /// let transport = MockTransport::new();
+    /// let response = transport.get("https://ddragon.leagueoflegends.com/api/versions.json", &[]);
+    /// assert_eq!(response.unwrap().status, 404);
+    /// ```
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Answers the requests matching a url pattern with a JSON body.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json("*/runesReforged.json", r#"[{"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": []}]"#);
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// assert_eq!(api.get_rune("Domination".to_owned()).unwrap().id, 8100);
+    /// ```
+    pub fn with_json(self, pattern: &str, body: &str) -> MockTransport {
+        self.with_response(pattern, 200, &[("Content-Type", "application/json")], body)
+    }
+
+    /// Answers the requests matching a url pattern with the given status, headers and body.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{error::*, riot_api::*, platform::*, retry_policy::*, transport::mock_transport::*};
+    ///
+    /// let transport = MockTransport::new().with_response("*", 429, &[("Retry-After", "10")], "");
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE")
+    ///     .with_transport(transport)
+    ///     .with_retry_policy(RetryPolicy::none());
+    /// let rotations = api.try_get_champion_rotations(&Platform::EUW1);
+    /// assert_eq!(rotations, Err(SamiraError::RateLimited { retry_after: Some(10) }));
+    /// ```
+    pub fn with_response(
+        mut self,
+        pattern: &str,
+        status: u16,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> MockTransport {
+        self.routes.push(MockRoute {
+            pattern: pattern.to_string(),
+            status,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.to_string(),
+        });
+        self
+    }
+
+    /// Returns the method and url of the requests answered so far, the first first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::transport::{mock_transport::*, *};
+    ///
+    /// let transport = MockTransport::new();
+    /// transport.clone().get("https://ddragon.leagueoflegends.com/api/versions.json", &[]).unwrap();
+    /// assert_eq!(
+    ///     transport.requests(),
+    ///     [("GET".to_string(), "https://ddragon.leagueoflegends.com/api/versions.json".to_string())]
+    /// );
+    /// ```
+    pub fn requests(&self) -> Vec<(String, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError> {
+        Ok(self.respond("GET", url))
+    }
+
+    /// Answers a POST request like a GET request to the same url, the body is ignored.
+    fn post(
+        &self,
+        url: &str,
+        _headers: &[(&str, &str)],
+        _body: &str,
+    ) -> Result<HttpResponse, SamiraError> {
+        Ok(self.respond("POST", url))
+    }
+}
+
+impl MockTransport {
+    fn respond(&self, method: &str, url: &str) -> HttpResponse {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((method.to_string(), url.to_string()));
+        match self
+            .routes
+            .iter()
+            .find(|route| matches_pattern(&route.pattern, url))
+        {
+            Some(route) => HttpResponse {
+                status: route.status,
+                headers: route.headers.clone(),
+                body: Box::new(std::io::Cursor::new(route.body.clone().into_bytes())),
+            },
+            None => HttpResponse {
+                status: 404,
+                headers: Vec::new(),
+                body: Box::new(std::io::empty()),
+            },
+        }
+    }
+}
+
+/// Checks if a url matches a pattern, where `*` matches any characters.
+fn matches_pattern(pattern: &str, url: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match url.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let last = match parts.pop() {
+        Some(last) => last,
+        // Without any wildcard the url must be the pattern itself.
+        None => return rest.is_empty(),
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
@@ -0,0 +1,112 @@
+#[cfg(feature = "wasm")]
+pub mod browser_transport;
+pub mod mock_transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod replay_transport;
+#[cfg(feature = "ureq")]
+pub mod ureq_transport;
+
+use std::{fmt, io::Read, sync::Arc};
+
+use crate::error::SamiraError;
+
+/// The HTTP client used by the APIs to send their requests.
+/// Implementations must be thread safe as a transport can be shared by several clients.
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    /// Sends a GET request with the given headers.
+    /// Responses with an error status must be returned as well so their headers can be read,
+    /// only a request which could not be sent or answered is an error.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError>;
+
+    /// Sends a POST request with the given headers and JSON body, the error statuses are handled like [`HttpTransport::get`].
+    /// POST requests are sent to the servers run on this machine by the game and the client,
+    /// and to the Riot API to create tournament codes.
+    /// Transports which never send them can keep the default implementation, which always fails.
+    fn post(
+        &self,
+        url: &str,
+        _headers: &[(&str, &str)],
+        _body: &str,
+    ) -> Result<HttpResponse, SamiraError> {
+        Err(SamiraError::Transport(format!(
+            "this transport cannot send the POST request to {}",
+            url
+        )))
+    }
+}
+
+/// The transport used by the APIs unless another one is given:
+/// a [`ureq_transport::UreqTransport`] with the `ureq` feature, else a
+/// [`browser_transport::BrowserTransport`] with the `wasm` feature.
+#[allow(unreachable_code)]
+pub(crate) fn default_transport() -> Arc<dyn HttpTransport> {
+    #[cfg(feature = "ureq")]
+    return Arc::new(ureq_transport::UreqTransport::default());
+    #[cfg(feature = "wasm")]
+    return Arc::new(browser_transport::BrowserTransport);
+    Arc::new(NoTransport)
+}
+
+/// The transport reaching the servers run on this machine by the game and the client:
+/// a [`ureq_transport::UreqTransport::local`] with the `local` feature, else the default transport.
+#[allow(unreachable_code)]
+pub(crate) fn local_transport() -> Arc<dyn HttpTransport> {
+    #[cfg(feature = "local")]
+    return Arc::new(ureq_transport::UreqTransport::local());
+    default_transport()
+}
+
+/// The default transport when no HTTP client is enabled, every request must then go through a custom transport.
+#[derive(Debug)]
+struct NoTransport;
+
+impl HttpTransport for NoTransport {
+    fn get(&self, _url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError> {
+        Err(SamiraError::Transport(
+            "no HTTP client enabled, enable the ureq or wasm feature or use a custom transport"
+                .to_string(),
+        ))
+    }
+}
+
+/// The response to a request sent by a [`HttpTransport`].
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    /// The decompressed body of the response, it should be buffered.
+    pub body: Box<dyn Read + Send>,
+}
+
+impl fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+impl HttpResponse {
+    /// Returns the value of a header, header names are case insensitive.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::transport::*;
+    ///
+    /// let response = HttpResponse {
+    ///     status: 200,
+    ///     headers: vec![("retry-after".to_string(), "10".to_string())],
+    ///     body: Box::new("{}".as_bytes()),
+    /// };
+    /// assert_eq!(response.header("Retry-After"), Some("10"));
+    /// ```
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
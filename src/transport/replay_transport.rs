@@ -0,0 +1,173 @@
+use std::{
+    fs,
+    io::{self, Cursor, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json;
+
+use crate::{
+    cache::file_cache::{hash, temporary_path},
+    error::SamiraError,
+};
+
+use super::{HttpResponse, HttpTransport};
+
+/// A transport recording responses to fixture files, or serving them back,
+/// so integration tests are deterministic and don't use the rate limit.
+/// Fixtures are named after a hash of the request url, only the url and response are stored, never the token.
+/// POST requests are identified by their url and body, their body being stored along with their url.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    directory: PathBuf,
+    /// The transport whose responses are recorded, None when replaying.
+    recorded: Option<Arc<dyn HttpTransport>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Fixture {
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl ReplayTransport {
+    /// Creates a ReplayTransport sending its requests with another transport
+    /// and writing every response to the given directory, creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::env;
+    /// use samira::{platform::*, riot_api::*, transport::{mock_transport::*, replay_transport::*}};
+    ///
+    /// let directory = env::temp_dir().join("samira-doc-fixtures");
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/lol/platform/v3/champion-rotations",
+    ///     r#"{"maxNewPlayerLevel": 10, "freeChampionIdsForNewPlayers": [], "freeChampionIds": [360]}"#,
+    /// );
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE")
+    ///     .with_transport(ReplayTransport::record(&directory, transport).unwrap());
+    /// assert!(api.get_champion_rotations(&Platform::EUW1).is_some());
+    ///
+    /// let api = RiotApi::new_unchecked("TOKEN_HERE")
+    ///     .with_transport(ReplayTransport::replay(&directory))
+    ///     .without_cache();
+    /// assert_eq!(api.get_champion_rotations(&Platform::EUW1).unwrap().free_champion_ids, vec![360]);
+    /// ```
+    pub fn record(
+        directory: impl AsRef<Path>,
+        transport: impl HttpTransport + 'static,
+    ) -> io::Result<ReplayTransport> {
+        fs::create_dir_all(directory.as_ref())?;
+        Ok(ReplayTransport {
+            directory: directory.as_ref().to_path_buf(),
+            recorded: Some(Arc::new(transport)),
+        })
+    }
+
+    /// Creates a ReplayTransport serving the responses recorded in the given directory.
+    /// Requests without a recorded response fail with a [`SamiraError::Transport`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::env;
+    /// use samira::{error::*, transport::{replay_transport::*, *}};
+    ///
+    /// let transport = ReplayTransport::replay(env::temp_dir().join("samira-doc-empty-fixtures"));
+    /// let response = transport.get("https://ddragon.leagueoflegends.com/api/versions.json", &[]);
+    /// assert!(matches!(response, Err(SamiraError::Transport(_))));
+    /// ```
+    pub fn replay(directory: impl AsRef<Path>) -> ReplayTransport {
+        ReplayTransport {
+            directory: directory.as_ref().to_path_buf(),
+            recorded: None,
+        }
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.json", hash(url)))
+    }
+
+    fn write(&self, fixture: &Fixture) -> io::Result<()> {
+        let path = self.path(&fixture.url);
+        let temporary = temporary_path(&path);
+        // Written then renamed so a concurrent replay never sees a partial file.
+        let written = serde_json::to_vec_pretty(fixture)
+            .map_err(io::Error::from)
+            .and_then(|content| fs::write(&temporary, content))
+            .and_then(|_| fs::rename(&temporary, &path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+        written
+    }
+
+    fn read(&self, url: &str) -> Option<Fixture> {
+        let content = fs::read(self.path(url)).ok()?;
+        let fixture: Fixture = serde_json::from_slice(&content).ok()?;
+        match fixture.url == url {
+            true => Some(fixture),
+            false => None,
+        }
+    }
+}
+
+impl HttpTransport for ReplayTransport {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError> {
+        self.respond(url, |transport| transport.get(url, headers))
+    }
+
+    /// Records or replays a POST request, identified by its url and body.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Result<HttpResponse, SamiraError> {
+        let key = format!("POST {}\n{}", url, body);
+        self.respond(&key, |transport| transport.post(url, headers, body))
+    }
+}
+
+impl ReplayTransport {
+    /// Sends a request with the recorded transport and writes its response as the fixture of `key`,
+    /// or reads the fixture of `key` when replaying.
+    fn respond(
+        &self,
+        key: &str,
+        send: impl FnOnce(&dyn HttpTransport) -> Result<HttpResponse, SamiraError>,
+    ) -> Result<HttpResponse, SamiraError> {
+        let fixture = match &self.recorded {
+            Some(transport) => {
+                let mut response = send(transport.as_ref())?;
+                let mut body = String::new();
+                response.body.read_to_string(&mut body)?;
+                let fixture = Fixture {
+                    url: key.to_string(),
+                    status: response.status,
+                    headers: response.headers,
+                    body,
+                };
+                self.write(&fixture)?;
+                fixture
+            }
+            None => self.read(key).ok_or_else(|| {
+                SamiraError::Transport(format!("no response recorded for {}", key))
+            })?,
+        };
+        Ok(HttpResponse {
+            status: fixture.status,
+            headers: fixture.headers,
+            body: Box::new(Cursor::new(fixture.body.into_bytes())),
+        })
+    }
+}
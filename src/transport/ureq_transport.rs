@@ -0,0 +1,198 @@
+use std::time::Duration;
+#[cfg(feature = "local")]
+use std::{net::IpAddr, sync::Arc};
+
+#[cfg(feature = "local")]
+use rustls::{
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    pki_types::{CertificateDer, ServerName, UnixTime},
+    DigitallySignedStruct, SignatureScheme,
+};
+
+use crate::error::SamiraError;
+
+use super::{HttpResponse, HttpTransport};
+
+/// The default transport, backed by a ureq agent.
+/// The agent is shared by every request so connections and TLS sessions are reused,
+/// and compressed responses are decompressed transparently.
+#[derive(Debug, Clone)]
+pub struct UreqTransport {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqTransport {
+    fn default() -> UreqTransport {
+        UreqTransport::configured(None, None)
+    }
+}
+
+impl UreqTransport {
+    /// Creates a transport with an optional timeout and user agent, `samira/<version>` by default.
+    pub(crate) fn configured(timeout: Option<Duration>, user_agent: Option<&str>) -> UreqTransport {
+        let mut agent = ureq::AgentBuilder::new()
+            .user_agent(user_agent.unwrap_or(concat!("samira/", env!("CARGO_PKG_VERSION"))));
+        if let Some(timeout) = timeout {
+            agent = agent.timeout(timeout);
+        }
+        UreqTransport::new(agent.build())
+    }
+
+    /// Creates a transport sending its requests with the given agent, e.g. one going through a proxy.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::transport::ureq_transport::*;
+    ///
+    /// let agent = ureq::AgentBuilder::new().user_agent("my-app").build();
+    /// let transport = UreqTransport::new(agent);
+    /// ```
+    pub fn new(agent: ureq::Agent) -> UreqTransport {
+        UreqTransport { agent }
+    }
+}
+
+impl HttpTransport for UreqTransport {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, SamiraError> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        read_response(request.call())
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Result<HttpResponse, SamiraError> {
+        let mut request = self.agent.post(url).set("Content-Type", "application/json");
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        read_response(request.send_string(body))
+    }
+}
+
+/// Converts the response of ureq, which returns the error statuses as errors.
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<HttpResponse, SamiraError> {
+    let response = match response {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(error) => return Err(error.into()),
+    };
+    let headers = response
+        .headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_string();
+            Some((name, value))
+        })
+        .collect();
+    Ok(HttpResponse {
+        status: response.status(),
+        headers,
+        body: response.into_reader(),
+    })
+}
+
+#[cfg(feature = "local")]
+impl UreqTransport {
+    /// Creates a transport for the servers run on this machine by the game and the client,
+    /// which answer over HTTPS with a certificate signed by Riot rather than by a public authority.
+    /// The certificates of loopback addresses are accepted as is, any other host is refused.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::transport::ureq_transport::*;
+    ///
+    /// let transport = UreqTransport::local();
+    /// ```
+    pub fn local() -> UreqTransport {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let config = rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .expect("the ring provider supports the default protocol versions")
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(LoopbackVerifier { provider }))
+            .with_no_client_auth();
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!("samira/", env!("CARGO_PKG_VERSION")))
+            .tls_config(Arc::new(config))
+            .build();
+        UreqTransport::new(agent)
+    }
+}
+
+/// Accepts any certificate from a loopback address, the handshake signatures are still checked.
+#[cfg(feature = "local")]
+#[derive(Debug)]
+struct LoopbackVerifier {
+    provider: Arc<rustls::crypto::CryptoProvider>,
+}
+
+#[cfg(feature = "local")]
+impl ServerCertVerifier for LoopbackVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match server_name {
+            ServerName::IpAddress(address) if IpAddr::from(*address).is_loopback() => {
+                Ok(ServerCertVerified::assertion())
+            }
+            ServerName::DnsName(name) if name.as_ref() == "localhost" => {
+                Ok(ServerCertVerified::assertion())
+            }
+            _ => Err(rustls::Error::InvalidCertificate(
+                rustls::CertificateError::NotValidForName,
+            )),
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
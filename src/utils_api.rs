@@ -1,22 +1,264 @@
-use ureq::serde_json::{self, Value};
+use std::collections::{btree_map, BTreeMap, HashMap};
+use std::fmt;
+use std::io::Read;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::Deserialize;
+use serde_json::{self, value::RawValue, Value};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::assets::*;
+use crate::bulk::run_bounded;
+use crate::cache::{get_or_revalidate, memory_cache::MemoryCache, Cache, CacheCategory, CacheTtls};
+use crate::error::SamiraError;
+use crate::filters::champion_filter::ChampionFilter;
+use crate::models::challenge_model::*;
 use crate::models::champion_model::*;
+use crate::models::item_model::*;
+use crate::models::league_model::*;
 use crate::models::rune_model::*;
+use crate::request::{coalesce, get_json, get_json_if_modified, get_reader, get_text_if_modified};
+use crate::transport::{default_transport, HttpTransport};
+use crate::version::GameVersion;
 
 const SERVER: &str = "https://ddragon.leagueoflegends.com";
+const CDRAGON_SERVER: &str = "https://raw.communitydragon.org";
 
-#[derive(Debug, PartialEq)]
+/// A client of the DDragon and CommunityDragon static data.
+/// Cloning a UtilsApi is cheap and the clones share their cache, parsed files and connections,
+/// so it can be stored in an application state and used from several threads.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::thread;
+/// use samira::{transport::mock_transport::*, utils_api::*};
+///
+/// let api = UtilsApi::default().with_transport(MockTransport::new());
+/// let worker = {
+///     let api = api.clone();
+///     thread::spawn(move || api.get_all_runes())
+/// };
+/// assert_eq!(worker.join().unwrap(), api.get_all_runes());
+/// ```
+#[derive(Debug, Clone)]
 pub struct UtilsApi {
     pub version: String,
     pub language: String,
+    pub fallback_languages: Vec<String>,
+    transport: Arc<dyn HttpTransport>,
+    cache: Option<Arc<dyn Cache>>,
+    cache_ttls: CacheTtls,
+    compression: bool,
+    ddragon_url: String,
+    cdragon_url: String,
+    champions: Arc<Datasets<Champion>>,
+    runes: Arc<Datasets<Rune>>,
+    items: Arc<Datasets<Item>>,
+}
+
+/// The layout of item.json, items are keyed by their id.
+#[derive(Deserialize)]
+struct ItemFile {
+    data: BTreeMap<String, Item>,
+}
+
+/// The layout of championFull.json, champions are keyed by their id.
+#[derive(Deserialize)]
+struct ChampionFull<T = Champion> {
+    data: BTreeMap<String, T>,
+}
+
+/// Reads championFull.json one champion at a time, without buffering the whole document,
+/// and hands each champion to `on_champion` until it returns false.
+fn stream_champions(
+    reader: impl Read,
+    mut on_champion: impl FnMut(Champion) -> bool,
+) -> Result<(), SamiraError> {
+    let mut stopped = false;
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = deserializer.deserialize_map(ChampionStream {
+        on_champion: &mut on_champion,
+        stopped: &mut stopped,
+    });
+    match result {
+        // Stopping early leaves the document unfinished, which serde reports as an error.
+        Err(_) if stopped => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Visits the top level of championFull.json, skipping everything but its data.
+struct ChampionStream<'a, F> {
+    on_champion: &'a mut F,
+    stopped: &'a mut bool,
+}
+
+impl<'de, F: FnMut(Champion) -> bool> Visitor<'de> for ChampionStream<'_, F> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a championFull.json document")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "data" => map.next_value_seed(ChampionStream {
+                    on_champion: &mut *self.on_champion,
+                    stopped: &mut *self.stopped,
+                })?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Visits the data of championFull.json, deserializing its champions one by one.
+impl<'de, F: FnMut(Champion) -> bool> DeserializeSeed<'de> for ChampionStream<'_, F> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        struct Data<'a, F>(ChampionStream<'a, F>);
+
+        impl<'de, F: FnMut(Champion) -> bool> Visitor<'de> for Data<'_, F> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of champions")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+                while map.next_key::<IgnoredAny>()?.is_some() {
+                    if !(self.0.on_champion)(map.next_value()?) {
+                        *self.0.stopped = true;
+                        return Err(de::Error::custom("stopped"));
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_map(Data(self))
+    }
+}
+
+/// An iterator over the champions of a version, deserializing them one at a time,
+/// created by [`UtilsApi::try_champions_iter`].
+/// The champions are sorted by id, and reading a malformed champion returns its error without ending the iteration.
+pub struct ChampionIter(ChampionSource);
+
+enum ChampionSource {
+    /// The champions were already parsed by a previous call.
+    Parsed(Arc<Vec<Champion>>, usize),
+    /// The champions of championFull.json, not deserialized yet.
+    Document(btree_map::IntoIter<String, Box<RawValue>>),
+}
+
+impl fmt::Debug for ChampionIter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = match &self.0 {
+            ChampionSource::Parsed(champions, index) => champions.len() - index,
+            ChampionSource::Document(champions) => champions.len(),
+        };
+        f.debug_struct("ChampionIter")
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+impl Iterator for ChampionIter {
+    type Item = Result<Champion, SamiraError>;
+
+    fn next(&mut self) -> Option<Result<Champion, SamiraError>> {
+        match &mut self.0 {
+            ChampionSource::Parsed(champions, index) => {
+                let champion = champions.get(*index)?.clone();
+                *index += 1;
+                Some(Ok(champion))
+            }
+            ChampionSource::Document(champions) => {
+                let (_, champion) = champions.next()?;
+                Some(serde_json::from_str(champion.get()).map_err(SamiraError::from))
+            }
+        }
+    }
+}
+
+/// A DDragon version and language.
+type DatasetKey = (String, String);
+
+/// Parsed DDragon datasets, keyed by version and language.
+/// They are kept for the lifetime of the client so repeated lookups don't parse the files again.
+struct Datasets<T>(Mutex<HashMap<DatasetKey, Arc<Vec<T>>>>);
+
+impl<T> Default for Datasets<T> {
+    fn default() -> Datasets<T> {
+        Datasets(Mutex::new(HashMap::new()))
+    }
+}
+
+impl<T> fmt::Debug for Datasets<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Datasets")
+            .field("loaded", &self.0.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl<T> Datasets<T> {
+    /// Drops the datasets of the other versions.
+    fn retain_version(&self, version: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .retain(|(kept, _), _| kept == version);
+    }
+
+    fn get(&self, version: &str, language: &str) -> Option<Arc<Vec<T>>> {
+        let key = (version.to_string(), language.to_string());
+        self.0.lock().unwrap().get(&key).cloned()
+    }
+
+    fn get_or_load(
+        &self,
+        version: &str,
+        language: &str,
+        load: impl FnOnce() -> Result<Option<Vec<T>>, SamiraError>,
+    ) -> Result<Option<Arc<Vec<T>>>, SamiraError> {
+        if let Some(dataset) = self.get(version, language) {
+            return Ok(Some(dataset));
+        }
+        // The lock isn't held while loading, concurrent loads are already coalesced by get_json.
+        let dataset = match load()? {
+            Some(dataset) => Arc::new(dataset),
+            None => return Ok(None),
+        };
+        let key = (version.to_string(), language.to_string());
+        self.0.lock().unwrap().insert(key, dataset.clone());
+        Ok(Some(dataset))
+    }
 }
 
 impl Default for UtilsApi {
     fn default() -> UtilsApi {
-        UtilsApi {
-            version: "12.14.1".to_string(),
-            language: "en_US".to_string(),
-        }
+        UtilsApi::unchecked("12.14.1", "en_US")
+    }
+}
+
+impl PartialEq for UtilsApi {
+    fn eq(&self, other: &UtilsApi) -> bool {
+        self.version == other.version
+            && self.language == other.language
+            && self.fallback_languages == other.fallback_languages
     }
 }
 
@@ -31,19 +273,28 @@ impl UtilsApi {
     /// use samira::utils_api::*;
     ///
     /// let api = UtilsApi::latest("en_US").unwrap_or_default();
-    /// assert_eq!(api, UtilsApi{version: "12.14.1".to_owned(), language: "en_US".to_owned()});
+    /// assert_eq!(api.version, "12.14.1");
+    /// assert_eq!(api.language, "en_US");
     /// ```
     pub fn latest(language: &str) -> Option<UtilsApi> {
-        let language_result = is_language_available(language.to_owned());
-        let version = get_latest_version();
-        if version.is_ok() && (language_result.is_ok() && language_result.unwrap() == true) {
-            Some(UtilsApi {
-                version: version.unwrap(),
-                language: language.to_owned(),
-            })
-        } else {
-            None
-        }
+        UtilsApi::try_latest(language).ok()
+    }
+
+    /// Creates a new UtilsApi using the latest available version and custom language.
+    /// Unlike [`UtilsApi::latest`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::try_latest("xx_XX");
+    /// assert_eq!(api, Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_latest(language: &str) -> Result<UtilsApi, SamiraError> {
+        UtilsApi::builder().language(language).build()
     }
 
     /// Creates a new UtilsApi using a custom version and custom language.
@@ -56,20 +307,267 @@ impl UtilsApi {
     /// use samira::utils_api::*;
     ///
     /// let api = UtilsApi::new("12.12.1", "fr_FR").unwrap_or_default();
-    /// assert_eq!(api, UtilsApi{version: "12.12.1".to_owned(), language: "fr_FR".to_owned()});
+    /// assert_eq!(api.version, "12.12.1");
+    /// assert_eq!(api.language, "fr_FR");
     /// ```
     pub fn new(version: &str, language: &str) -> Option<UtilsApi> {
-        let version_result = is_version_available(version.to_owned());
-        let language_result = is_language_available(language.to_owned());
-        if (language_result.is_ok() && language_result.unwrap() == true)
-            && (version_result.is_ok() && version_result.unwrap() == true)
-        {
-            return Some(UtilsApi {
-                version: version.to_owned(),
-                language: language.to_owned(),
-            });
+        UtilsApi::try_new(version, language).ok()
+    }
+
+    /// Creates a new UtilsApi using a custom version and custom language.
+    /// Unlike [`UtilsApi::new`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::try_new("0.0.0", "fr_FR");
+    /// assert_eq!(api, Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_new(version: &str, language: &str) -> Result<UtilsApi, SamiraError> {
+        UtilsApi::builder()
+            .version(version)
+            .language(language)
+            .build()
+    }
+
+    /// Creates a [`UtilsApiBuilder`] to configure a UtilsApi before creating it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::builder()
+    ///     .version("12.12.1")
+    ///     .language("fr_FR")
+    ///     .fallback_languages(&["en_US"])
+    ///     .validate(false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(api.version, "12.12.1");
+    /// ```
+    pub fn builder() -> UtilsApiBuilder {
+        UtilsApiBuilder::default()
+    }
+
+    /// Sets the cache used to store DDragon files, replacing the default in-memory cache.
+    /// A cache can be shared by several clients.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use samira::{cache::memory_cache::*, utils_api::*};
+    ///
+    /// let cache = Arc::new(MemoryCache::default());
+    /// let api = UtilsApi::default().with_cache(cache.clone());
+    /// let other_api = UtilsApi::new("12.12.1", "fr_FR").unwrap_or_default().with_cache(cache);
+    /// ```
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> UtilsApi {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Disables the DDragon files cache.
+    pub fn without_cache(mut self) -> UtilsApi {
+        self.cache = None;
+        self
+    }
+
+    /// Sets the time to live of the cached files, DDragon files belong to [`CacheCategory::StaticData`].
+    /// Once expired, a file is revalidated with a conditional request and only downloaded again if it changed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use samira::{cache::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::default()
+    ///     .with_cache_ttls(CacheTtls::default().with(CacheCategory::StaticData, Duration::from_secs(3600)));
+    /// ```
+    pub fn with_cache_ttls(mut self, cache_ttls: CacheTtls) -> UtilsApi {
+        self.cache_ttls = cache_ttls;
+        self
+    }
+
+    /// Enables or disables gzip compressed responses, which are enabled by default with the `gzip` feature.
+    /// Disabling it is useful to debug raw payloads.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::default().with_compression(false);
+    /// ```
+    pub fn with_compression(mut self, compression: bool) -> UtilsApi {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests, a [`UreqTransport`](crate::transport::ureq_transport::UreqTransport) by default.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{utils_api::*, transport::ureq_transport::*};
+    ///
+    /// let agent = ureq::AgentBuilder::new().user_agent("my-app").build();
+    /// let api = UtilsApi::default().with_transport(UreqTransport::new(agent));
+    /// ```
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> UtilsApi {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets the languages to retry, in order, when a file or an entity is missing in the primary language.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::default().with_fallback_languages(&["fr_FR", "en_US"]);
+    /// assert_eq!(api.fallback_languages, vec!["fr_FR".to_owned(), "en_US".to_owned()]);
+    /// ```
+    pub fn with_fallback_languages(mut self, languages: &[&str]) -> UtilsApi {
+        self.fallback_languages = languages
+            .iter()
+            .map(|language| language.to_string())
+            .collect();
+        self
+    }
+
+    /// Downloads the champions, items, runes and summoner spells files in parallel,
+    /// so the first lookups after startup are answered from the cache instead of waiting on DDragon.
+    /// Only the primary language is loaded, files missing on DDragon are skipped.
+    /// On the wasm32 target, where threads cannot be spawned, the files are downloaded one after the other.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/championFull.json", r#"{"data": {}}"#)
+    ///     .with_json("*/item.json", r#"{"data": {}}"#)
+    ///     .with_json(
+    ///         "*/runesReforged.json",
+    ///         r#"[{"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": []}]"#,
+    ///     )
+    ///     .with_json("*/summoner.json", r#"{"data": {}}"#);
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// api.warm_cache().unwrap();
+    /// assert_eq!(api.get_rune("Domination".to_owned()).is_some(), true);
+    /// ```
+    pub fn warm_cache(&self) -> Result<(), SamiraError> {
+        let files = [
+            "championFull.json",
+            "item.json",
+            "runesReforged.json",
+            "summoner.json",
+        ];
+        let (version, language) = (self.version.as_str(), self.language.as_str());
+        run_bounded(files, files.len(), |file| match file {
+            "championFull.json" => get_all_champions(self, version, language).map(|_| ()),
+            "runesReforged.json" => get_all_runes(self, version, language).map(|_| ()),
+            "item.json" => get_all_items(self, version, language).map(|_| ()),
+            file => self
+                .get_json(&get_data_url(self, version, language, file))
+                .map(|_| ()),
+        })
+        .into_iter()
+        .collect()
+    }
+
+    /// Checks versions.json for a newer patch and switches to it, so long-running bots follow the patches
+    /// without building a new client. Returns true if the version changed.
+    /// The files parsed for the other versions are dropped, which the clones of the client share.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json("*/api/versions.json", r#"["13.1.1", "12.23.1", "12.14.1"]"#);
+    /// let mut api = UtilsApi::default().with_transport(transport);
+    /// assert_eq!(api.version, "12.14.1");
+    /// assert_eq!(api.refresh(), Ok(true));
+    /// assert_eq!(api.version, "13.1.1");
+    /// assert_eq!(api.refresh(), Ok(false));
+    /// ```
+    pub fn refresh(&mut self) -> Result<bool, SamiraError> {
+        let latest = get_latest_version(self)?;
+        let newer = match (
+            GameVersion::parse(&latest),
+            GameVersion::parse(&self.version),
+        ) {
+            (Some(latest), Some(current)) => latest > current,
+            _ => latest != self.version,
+        };
+        if !newer {
+            return Ok(false);
+        }
+        self.version = latest;
+        self.champions.retain_version(&self.version);
+        self.runes.retain_version(&self.version);
+        self.items.retain_version(&self.version);
+        Ok(true)
+    }
+
+    /// Returns a client reading the data of another version, e.g. to look up a champion as it was on an old patch.
+    /// The client shares the cache and the parsed files of this one, and the version is not checked.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/cdn/13.1.1/data/en_US/championFull.json", r#"{"data": {"Samira": {"id": "Samira", "key": "360", "name": "Samira", "title": "the Desert Rose"}}}"#)
+    ///     .with_json("*/cdn/13.1.1/data/fr_FR/championFull.json", r#"{"data": {"Samira": {"id": "Samira", "key": "360", "name": "Samira", "title": "la Rose du désert"}}}"#);
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// assert_eq!(api.at("13.1.1").get_champion_by_key(360).unwrap().title, "the Desert Rose");
+    /// assert_eq!(api.at("13.1.1").in_language("fr_FR").get_champion_by_key(360).unwrap().title, "la Rose du désert");
+    /// assert_eq!(api.version, "12.14.1");
+    /// ```
+    pub fn at(&self, version: &str) -> UtilsApi {
+        UtilsApi {
+            version: version.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a client reading the data in another language, sharing the cache and the parsed files of this one.
+    /// The language is not checked and the fallback languages are kept.
+    pub fn in_language(&self, language: &str) -> UtilsApi {
+        UtilsApi {
+            language: language.to_string(),
+            ..self.clone()
         }
-        None
     }
 
     /// Retrieve all current champions.
@@ -88,11 +586,95 @@ impl UtilsApi {
     /// assert_eq!(champions.iter().find(|&c| c.name == "RqndomChampion").is_some(), false);
     /// ```
     pub fn get_all_champions(&self) -> Vec<Champion> {
-        let champions = get_all_champions(&self.version, &self.language);
-        if champions.is_ok() {
-            return champions.unwrap();
-        }
-        Vec::new()
+        self.try_get_all_champions().unwrap_or_default()
+    }
+
+    /// Retrieve all current champions.
+    /// Unlike [`UtilsApi::get_all_champions`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::new("12.12.1", "fr_FR").unwrap_or_default();
+    /// match api.try_get_all_champions() {
+    ///     Ok(champions) => println!("{} champions", champions.len()),
+    ///     Err(error) => println!("could not retrieve champions: {}", error),
+    /// }
+    /// ```
+    pub fn try_get_all_champions(&self) -> Result<Vec<Champion>, SamiraError> {
+        self.find_in_languages(|language| get_all_champions(self, &self.version, language))
+            .map(|champions| champions.to_vec())
+    }
+
+    /// Iterates over the current champions, deserializing them one at a time,
+    /// so searching for a champion can stop at the first match instead of deserializing them all.
+    /// The champions which cannot be read are skipped, see [`UtilsApi::try_champions_iter`] to get their errors.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/championFull.json",
+    ///     r#"{"data": {
+    ///         "Samira": {"id": "Samira", "key": "360", "name": "Samira", "tags": ["Marksman"]},
+    ///         "Akali": {"id": "Akali", "key": "84", "name": "Akali", "tags": ["Assassin"]}
+    ///     }}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let marksman = api.champions_iter().find(|champion| champion.tags.contains(&"Marksman".to_string()));
+    /// assert_eq!(marksman.unwrap().name, "Samira");
+    /// assert_eq!(api.champions_iter().count(), 2);
+    /// ```
+    pub fn champions_iter(&self) -> impl Iterator<Item = Champion> {
+        self.try_champions_iter().into_iter().flatten().flatten()
+    }
+
+    /// Iterates over the current champions, deserializing them one at a time.
+    /// Unlike [`UtilsApi::champions_iter`] it returns the reason of the failure,
+    /// and the error of each champion which cannot be read.
+    pub fn try_champions_iter(&self) -> Result<ChampionIter, SamiraError> {
+        self.find_in_languages(|language| get_champion_iter(self, &self.version, language))
+    }
+
+    /// Retrieve all current champions, only deserializing their heavy fields (lore, skins, spells, ...) once accessed.
+    /// Useful to list champions when only their names and images are needed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/championFull.json",
+    ///     r#"{"data": {
+    ///         "Samira": {"id": "Samira", "key": "360", "name": "Samira", "lore": "Samira stares death in the eye..."},
+    ///         "Akali": {"id": "Akali", "key": "84", "name": "Akali"}
+    ///     }}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let champions = api.get_all_champions_lazy();
+    /// assert_eq!(champions.len(), 2);
+    /// assert_eq!(champions[1].name, "Samira");
+    /// assert_eq!(champions[1].lore().unwrap(), "Samira stares death in the eye...");
+    /// ```
+    pub fn get_all_champions_lazy(&self) -> Vec<LazyChampion> {
+        self.try_get_all_champions_lazy().unwrap_or_default()
+    }
+
+    /// Retrieve all current champions, only deserializing their heavy fields once accessed.
+    /// Unlike [`UtilsApi::get_all_champions_lazy`] it returns the reason of the failure.
+    pub fn try_get_all_champions_lazy(&self) -> Result<Vec<LazyChampion>, SamiraError> {
+        self.find_in_languages(|language| get_all_lazy_champions(self, &self.version, language))
     }
 
     /// Retrieve a champion from its id.
@@ -104,13 +686,25 @@ impl UtilsApi {
     /// use samira::{models::champion_model::*, utils_api::*};
     ///
     /// let api = UtilsApi::latest("en_US").unwrap_or_default();
-    /// assert_eq!("360", api.get_champion_by_key("360".to_owned()).unwrap().key);
-    pub fn get_champion_by_key(&self, key: String) -> Option<Champion> {
-        let champion = get_champion_by_key(&self.version, &self.language, key);
-        if champion.is_ok() {
-            return Some(champion.unwrap());
-        }
-        None
+    /// assert_eq!(360, api.get_champion_by_key(360).unwrap().key);
+    pub fn get_champion_by_key(&self, key: i64) -> Option<Champion> {
+        self.try_get_champion_by_key(key).ok()
+    }
+
+    /// Retrieve a champion from its id.
+    /// Unlike [`UtilsApi::get_champion_by_key`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// assert_eq!(api.try_get_champion_by_key(0), Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_get_champion_by_key(&self, key: i64) -> Result<Champion, SamiraError> {
+        self.find_in_languages(|language| get_champion_by_key(self, &self.version, language, key))
     }
 
     /// Retrieve a champion from its name.
@@ -124,11 +718,132 @@ impl UtilsApi {
     /// let api = UtilsApi::latest("en_US").unwrap_or_default();
     /// assert_eq!("Samira", api.get_champion_by_name("Samira".to_owned()).unwrap().name);
     pub fn get_champion_by_name(&self, name: String) -> Option<Champion> {
-        let champion = get_champion_by_name(&self.version, &self.language, name);
-        if champion.is_ok() {
-            return Some(champion.unwrap());
-        }
-        None
+        self.try_get_champion_by_name(name).ok()
+    }
+
+    /// Retrieve a champion from its name.
+    /// Unlike [`UtilsApi::get_champion_by_name`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// assert_eq!(api.try_get_champion_by_name("RqndomChampion".to_owned()), Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_get_champion_by_name(&self, name: String) -> Result<Champion, SamiraError> {
+        self.find_in_languages(|language| {
+            get_champion_by_name(self, &self.version, language, name.clone())
+        })
+    }
+
+    /// Retrieve a champion from its name as it was in another version, see [`UtilsApi::at`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/cdn/13.1.1/data/en_US/championFull.json",
+    ///     r#"{"data": {"Samira": {"id": "Samira", "key": "360", "name": "Samira", "stats": {"hp": 600}}}}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// assert_eq!(api.get_champion_at("Samira", "13.1.1").unwrap().stats.hp, 600.0);
+    /// ```
+    pub fn get_champion_at(&self, name: &str, version: &str) -> Option<Champion> {
+        self.try_get_champion_at(name, version).ok()
+    }
+
+    /// Retrieve a champion from its name as it was in another version.
+    /// Unlike [`UtilsApi::get_champion_at`] it returns the reason of the failure.
+    pub fn try_get_champion_at(&self, name: &str, version: &str) -> Result<Champion, SamiraError> {
+        self.at(version).try_get_champion_by_name(name.to_string())
+    }
+
+    /// Rolls a random champion among those matching the filter, e.g. for "roll me a champ" bots.
+    /// With a seed, the same champion is rolled from the same version.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{filters::champion_filter::*, transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/championFull.json",
+    ///     r#"{"data": {
+    ///         "Samira": {"id": "Samira", "key": "360", "name": "Samira", "tags": ["Marksman"], "partype": "None"},
+    ///         "Jinx": {"id": "Jinx", "key": "222", "name": "Jinx", "tags": ["Marksman"], "partype": "Mana"},
+    ///         "Akali": {"id": "Akali", "key": "84", "name": "Akali", "tags": ["Assassin"], "partype": "Energy"}
+    ///     }}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let champion = api.random_champion(ChampionFilter::new().tag("Marksman").exclude(360)).unwrap();
+    /// assert_eq!(champion.name, "Jinx");
+    ///
+    /// let filter = ChampionFilter::new().seed(42);
+    /// assert_eq!(api.random_champion(filter.clone()), api.random_champion(filter));
+    /// ```
+    pub fn random_champion(&self, filter: ChampionFilter) -> Option<Champion> {
+        self.try_random_champion(filter).ok()
+    }
+
+    /// Rolls a random champion among those matching the filter.
+    /// Unlike [`UtilsApi::random_champion`] it returns the reason of the failure,
+    /// [`SamiraError::NotFound`] if no champion matches the filter.
+    pub fn try_random_champion(&self, filter: ChampionFilter) -> Result<Champion, SamiraError> {
+        self.try_random_champions(filter, 1)?
+            .pop()
+            .ok_or(SamiraError::NotFound)
+    }
+
+    /// Rolls up to `count` distinct random champions among those matching the filter, e.g. the champions of an ARAM team.
+    /// Fewer champions are returned if fewer match the filter.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{filters::champion_filter::*, transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/championFull.json",
+    ///     r#"{"data": {
+    ///         "Samira": {"id": "Samira", "key": "360", "name": "Samira"},
+    ///         "Jinx": {"id": "Jinx", "key": "222", "name": "Jinx"},
+    ///         "Akali": {"id": "Akali", "key": "84", "name": "Akali"}
+    ///     }}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let mut team = api.random_champions(ChampionFilter::new(), 2);
+    /// assert_eq!(team.len(), 2);
+    /// team.dedup_by_key(|champion| champion.key);
+    /// assert_eq!(team.len(), 2);
+    /// ```
+    pub fn random_champions(&self, filter: ChampionFilter, count: usize) -> Vec<Champion> {
+        self.try_random_champions(filter, count).unwrap_or_default()
+    }
+
+    /// Rolls up to `count` distinct random champions among those matching the filter.
+    /// Unlike [`UtilsApi::random_champions`] it returns the reason of the failure.
+    pub fn try_random_champions(
+        &self,
+        filter: ChampionFilter,
+        count: usize,
+    ) -> Result<Vec<Champion>, SamiraError> {
+        let candidates = self
+            .try_get_all_champions()?
+            .into_iter()
+            .filter(|champion| filter.matches(champion))
+            .collect();
+        Ok(roll(candidates, count, filter.seed_or_clock()))
     }
 
     /// Retrieve a rune by its name
@@ -137,17 +852,38 @@ impl UtilsApi {
     ///
     /// Basic usage:
     /// ```
-    /// use samira::{models::rune_model::*, utils_api::*};
+    /// use samira::{models::rune_model::*, transport::mock_transport::*, utils_api::*};
     ///
-    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/runesReforged.json",
+    ///     r#"[
+    ///         {"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": []},
+    ///         {"id": 8300, "key": "Inspiration", "icon": "", "name": "Inspiration", "slots": []},
+    ///         {"id": 8400, "key": "Resolve", "icon": "", "name": "Resolve", "slots": []}
+    ///     ]"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
     /// assert_eq!("Domination", api.get_rune("Domination".to_owned()).unwrap().name);
     /// assert_eq!("Inspiration", api.get_rune("Inspiration".to_owned()).unwrap().name);
+    /// ```
     pub fn get_rune(&self, name: String) -> Option<Rune> {
-        let rune = get_rune(&self.version, &self.language, name);
-        if rune.is_ok() {
-            return Some(rune.unwrap());
-        }
-        None
+        self.try_get_rune(name).ok()
+    }
+
+    /// Retrieve a rune by its name
+    /// Unlike [`UtilsApi::get_rune`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// assert_eq!(api.try_get_rune("RqndomRune".to_owned()), Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_get_rune(&self, name: String) -> Result<Rune, SamiraError> {
+        self.find_in_languages(|language| get_rune(self, &self.version, language, name.clone()))
     }
 
     /// Retrieve all current runes
@@ -157,9 +893,17 @@ impl UtilsApi {
     /// Basic usage:
     ///
     /// ```
-    /// use samira::{models::rune_model::*, utils_api::*};
+    /// use samira::{models::rune_model::*, transport::mock_transport::*, utils_api::*};
     ///
-    /// let api = UtilsApi::new("12.12.1", "fr_FR").unwrap_or_default();
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/runesReforged.json",
+    ///     r#"[
+    ///         {"id": 8100, "key": "Domination", "icon": "", "name": "Domination", "slots": []},
+    ///         {"id": 8300, "key": "Inspiration", "icon": "", "name": "Inspiration", "slots": []},
+    ///         {"id": 8400, "key": "Resolve", "icon": "", "name": "Resolve", "slots": []}
+    ///     ]"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
     /// let runes = api.get_all_runes();
     /// assert_eq!(runes.iter().find(|&c| c.name == "Domination").is_some(), true);
     /// assert_eq!(runes.iter().find(|&c| c.name == "Inspiration").is_some(), true);
@@ -167,181 +911,777 @@ impl UtilsApi {
     /// assert_eq!(runes.iter().find(|&c| c.name == "RqndomRune").is_some(), false);
     /// ```
     pub fn get_all_runes(&self) -> Vec<Rune> {
-        let runes = get_all_runes(&self.version, &self.language);
-        if runes.is_ok() {
-            return runes.unwrap();
+        self.try_get_all_runes().unwrap_or_default()
+    }
+
+    /// Retrieve all current runes
+    /// Unlike [`UtilsApi::get_all_runes`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::new("12.12.1", "fr_FR").unwrap_or_default();
+    /// let runes = api.try_get_all_runes();
+    /// assert_eq!(runes.is_ok(), true);
+    /// ```
+    pub fn try_get_all_runes(&self) -> Result<Vec<Rune>, SamiraError> {
+        self.find_in_languages(|language| get_all_runes(self, &self.version, language))
+            .map(|runes| runes.to_vec())
+    }
+
+    /// Retrieve all current items, the champion-specific and removed items included.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new().with_json(
+    ///     "*/item.json",
+    ///     r#"{"data": {
+    ///         "1055": {"name": "Doran's Blade", "gold": {"total": 450, "purchasable": true}},
+    ///         "3031": {"name": "Infinity Edge", "from": ["1038", "1037", "1018"]}
+    ///     }}"#,
+    /// );
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let items = api.get_all_items();
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(api.get_item(3031).unwrap().name, "Infinity Edge");
+    /// ```
+    pub fn get_all_items(&self) -> Vec<Item> {
+        self.try_get_all_items().unwrap_or_default()
+    }
+
+    /// Retrieve all current items.
+    /// Unlike [`UtilsApi::get_all_items`] it returns the reason of the failure.
+    pub fn try_get_all_items(&self) -> Result<Vec<Item>, SamiraError> {
+        self.find_in_languages(|language| get_all_items(self, &self.version, language))
+            .map(|items| items.to_vec())
+    }
+
+    /// Retrieve an item from its id.
+    pub fn get_item(&self, id: i64) -> Option<Item> {
+        self.try_get_item(id).ok()
+    }
+
+    /// Retrieve an item from its id.
+    /// Unlike [`UtilsApi::get_item`] it returns the reason of the failure.
+    pub fn try_get_item(&self, id: i64) -> Result<Item, SamiraError> {
+        self.find_in_languages(|language| {
+            Ok(get_all_items(self, &self.version, language)?
+                .and_then(|items| items.iter().find(|item| item.id == id).cloned()))
+        })
+    }
+
+    /// Retrieve the token image url of a challenge at a given level.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::challenge_model::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::default();
+    /// assert_eq!(
+    ///     api.get_challenge_token_url(101000, &ChallengeLevel::MASTER),
+    ///     "https://raw.communitydragon.org/12.14/game/assets/challenges/config/101000/tokens/master.png"
+    /// );
+    /// ```
+    pub fn get_challenge_token_url(&self, id: i64, level: &ChallengeLevel) -> String {
+        format!(
+            "{CDRAGON_SERVER}/{patch}/game/assets/challenges/config/{id}/tokens/{level}.png",
+            CDRAGON_SERVER = self.cdragon_url,
+            patch = get_cdragon_patch(&self.version),
+            id = id,
+            level = level.as_str().to_lowercase(),
+        )
+    }
+
+    /// Retrieve the localized title rewarded by a challenge at a given level.
+    /// If the challenge does not reward a title at this level it returns None.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{models::challenge_model::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// let title = api.get_challenge_title(2022000, &ChallengeLevel::CHALLENGER);
+    /// assert_eq!(title.is_some(), true);
+    /// ```
+    pub fn get_challenge_title(&self, id: i64, level: &ChallengeLevel) -> Option<String> {
+        self.try_get_challenge_title(id, level).ok()
+    }
+
+    /// Retrieve the localized title rewarded by a challenge at a given level.
+    /// Unlike [`UtilsApi::get_challenge_title`] it returns the reason of the failure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, models::challenge_model::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::latest("en_US").unwrap_or_default();
+    /// let title = api.try_get_challenge_title(0, &ChallengeLevel::IRON);
+    /// assert_eq!(title, Err(SamiraError::NotFound));
+    /// ```
+    pub fn try_get_challenge_title(
+        &self,
+        id: i64,
+        level: &ChallengeLevel,
+    ) -> Result<String, SamiraError> {
+        self.find_in_languages(|language| {
+            get_challenge_title(self, &self.version, language, id, level)
+        })
+    }
+
+    /// Retrieve the ranked emblem, wings and crest image urls matching the tier of a league entry.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::league_model::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::default();
+    /// let entry = LeagueEntry {tier: "GOLD".to_string(), rank: "II".to_string(), ..Default::default()};
+    /// let emblem = api.get_ranked_emblem(&entry);
+    /// assert_eq!(
+    ///     emblem.emblem,
+    ///     "https://raw.communitydragon.org/12.14/plugins/rcp-fe-lol-static-assets/global/default/images/ranked-emblem/emblem-gold.png"
+    /// );
+    /// assert_eq!(emblem.wings.ends_with("/ranked-emblem/wings/wings_gold.png"), true);
+    /// assert_eq!(emblem.crest.ends_with("/ranked-mini-crests/gold.png"), true);
+    /// ```
+    pub fn get_ranked_emblem(&self, entry: &LeagueEntry) -> RankedEmblem {
+        let images = format!(
+            "{CDRAGON_SERVER}/{patch}/plugins/rcp-fe-lol-static-assets/global/default/images",
+            CDRAGON_SERVER = self.cdragon_url,
+            patch = get_cdragon_patch(&self.version),
+        );
+        let tier = match entry.tier.as_str() {
+            "" => "unranked".to_string(),
+            tier => tier.to_lowercase(),
+        };
+        RankedEmblem {
+            emblem: format!("{images}/ranked-emblem/emblem-{tier}.png"),
+            wings: format!("{images}/ranked-emblem/wings/wings_{tier}.png"),
+            crest: format!("{images}/ranked-mini-crests/{tier}.png"),
+        }
+    }
+
+    /// Downloads the images of the version into `destination`, for applications serving them themselves:
+    /// the champion squares in `champion/`, the item icons in `item/`, the rune icons in `rune/` and the summoner spell icons in `spell/`,
+    /// each named like on DDragon.
+    /// The downloads are remembered in `samira-assets.json`, so images already downloaded for the version are skipped
+    /// and the unversioned rune icons are only downloaded again when they change.
+    /// It fails if an image list cannot be retrieved, the images which could not be downloaded are listed in the report,
+    /// like the images whose DDragon path would be written outside of `destination`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{assets::*, transport::mock_transport::*, utils_api::*};
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/championFull.json", r#"{"data": {"Samira": {"key": "360", "name": "Samira", "image": {"full": "Samira.png"}}}}"#)
+    ///     .with_json("*/summoner.json", r#"{"data": {"SummonerFlash": {"image": {"full": "SummonerFlash.png"}}}}"#)
+    ///     .with_response("*/img/*", 200, &[("Content-Type", "image/png")], "PNG");
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let destination = std::env::temp_dir().join("samira-sync-assets");
+    /// # let _ = std::fs::remove_dir_all(&destination);
+    /// let kinds = AssetKinds { champions: true, summoner_spells: true, ..Default::default() };
+    ///
+    /// let report = api.sync_assets(&destination, kinds).unwrap();
+    /// assert_eq!(report.downloaded, 2);
+    /// assert_eq!(std::fs::read_to_string(destination.join("champion/Samira.png")).unwrap(), "PNG");
+    ///
+    /// let report = api.sync_assets(&destination, kinds).unwrap();
+    /// assert_eq!((report.downloaded, report.skipped), (0, 2));
+    ///
+    /// let transport = MockTransport::new()
+    ///     .with_json("*/championFull.json", r#"{"data": {"Samira": {"key": "360", "name": "Samira", "image": {"full": "../Samira.png"}}}}"#)
+    ///     .with_response("*/img/*", 200, &[("Content-Type", "image/png")], "PNG");
+    /// let api = UtilsApi::default().with_transport(transport);
+    /// let report = api.sync_assets(&destination, AssetKinds { champions: true, ..Default::default() }).unwrap();
+    /// assert_eq!(report.failed[0].0, "champion/../Samira.png");
+    /// assert!(!destination.join("Samira.png").exists());
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn sync_assets(
+        &self,
+        destination: impl AsRef<Path>,
+        kinds: AssetKinds,
+    ) -> Result<SyncReport, SamiraError> {
+        let destination = destination.as_ref();
+        let images = format!("{}/cdn/{}/img", self.ddragon_url, self.version);
+        let mut assets: Vec<(String, String)> = Vec::new();
+        if kinds.champions {
+            for champion in self.try_get_all_champions()? {
+                let file = &champion.image.full;
+                assets.push((
+                    format!("champion/{file}"),
+                    format!("{images}/champion/{file}"),
+                ));
+            }
         }
-        Vec::new()
+        if kinds.items {
+            for item in self.try_get_all_items()? {
+                let file = &item.image.full;
+                assets.push((format!("item/{file}"), format!("{images}/item/{file}")));
+            }
+        }
+        if kinds.runes {
+            for tree in self.try_get_all_runes()? {
+                let icons = std::iter::once(tree.icon).chain(
+                    tree.slots
+                        .into_iter()
+                        .flat_map(|slot| slot.runes)
+                        .map(|rune| rune.icon),
+                );
+                for icon in icons {
+                    let url = format!("{}/cdn/img/{icon}", self.ddragon_url);
+                    assets.push((format!("rune/{icon}"), url));
+                }
+            }
+        }
+        if kinds.summoner_spells {
+            let url = get_data_url(self, &self.version, &self.language, "summoner.json");
+            let spells = self.get_json(&url)?.ok_or(SamiraError::NotFound)?;
+            let files = spells["data"]
+                .as_object()
+                .into_iter()
+                .flat_map(|spells| spells.values())
+                .filter_map(|spell| spell["image"]["full"].as_str());
+            for file in files {
+                assets.push((format!("spell/{file}"), format!("{images}/spell/{file}")));
+            }
+        }
+
+        let mut manifest = read_manifest(destination);
+        let synced = run_bounded(&assets, 8, |(path, url)| {
+            sync_asset(
+                self.transport.as_ref(),
+                destination,
+                path,
+                url,
+                manifest.get(path),
+            )
+        });
+        let mut report = SyncReport::default();
+        for ((path, _), synced) in assets.into_iter().zip(synced) {
+            match synced {
+                Ok(Synced::Skipped) => report.skipped += 1,
+                Ok(Synced::NotModified(entry)) => {
+                    report.skipped += 1;
+                    manifest.insert(path, entry);
+                }
+                Ok(Synced::Downloaded(entry)) => {
+                    report.downloaded += 1;
+                    manifest.insert(path, entry);
+                }
+                Err(error) => report.failed.push((path, error)),
+            }
+        }
+        write_manifest(destination, &manifest)?;
+        Ok(report)
     }
 }
 
-fn get_all_champions(version: &String, language: &String) -> Result<Vec<Champion>, ureq::Error> {
-    let mut champions: Vec<Champion> = Vec::new();
-    let request = format!(
-        "{SERVER}/cdn/{version}/data/{language}/championFull.json",
-        SERVER = SERVER,
-        version = version,
-        language = language,
-    );
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
+/// Configures a [`UtilsApi`], created with [`UtilsApi::builder`].
+/// Without a version the latest one is used, and the language defaults to en_US.
+#[derive(Debug)]
+pub struct UtilsApiBuilder {
+    api: UtilsApi,
+    version: Option<String>,
+    validate: bool,
+}
+
+impl Default for UtilsApiBuilder {
+    fn default() -> UtilsApiBuilder {
+        UtilsApiBuilder {
+            api: UtilsApi::unchecked("", "en_US"),
+            version: None,
+            validate: true,
+        }
+    }
+}
+
+impl UtilsApiBuilder {
+    /// Sets the DDragon version, the latest one is retrieved when building otherwise.
+    pub fn version(mut self, version: &str) -> UtilsApiBuilder {
+        self.version = Some(version.to_string());
+        self
+    }
 
-    let champ = response
-        .as_object()
-        .expect("not an object")
-        .get("data")
-        .expect("no data found")
-        .as_object()
-        .expect("no champions found");
+    /// Sets the language of the DDragon files.
+    pub fn language(mut self, language: &str) -> UtilsApiBuilder {
+        self.api.language = language.to_string();
+        self
+    }
+
+    /// Sets the languages looked up in order when a value is missing in the primary language.
+    pub fn fallback_languages(mut self, languages: &[&str]) -> UtilsApiBuilder {
+        self.api = self.api.with_fallback_languages(languages);
+        self
+    }
+
+    /// Checks if the version and language are available when building, which is the default.
+    /// Skipping it avoids any request when building with a version.
+    pub fn validate(mut self, validate: bool) -> UtilsApiBuilder {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets the cache used to store DDragon files, replacing the default in-memory cache.
+    pub fn cache(mut self, cache: Arc<dyn Cache>) -> UtilsApiBuilder {
+        self.api = self.api.with_cache(cache);
+        self
+    }
 
-    for val in champ.values() {
-        champions.push(serde_json::from_value(val.clone()).unwrap());
+    /// Disables the DDragon files cache.
+    pub fn without_cache(mut self) -> UtilsApiBuilder {
+        self.api = self.api.without_cache();
+        self
     }
 
-    Ok(champions)
+    /// Sets how long DDragon files are cached before being revalidated.
+    pub fn cache_ttls(mut self, cache_ttls: CacheTtls) -> UtilsApiBuilder {
+        self.api = self.api.with_cache_ttls(cache_ttls);
+        self
+    }
+
+    /// Sets the HTTP client used to send the requests.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> UtilsApiBuilder {
+        self.api = self.api.with_transport(transport);
+        self
+    }
+
+    /// Sets the root of the DDragon CDN, `https://ddragon.leagueoflegends.com` by default.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::utils_api::*;
+    ///
+    /// let api = UtilsApi::builder()
+    ///     .version("12.14.1")
+    ///     .validate(false)
+    ///     .ddragon_url("https://ddragon.example.com/")
+    ///     .build();
+    /// assert!(api.is_ok());
+    /// ```
+    pub fn ddragon_url(mut self, url: &str) -> UtilsApiBuilder {
+        self.api.ddragon_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the root of the CommunityDragon CDN, `https://raw.communitydragon.org` by default.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use samira::{models::challenge_model::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::builder()
+    ///     .version("12.14.1")
+    ///     .validate(false)
+    ///     .cdragon_url("https://cdragon.example.com")
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(api
+    ///     .get_challenge_token_url(101000, &ChallengeLevel::MASTER)
+    ///     .starts_with("https://cdragon.example.com/12.14/"));
+    /// ```
+    pub fn cdragon_url(mut self, url: &str) -> UtilsApiBuilder {
+        self.api.cdragon_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Creates the UtilsApi.
+    /// It fails with [`SamiraError::NotFound`] if the version or language is validated and unavailable.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// use samira::{error::*, utils_api::*};
+    ///
+    /// let api = UtilsApi::builder().version("0.0.0").build();
+    /// assert_eq!(api, Err(SamiraError::NotFound));
+    /// ```
+    pub fn build(self) -> Result<UtilsApi, SamiraError> {
+        let mut api = self.api;
+        match self.version {
+            Some(version) => {
+                if self.validate && !is_version_available(&api, version.clone())? {
+                    return Err(SamiraError::NotFound);
+                }
+                api.version = version;
+            }
+            None => api.version = get_latest_version(&api)?,
+        }
+        if self.validate && !is_language_available(&api, api.language.clone())? {
+            return Err(SamiraError::NotFound);
+        }
+        Ok(api)
+    }
 }
 
-fn get_champion_by_key(
-    version: &String,
-    language: &String,
-    key: String,
-) -> Result<Champion, ureq::Error> {
-    let request = format!(
-        "{SERVER}/cdn/{version}/data/{language}/championFull.json",
-        SERVER = SERVER,
-        version = version,
-        language = language,
-    );
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
-
-    let champs = response
-        .as_object()
-        .expect("not an object")
-        .get("data")
-        .expect("no data found")
-        .as_object()
-        .expect("no champions found");
-
-    let mut champ: Option<Champion> = None;
-
-    for (_, value) in champs {
-        if value
-            .as_object()
-            .expect("not an object")
-            .get("key")
-            .expect("no key found")
-            .as_str()
-            .expect("not a string")
-            == key
-        {
-            champ = Some(serde_json::from_value(value.clone()).unwrap());
-            break;
+impl UtilsApi {
+    fn unchecked(version: &str, language: &str) -> UtilsApi {
+        UtilsApi {
+            version: version.to_owned(),
+            language: language.to_owned(),
+            fallback_languages: Vec::new(),
+            transport: default_transport(),
+            cache: Some(Arc::new(MemoryCache::default())),
+            cache_ttls: CacheTtls::default(),
+            compression: true,
+            ddragon_url: SERVER.to_string(),
+            cdragon_url: CDRAGON_SERVER.to_string(),
+            champions: Arc::default(),
+            runes: Arc::default(),
+            items: Arc::default(),
+        }
+    }
+
+    fn get_json(&self, request: &str) -> Result<Option<Value>, SamiraError> {
+        coalesce(self.transport.as_ref(), request, || {
+            get_or_revalidate(
+                self.cache.as_deref(),
+                self.cache_ttls.get(CacheCategory::StaticData),
+                request,
+                |validators| {
+                    get_json_if_modified(
+                        self.transport.as_ref(),
+                        request,
+                        validators,
+                        self.compression,
+                    )
+                },
+            )
+        })
+    }
+
+    /// Returns the text of a DDragon file, cached as is so it is deserialized straight into typed values
+    /// rather than parsed into a JSON value first.
+    fn get_document(&self, request: &str) -> Result<Option<String>, SamiraError> {
+        // Kept apart from the JSON values cached for the same url.
+        let key = format!("{}\ndocument", request);
+        let document = coalesce(self.transport.as_ref(), &key, || {
+            get_or_revalidate(
+                self.cache.as_deref(),
+                self.cache_ttls.get(CacheCategory::StaticData),
+                &key,
+                |validators| {
+                    get_text_if_modified(
+                        self.transport.as_ref(),
+                        request,
+                        validators,
+                        self.compression,
+                    )
+                },
+            )
+        })?;
+        match document {
+            Some(Value::String(document)) => Ok(Some(document)),
+            Some(_) => Err(SamiraError::malformed("the cached document is not text")),
+            None => Ok(None),
         }
     }
 
-    Ok(champ.expect("key not found"))
+    fn languages(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.language).chain(self.fallback_languages.iter())
+    }
+
+    fn find_in_languages<T>(
+        &self,
+        mut lookup: impl FnMut(&String) -> Result<Option<T>, SamiraError>,
+    ) -> Result<T, SamiraError> {
+        for language in self.languages() {
+            if let Some(value) = lookup(language)? {
+                return Ok(value);
+            }
+        }
+        Err(SamiraError::NotFound)
+    }
 }
 
-fn get_champion_by_name(
-    version: &String,
-    language: &String,
-    name: String,
-) -> Result<Champion, ureq::Error> {
+fn get_cdragon_patch(version: &str) -> String {
+    version.split('.').take(2).collect::<Vec<&str>>().join(".")
+}
+
+fn get_cdragon_locale(language: &str) -> String {
+    match language {
+        "en_US" => "default".to_string(),
+        _ => language.to_lowercase(),
+    }
+}
+
+fn get_challenge_title(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+    id: i64,
+    level: &ChallengeLevel,
+) -> Result<Option<String>, SamiraError> {
     let request = format!(
-        "{SERVER}/cdn/{version}/data/{language}/championFull.json",
-        SERVER = SERVER,
-        version = version,
-        language = language,
+        "{CDRAGON_SERVER}/{patch}/plugins/rcp-be-lol-game-data/global/{locale}/v1/challenges.json",
+        CDRAGON_SERVER = api.cdragon_url,
+        patch = get_cdragon_patch(version),
+        locale = get_cdragon_locale(language),
     );
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
+    let response = match api.get_json(&request)? {
+        Some(response) => response,
+        None => return Ok(None),
+    };
 
-    let champ = response
-        .as_object()
-        .expect("not an object")
-        .get("data")
-        .expect("no data found")
-        .as_object()
-        .expect("no champions found")
-        .get(&name)
-        .expect("champion not found");
+    let threshold = response
+        .get("challenges")
+        .and_then(|challenges| challenges.get(id.to_string()))
+        .and_then(|challenge| challenge.get("thresholds"))
+        .and_then(|thresholds| thresholds.get(level.as_str()))
+        .and_then(|threshold| ChallengeThreshold::deserialize(threshold).ok());
 
-    Ok(serde_json::from_value(champ.clone()).unwrap())
+    Ok(threshold.and_then(|threshold| {
+        threshold
+            .rewards
+            .into_iter()
+            .find(|reward| reward.category == "TITLE")
+            .and_then(|reward| reward.title)
+    }))
 }
 
-fn get_all_runes(version: &String, language: &String) -> Result<Vec<Rune>, ureq::Error> {
-    let mut runes = Vec::new();
-    let request = format!(
-        "{SERVER}/cdn/{version}/data/{language}/runesReforged.json",
-        SERVER = SERVER,
-        version = version,
-        language = language,
-    );
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
+/// Draws up to `count` distinct candidates with a partial Fisher-Yates shuffle driven by SplitMix64,
+/// so a seed always draws the same candidates from the same list.
+fn roll<T>(mut candidates: Vec<T>, count: usize, seed: u64) -> Vec<T> {
+    let mut state = seed;
+    let count = count.min(candidates.len());
+    for index in 0..count {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut random = state;
+        random = (random ^ (random >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        random = (random ^ (random >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        random ^= random >> 31;
+        let picked = index + (random % (candidates.len() - index) as u64) as usize;
+        candidates.swap(index, picked);
+    }
+    candidates.truncate(count);
+    candidates
+}
 
-    let rune = response.as_array().expect("not an array");
+fn get_all_champions(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+) -> Result<Option<Arc<Vec<Champion>>>, SamiraError> {
+    api.champions.get_or_load(version, language, || {
+        let request = get_data_url(api, version, language, "championFull.json");
+        let champions = read_document(api, &request, |reader| {
+            let mut champions = Vec::new();
+            stream_champions(reader, |champion| {
+                champions.push(champion);
+                true
+            })?;
+            Ok(champions)
+        })?;
+        Ok(champions.map(|mut champions| {
+            champions.sort_by(|a, b| a.id.cmp(&b.id));
+            champions
+        }))
+    })
+}
 
-    for val in rune {
-        runes.push(serde_json::from_value(val.clone()).unwrap());
+fn get_champion_iter(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+) -> Result<Option<ChampionIter>, SamiraError> {
+    if let Some(champions) = api.champions.get(version, language) {
+        return Ok(Some(ChampionIter(ChampionSource::Parsed(champions, 0))));
     }
+    let request = get_data_url(api, version, language, "championFull.json");
+    let champions = read_document(api, &request, |reader| {
+        Ok(serde_json::from_reader::<_, ChampionFull<Box<RawValue>>>(
+            reader,
+        )?)
+    })?;
+    Ok(champions
+        .map(|champions| ChampionIter(ChampionSource::Document(champions.data.into_iter()))))
+}
 
-    Ok(runes)
+fn get_all_lazy_champions(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+) -> Result<Option<Vec<LazyChampion>>, SamiraError> {
+    let request = get_data_url(api, version, language, "championFull.json");
+    let champions = read_document(api, &request, |reader| {
+        Ok(serde_json::from_reader::<_, ChampionFull<LazyChampion>>(
+            reader,
+        )?)
+    })?;
+    Ok(champions.map(|champions| champions.data.into_values().collect()))
 }
 
-fn get_rune(version: &String, language: &String, name: String) -> Result<Rune, ureq::Error> {
-    let request = format!(
-        "{SERVER}/cdn/{version}/data/{language}/runesReforged.json",
-        SERVER = SERVER,
+/// Reads a DDragon file with `read`, from its cached text or, without a cache, streamed from DDragon.
+/// Either way it is deserialized straight into typed values.
+fn read_document<T>(
+    api: &UtilsApi,
+    request: &str,
+    read: impl FnOnce(&mut dyn Read) -> Result<T, SamiraError>,
+) -> Result<Option<T>, SamiraError> {
+    match api.cache {
+        Some(_) => match api.get_document(request)? {
+            Some(document) => Ok(Some(read(&mut document.as_bytes())?)),
+            None => Ok(None),
+        },
+        None => match get_reader(api.transport.as_ref(), request, api.compression)? {
+            Some(mut reader) => Ok(Some(read(&mut reader)?)),
+            None => Ok(None),
+        },
+    }
+}
+
+fn get_data_url(api: &UtilsApi, version: &str, language: &str, file: &str) -> String {
+    format!(
+        "{SERVER}/cdn/{version}/data/{language}/{file}",
+        SERVER = api.ddragon_url,
         version = version,
         language = language,
-    );
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
-
-    let rune = response.as_array().expect("not an array");
-    let mut target = None;
-
-    for val in rune {
-        if val
-            .as_object()
-            .expect("not an object")
-            .get("name")
-            .expect("name not found")
-            .as_str()
-            .expect("not a string")
-            == name
-        {
-            target = Some(val);
-        }
+        file = file,
+    )
+}
+
+fn find_champion(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+    predicate: impl Fn(&Champion) -> bool,
+) -> Result<Option<Champion>, SamiraError> {
+    if let Some(champions) = api.champions.get(version, language) {
+        return Ok(champions.iter().find(|champ| predicate(champ)).cloned());
     }
 
-    Ok(serde_json::from_value(target.unwrap().clone()).unwrap())
+    // The file is streamed from the cache or from DDragon, and reading stops at the wanted champion.
+    let request = get_data_url(api, version, language, "championFull.json");
+    let champion = read_document(api, &request, |reader| {
+        let mut champ = None;
+        stream_champions(reader, |champion| match predicate(&champion) {
+            true => {
+                champ = Some(champion);
+                false
+            }
+            false => true,
+        })?;
+        Ok(champ)
+    })?;
+    Ok(champion.flatten())
+}
+
+fn get_champion_by_key(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+    key: i64,
+) -> Result<Option<Champion>, SamiraError> {
+    find_champion(api, version, language, |champ| champ.key == key)
+}
+
+fn get_champion_by_name(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+    name: String,
+) -> Result<Option<Champion>, SamiraError> {
+    find_champion(api, version, language, |champ| champ.id == name)
+}
+
+fn get_all_runes(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+) -> Result<Option<Arc<Vec<Rune>>>, SamiraError> {
+    api.runes.get_or_load(version, language, || {
+        let request = get_data_url(api, version, language, "runesReforged.json");
+        read_document(api, &request, |reader| {
+            Ok(serde_json::from_reader::<_, Vec<Rune>>(reader)?)
+        })
+    })
+}
+
+fn get_all_items(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+) -> Result<Option<Arc<Vec<Item>>>, SamiraError> {
+    api.items.get_or_load(version, language, || {
+        let request = get_data_url(api, version, language, "item.json");
+        let items = read_document(api, &request, |reader| {
+            Ok(serde_json::from_reader::<_, ItemFile>(reader)?)
+        })?;
+        Ok(items.map(|items| {
+            items
+                .data
+                .into_iter()
+                .filter_map(|(id, item)| {
+                    Some(Item {
+                        id: id.parse().ok()?,
+                        ..item
+                    })
+                })
+                .collect()
+        }))
+    })
+}
+
+fn get_rune(
+    api: &UtilsApi,
+    version: &str,
+    language: &str,
+    name: String,
+) -> Result<Option<Rune>, SamiraError> {
+    Ok(get_all_runes(api, version, language)?
+        .and_then(|runes| runes.iter().find(|rune| rune.name == name).cloned()))
 }
 
-fn get_latest_version() -> Result<String, ureq::Error> {
-    let request = format!("{SERVER}/api/versions.json", SERVER = SERVER,);
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
-    Ok(response
-        .as_array()
-        .expect("not an array")
-        .get(0)
-        .expect("no latest version")
-        .as_str()
-        .expect("not a string")
-        .to_string())
+fn get_latest_version(api: &UtilsApi) -> Result<String, SamiraError> {
+    let request = format!("{SERVER}/api/versions.json", SERVER = api.ddragon_url);
+    let response = get_json(api.transport.as_ref(), &request)?.ok_or(SamiraError::NotFound)?;
+    Vec::<String>::deserialize(&response)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| SamiraError::malformed("no latest version"))
 }
 
-fn is_version_available(version: String) -> Result<bool, ureq::Error> {
-    let request = format!("{SERVER}/api/versions.json", SERVER = SERVER,);
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
-    Ok(response
-        .as_array()
-        .expect("not an array")
-        .contains(&Value::String(version.to_string())))
+fn is_version_available(api: &UtilsApi, version: String) -> Result<bool, SamiraError> {
+    let request = format!("{SERVER}/api/versions.json", SERVER = api.ddragon_url);
+    let response = get_json(api.transport.as_ref(), &request)?.ok_or(SamiraError::NotFound)?;
+    Ok(Vec::<String>::deserialize(&response)?.contains(&version))
 }
 
-fn is_language_available(language: String) -> Result<bool, ureq::Error> {
-    let request = format!("{SERVER}/cdn/languages.json", SERVER = SERVER,);
-    let response: serde_json::Value = ureq::get(&request).call()?.into_json()?;
-    Ok(response
-        .as_array()
-        .expect("not an array")
-        .contains(&Value::String(language.to_string())))
+fn is_language_available(api: &UtilsApi, language: String) -> Result<bool, SamiraError> {
+    let request = format!("{SERVER}/cdn/languages.json", SERVER = api.ddragon_url);
+    let response = get_json(api.transport.as_ref(), &request)?.ok_or(SamiraError::NotFound)?;
+    Ok(Vec::<String>::deserialize(&response)?.contains(&language))
 }
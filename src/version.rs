@@ -0,0 +1,87 @@
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A game version, written "12.12.1" by DDragon or "12.12.448.1234" by match-v5,
+/// ordered by major, minor, patch then build.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use samira::version::*;
+///
+/// let ddragon = GameVersion::parse("12.12.1").unwrap();
+/// let game = GameVersion::parse("12.12.448.1234").unwrap();
+/// assert!(ddragon.same_patch(&game));
+/// assert!(GameVersion::parse("12.13.1").unwrap() > game);
+/// assert_eq!(game.to_string(), "12.12.448.1234");
+/// assert_eq!(game.patch_str(), "12.12");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// The build of the patch, only written by the game itself.
+    pub build: Option<u32>,
+}
+
+impl GameVersion {
+    /// Parses a version of three or four numbers, None if it is malformed.
+    pub fn parse(version: &str) -> Option<GameVersion> {
+        let mut parts = version.trim().split('.').map(|part| part.parse().ok());
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = parts.next()??;
+        let build = match parts.next() {
+            Some(build) => Some(build?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(GameVersion {
+            major,
+            minor,
+            patch,
+            build,
+        })
+    }
+
+    /// Returns whether both versions belong to the same patch, e.g. 12.12.1 and 12.12.448.1234.
+    pub fn same_patch(&self, other: &GameVersion) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+
+    /// Returns the patch of the version ("12.12"), as used by CommunityDragon and patch notes.
+    pub fn patch_str(&self) -> String {
+        format!("{}.{}", self.major, self.minor)
+    }
+}
+
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(build) = self.build {
+            write!(f, ".{}", build)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for GameVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameVersion, D::Error> {
+        let version = String::deserialize(deserializer)?;
+        GameVersion::parse(&version).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&version), &"a game version")
+        })
+    }
+}